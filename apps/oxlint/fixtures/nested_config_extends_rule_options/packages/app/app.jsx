@@ -0,0 +1,3 @@
+items.reduce((acc, x) => [...acc, <Item />], []);
+items.eachElement((x) => <Item />);
+items.mapItems((x) => <Item />);
@@ -0,0 +1,4 @@
+items.reduce((acc, x) => [...acc, <Item />], []);
+items.eachElement((x) => <Item />);
+// not an iterator method for files at the root
+items.mapItems((x) => <Item />);
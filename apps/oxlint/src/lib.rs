@@ -66,6 +66,9 @@ fn init_miette() {
 
 /// To debug `oxc_resolver`:
 /// `OXC_LOG=oxc_resolver oxlint --import-plugin`
+///
+/// To print the decision trace of `react/jsx-key` (internal, the output is not stable):
+/// `OXC_LOG=oxc_linter::trace::jsx_key=trace oxlint -A all -D react/jsx-key file.jsx`
 fn init_tracing() {
     use tracing_subscriber::{filter::Targets, prelude::*};
 
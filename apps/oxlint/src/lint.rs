@@ -1054,6 +1054,17 @@ mod test {
         Tester::new().with_cwd("fixtures/extends_config".into()).test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_nested_config_extends_rule_options() {
+        // `packages/app` extends the root config with `"ruleOptionsMerge": "merge"`, and adds an
+        // entry to an array-valued rule option, which is merged with the root's options for files
+        // in that package only.
+        let args = &[];
+        Tester::new()
+            .with_cwd("fixtures/nested_config_extends_rule_options".into())
+            .test_and_snapshot(args);
+    }
+
     #[test]
     fn test_nested_config_subdirectory() {
         // This tests the specific scenario from issue #10156
@@ -413,6 +413,8 @@ fn pure_comment() {
     test_same("/* @__PURE__ */ a?.b();\n");
     test_same("true && /* @__PURE__ */ noEffect();\n");
     test_same("false || /* @__PURE__ */ noEffect();\n");
+    test_same("async function f() {\n\tawait /* @__PURE__ */ noEffect();\n}\n");
+    test_same("async function f() {\n\tawait /* @__PURE__ */ new NoEffect();\n}\n");
 }
 
 // followup from https://github.com/oxc-project/oxc/pull/6422
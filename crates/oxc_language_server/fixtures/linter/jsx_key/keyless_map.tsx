@@ -0,0 +1,3 @@
+export function List({ items }) {
+  return <ul>{items.map((item) => <Item name={item.name} />)}</ul>;
+}
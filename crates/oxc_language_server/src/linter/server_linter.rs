@@ -379,6 +379,21 @@ mod test {
         .test_and_snapshot_single_file("forward_ref.ts");
     }
 
+    #[test]
+    fn test_jsx_key() {
+        Tester::new(
+            "fixtures/linter/jsx_key",
+            Some(Options {
+                flags: FxHashMap::from_iter([(
+                    "fix_kind".to_string(),
+                    "safe_fix_or_suggestion".to_string(),
+                )]),
+                ..Options::default()
+            }),
+        )
+        .test_and_snapshot_single_file("keyless_map.tsx");
+    }
+
     #[test]
     fn test_report_unused_directives() {
         use crate::options::UnusedDisableDirectives;
@@ -12,8 +12,12 @@ use oxc_diagnostics::OxcDiagnostic;
 use crate::{LintPlugins, utils::read_to_string};
 
 use super::{
-    categories::OxlintCategories, env::OxlintEnv, globals::OxlintGlobals,
-    overrides::OxlintOverrides, rules::OxlintRules, settings::OxlintSettings,
+    categories::OxlintCategories,
+    env::OxlintEnv,
+    globals::OxlintGlobals,
+    overrides::OxlintOverrides,
+    rules::{ESLintRule, OxlintRules},
+    settings::OxlintSettings,
 };
 
 /// Oxlint Configuration File
@@ -101,8 +105,47 @@ pub struct Oxlintrc {
     /// are resolved relative to the location of the configuration file that contains the `extends`
     /// property. The configuration files are merged from the first to the last, with the last file
     /// overriding the previous ones.
+    ///
+    /// When a rule is configured in both files, the rule configuration of the extending file
+    /// replaces the one of the extended file, options included. See `ruleOptionsMerge` to merge
+    /// the options instead.
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub extends: Vec<PathBuf>,
+    /// How the configuration of a rule in this file is combined with the configuration of the
+    /// same rule in the files it `extends`.
+    #[serde(rename = "ruleOptionsMerge", skip_serializing_if = "RuleOptionsMerge::is_replace")]
+    pub rule_options_merge: RuleOptionsMerge,
+}
+
+/// How the configuration of a rule is combined with the configuration of the same rule in an
+/// extended configuration file.
+///
+/// - `"replace"` (default): the rule configuration of the extending file is used as is. A rule
+///   configured with only a severity, such as `"warn"`, has no options.
+/// - `"merge"`: the severity is taken from the extending file. If the extending file only sets a
+///   severity, the rule options of the extended file are kept. If both files configure the rule
+///   with a single options object, the objects are merged: array-valued options are
+///   concatenated, with duplicates removed, and any other option is taken from the extending
+///   file. Otherwise, the options of the extending file are used. To replace an inherited array
+///   in this mode, set `ruleOptionsMerge` back to `"replace"` in that file.
+///
+/// For example, a nested `.oxlintrc.json` of a package in a monorepo can extend the root
+/// configuration file with `"extends": ["../../.oxlintrc.json"]` and
+/// `"ruleOptionsMerge": "merge"`, and add entries to an array-valued rule option without
+/// repeating the entries from the root configuration.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleOptionsMerge {
+    #[default]
+    Replace,
+    Merge,
+}
+
+impl RuleOptionsMerge {
+    #[expect(clippy::trivially_copy_pass_by_ref)] // `serde(skip_serializing_if)` passes a reference
+    fn is_replace(&self) -> bool {
+        *self == Self::Replace
+    }
 }
 
 impl Oxlintrc {
@@ -168,20 +211,23 @@ impl Oxlintrc {
         let mut categories = other.categories.clone();
         categories.extend(self.categories.iter());
 
-        let rules = self
-            .rules
-            .rules
-            .iter()
-            .chain(&other.rules.rules)
-            .fold(FxHashMap::default(), |mut rules_set, rule| {
-                if rules_set.contains_key(&(&rule.plugin_name, &rule.rule_name)) {
-                    return rules_set;
+        let base_rules = first_rule_by_name(&other.rules.rules);
+        let own_rules = first_rule_by_name(&self.rules.rules);
+
+        let rules = own_rules
+            .values()
+            .map(|rule| match base_rules.get(&(&rule.plugin_name, &rule.rule_name)) {
+                Some(base) if self.rule_options_merge == RuleOptionsMerge::Merge => {
+                    rule.merge(base)
                 }
-                rules_set.insert((&rule.plugin_name, &rule.rule_name), rule);
-                rules_set
+                _ => (*rule).clone(),
             })
-            .values()
-            .map(|rule| (**rule).clone())
+            .chain(
+                base_rules
+                    .iter()
+                    .filter(|(key, _)| !own_rules.contains_key(*key))
+                    .map(|(_, rule)| (*rule).clone()),
+            )
             .collect::<Vec<_>>();
 
         let settings = self.settings.clone();
@@ -208,10 +254,19 @@ impl Oxlintrc {
             path: self.path.clone(),
             ignore_patterns: self.ignore_patterns.clone(),
             extends: self.extends.clone(),
+            rule_options_merge: self.rule_options_merge,
         }
     }
 }
 
+/// Maps each configured rule to its first entry, by plugin and rule name.
+fn first_rule_by_name(rules: &[ESLintRule]) -> FxHashMap<(&String, &String), &ESLintRule> {
+    rules.iter().fold(FxHashMap::default(), |mut rules_set, rule| {
+        rules_set.entry((&rule.plugin_name, &rule.rule_name)).or_insert(rule);
+        rules_set
+    })
+}
+
 fn is_json_ext(ext: &str) -> bool {
     ext == "json" || ext == "jsonc"
 }
@@ -220,7 +275,7 @@ fn is_json_ext(ext: &str) -> bool {
 mod test {
     use serde_json::json;
 
-    use crate::config::plugins::BuiltinLintPlugins;
+    use crate::{AllowWarnDeny, config::plugins::BuiltinLintPlugins};
 
     use super::*;
 
@@ -263,7 +318,7 @@ mod test {
             Some(BuiltinLintPlugins::TYPESCRIPT.union(BuiltinLintPlugins::UNICORN).into())
         );
         let config: Oxlintrc =
-            serde_json::from_str(r#"{ "plugins": ["typescript", "unicorn", "react", "oxc", "import", "jsdoc", "jest", "vitest", "jsx-a11y", "nextjs", "react-perf", "promise", "node"] }"#).unwrap();
+            serde_json::from_str(r#"{ "plugins": ["typescript", "unicorn", "react", "oxc", "import", "jsdoc", "jest", "vitest", "jsx-a11y", "nextjs", "react-perf", "promise", "node", "security"] }"#).unwrap();
         assert_eq!(config.plugins, Some(BuiltinLintPlugins::all().into()));
 
         let config: Oxlintrc =
@@ -289,4 +344,87 @@ mod test {
         let config: Oxlintrc = serde_json::from_str(r#"{"extends": []}"#).unwrap();
         assert_eq!(0, config.extends.len());
     }
+
+    fn merge_rule_options_configs(mode: &str) -> Oxlintrc {
+        let root = Oxlintrc::from_string(
+            r#"{
+                "rules": {
+                    "react/jsx-key": ["error", { "checkReduce": true, "additionalIteratorMethods": ["eachElement"] }],
+                    "no-console": ["warn", { "allow": ["info"] }],
+                    "no-restricted-globals": ["error", "event"],
+                    "no-debugger": "error"
+                }
+            }"#,
+        )
+        .unwrap();
+        let nested = Oxlintrc::from_string(&format!(
+            r#"{{
+                "ruleOptionsMerge": "{mode}",
+                "rules": {{
+                    "react/jsx-key": ["warn", {{ "additionalIteratorMethods": ["eachElement", "mapItems"] }}],
+                    "no-console": "error",
+                    "no-restricted-globals": ["error", "name"]
+                }}
+            }}"#
+        ))
+        .unwrap();
+        nested.merge(root)
+    }
+
+    #[test]
+    fn test_oxlintrc_merge_rule_options() {
+        let merged = merge_rule_options_configs("merge");
+        assert_eq!(merged.rule_options_merge, RuleOptionsMerge::Merge);
+        let rule = |name: &str| {
+            merged.rules.rules.iter().find(|rule| rule.rule_name == name).unwrap().clone()
+        };
+
+        let jsx_key = rule("jsx-key");
+        assert_eq!(jsx_key.severity, AllowWarnDeny::Warn);
+        assert_eq!(
+            jsx_key.config,
+            Some(
+                json!([{ "checkReduce": true, "additionalIteratorMethods": ["eachElement", "mapItems"] }])
+            )
+        );
+
+        let no_console = rule("no-console");
+        assert_eq!(no_console.severity, AllowWarnDeny::Deny);
+        assert_eq!(no_console.config, Some(json!([{ "allow": ["info"] }])));
+
+        assert_eq!(rule("no-restricted-globals").config, Some(json!(["name"])));
+        assert_eq!(rule("no-debugger").severity, AllowWarnDeny::Deny);
+        assert_eq!(merged.rules.rules.len(), 4);
+    }
+
+    #[test]
+    fn test_oxlintrc_replace_rule_options() {
+        let merged = merge_rule_options_configs("replace");
+        let rule = |name: &str| {
+            merged.rules.rules.iter().find(|rule| rule.rule_name == name).unwrap().clone()
+        };
+
+        let jsx_key = rule("jsx-key");
+        assert_eq!(jsx_key.severity, AllowWarnDeny::Warn);
+        assert_eq!(
+            jsx_key.config,
+            Some(json!([{ "additionalIteratorMethods": ["eachElement", "mapItems"] }]))
+        );
+
+        let no_console = rule("no-console");
+        assert_eq!(no_console.severity, AllowWarnDeny::Deny);
+        assert_eq!(no_console.config, None);
+
+        assert_eq!(rule("no-restricted-globals").config, Some(json!(["name"])));
+        assert_eq!(rule("no-debugger").severity, AllowWarnDeny::Deny);
+        assert_eq!(merged.rules.rules.len(), 4);
+    }
+
+    #[test]
+    fn test_oxlintrc_rule_options_merge_defaults_to_replace() {
+        let config: Oxlintrc = serde_json::from_str("{}").unwrap();
+        assert_eq!(config.rule_options_merge, RuleOptionsMerge::Replace);
+        assert!(serde_json::to_value(&config).unwrap().get("ruleOptionsMerge").is_none());
+        assert!(serde_json::from_str::<Oxlintrc>(r#"{"ruleOptionsMerge": "concat"}"#).is_err());
+    }
 }
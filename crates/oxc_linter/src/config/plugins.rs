@@ -47,6 +47,8 @@ bitflags! {
         const PROMISE = 1 << 11;
         /// `eslint-plugin-node`
         const NODE = 1 << 12;
+        /// `eslint-plugin-security`
+        const SECURITY = 1 << 13;
     }
 }
 
@@ -119,6 +121,7 @@ impl From<&str> for BuiltinLintPlugins {
             "react-perf" | "react_perf" => BuiltinLintPlugins::REACT_PERF,
             "promise" => BuiltinLintPlugins::PROMISE,
             "node" => BuiltinLintPlugins::NODE,
+            "security" => BuiltinLintPlugins::SECURITY,
             // "eslint" is not really a plugin, so it's 'empty'. This has the added benefit of
             // making it the default value.
             _ => BuiltinLintPlugins::empty(),
@@ -142,6 +145,7 @@ impl From<BuiltinLintPlugins> for &'static str {
             BuiltinLintPlugins::REACT_PERF => "react-perf",
             BuiltinLintPlugins::PROMISE => "promise",
             BuiltinLintPlugins::NODE => "node",
+            BuiltinLintPlugins::SECURITY => "security",
             _ => "",
         }
     }
@@ -225,6 +229,7 @@ impl JsonSchema for LintPlugins {
             ReactPerf,
             Promise,
             Node,
+            Security,
         }
 
         let enum_schema = r#gen.subschema_for::<LintPluginOptionsSchema>();
@@ -305,6 +305,69 @@ impl ESLintRule {
             Cow::Owned(format!("{}/{}", self.plugin_name, self.rule_name))
         }
     }
+
+    /// Merges this rule on top of `base`, the same rule configured in an extended config file.
+    ///
+    /// The severity is always taken from `self`. The options are merged as follows:
+    /// - If `self` only sets a severity, the options of `base` are kept.
+    /// - If both set a single options object, the objects are merged with [`merge_options`].
+    /// - Otherwise, the options of `self` replace the options of `base`.
+    #[must_use]
+    pub(crate) fn merge(&self, base: &ESLintRule) -> ESLintRule {
+        let config = match (&self.config, &base.config) {
+            (None, base_config) => base_config.clone(),
+            (Some(config), Some(base_config)) => {
+                match (single_options(config), single_options(base_config)) {
+                    (Some(options), Some(base_options)) => {
+                        Some(serde_json::Value::Array(vec![merge_options(options, base_options)]))
+                    }
+                    _ => Some(config.clone()),
+                }
+            }
+            (Some(config), None) => Some(config.clone()),
+        };
+        ESLintRule { config, ..self.clone() }
+    }
+}
+
+/// Returns the options object of a rule configured as `[severity, { ... }]`.
+fn single_options(config: &serde_json::Value) -> Option<&serde_json::Value> {
+    match config.as_array()?.as_slice() {
+        [options @ serde_json::Value::Object(_)] => Some(options),
+        _ => None,
+    }
+}
+
+/// Deep-merges `value` on top of `base`.
+///
+/// Objects are merged key by key, arrays are concatenated with `base`'s items first and
+/// duplicates removed, and any other value in `value` replaces the one in `base`.
+fn merge_options(value: &serde_json::Value, base: &serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+
+    match (value, base) {
+        (Value::Object(object), Value::Object(base_object)) => {
+            let mut merged = base_object.clone();
+            for (key, value) in object {
+                let value = match base_object.get(key) {
+                    Some(base_value) => merge_options(value, base_value),
+                    None => value.clone(),
+                };
+                merged.insert(key.clone(), value);
+            }
+            Value::Object(merged)
+        }
+        (Value::Array(items), Value::Array(base_items)) => {
+            let mut merged = base_items.clone();
+            for item in items {
+                if !merged.contains(item) {
+                    merged.push(item.clone());
+                }
+            }
+            Value::Array(merged)
+        }
+        (value, _) => value.clone(),
+    }
 }
 
 #[cfg(test)]
@@ -93,7 +93,8 @@ mod test {
                     "Hyperlink",
                     {"name": "MyLink", "linkAttribute": "to"},
                     {"name": "Link", "linkAttribute": ["to", "href"]},
-                ]
+                ],
+                "version": "18.2.0"
             }
         }))
         .unwrap();
@@ -118,6 +119,23 @@ mod test {
             as_attrs(["to", "href"])
         );
         assert_eq!(settings.react.get_link_component_attrs("Noop"), None);
+        assert_eq!(settings.react.version(), Some((18, 2, 0)));
+    }
+
+    #[test]
+    fn test_parse_react_version() {
+        let version = |version: &str| {
+            OxlintSettings::deserialize(&serde_json::json!({ "react": { "version": version } }))
+                .unwrap()
+                .react
+                .version()
+        };
+        assert_eq!(version("19"), Some((19, 0, 0)));
+        assert_eq!(version("16.14"), Some((16, 14, 0)));
+        assert_eq!(version("^18.3.1"), Some((18, 3, 1)));
+        assert_eq!(version("19.0.0-rc.1"), Some((19, 0, 0)));
+        assert_eq!(version("detect"), None);
+        assert_eq!(version(""), None);
     }
 
     #[test]
@@ -125,5 +143,6 @@ mod test {
         let settings = OxlintSettings::default();
         assert!(settings.jsx_a11y.polymorphic_prop_name.is_none());
         assert!(settings.jsx_a11y.components.is_empty());
+        assert!(settings.react.version().is_none());
     }
 }
@@ -56,6 +56,25 @@ pub struct ReactPluginSettings {
     #[serde(default)]
     #[serde(rename = "linkComponents")]
     link_components: Vec<CustomComponent>,
+
+    /// React version. Rules which depend on the version of React use it to decide what to report.
+    ///
+    /// `"detect"` is accepted for compatibility with eslint-plugin-react, but is treated the same as
+    /// not setting a version.
+    ///
+    /// Example:
+    ///
+    /// ```jsonc
+    /// {
+    ///   "settings": {
+    ///     "react": {
+    ///       "version": "18.2.0"
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    #[serde(default)]
+    version: Option<CompactStr>,
     // TODO: More properties should be added
 }
 
@@ -68,6 +87,27 @@ impl ReactPluginSettings {
     pub fn get_link_component_attrs(&self, name: &str) -> Option<ComponentAttrs<'_>> {
         get_component_attrs_by_name(&self.link_components, name)
     }
+
+    /// Configured React version, as `(major, minor, patch)`.
+    ///
+    /// Returns `None` if no version is set, the version is `"detect"`, or it cannot be parsed.
+    pub fn version(&self) -> Option<(u32, u32, u32)> {
+        self.version.as_deref().and_then(parse_version)
+    }
+}
+
+/// Parse a version such as `"18"`, `"18.2"`, `"^18.2.0"` or `"19.0.0-rc.1"`.
+/// Missing minor and patch versions default to 0.
+fn parse_version(version: &str) -> Option<(u32, u32, u32)> {
+    let version = version.trim().trim_start_matches(['^', '~', '=', 'v']);
+    let mut parts = version.splitn(3, '.').map(|part| {
+        let end = part.find(|c: char| !c.is_ascii_digit()).unwrap_or(part.len());
+        part[..end].parse::<u32>().ok()
+    });
+    let major = parts.next()??;
+    let minor = parts.next().unwrap_or(Some(0))?;
+    let patch = parts.next().unwrap_or(Some(0))?;
+    Some((major, minor, patch))
 }
 
 // Deserialize helper types
@@ -7,13 +7,14 @@ use javascript_globals::GLOBALS;
 use oxc_ast::ast::IdentifierReference;
 use oxc_cfg::ControlFlowGraph;
 use oxc_diagnostics::{OxcDiagnostic, Severity};
-use oxc_semantic::Semantic;
+use oxc_semantic::{AstNode, NodeId, Semantic};
 use oxc_span::{GetSpan, Span};
 
 #[cfg(debug_assertions)]
 use crate::rule::RuleFixMeta;
 use crate::{
     AllowWarnDeny, FrameworkFlags, ModuleRecord, OxlintEnv, OxlintGlobals, OxlintSettings,
+    ast_util::get_enclosing_function,
     config::GlobalValue,
     disable_directives::DisableDirectives,
     fixer::{Fix, FixKind, Message, PossibleFixes, RuleFix, RuleFixer},
@@ -442,6 +443,15 @@ impl<'a> LintContext<'a> {
     pub fn frameworks(&self) -> FrameworkFlags {
         self.parent.frameworks
     }
+
+    /// Get the nearest `Function` or `ArrowFunctionExpression` enclosing the node with the given
+    /// [`NodeId`], or [`None`] if the node is not inside a function.
+    ///
+    /// The node itself is not considered, so for a function node this returns the function it is
+    /// nested in. Use [`get_enclosing_function`] to include the node itself.
+    pub fn enclosing_function(&self, node_id: NodeId) -> Option<&AstNode<'a>> {
+        get_enclosing_function(self.nodes().parent_node(node_id), self)
+    }
 }
 
 /// Gets the prefixed plugin name, given the short plugin name.
@@ -470,4 +480,68 @@ const PLUGIN_PREFIXES: phf::Map<&'static str, &'static str> = phf::phf_map! {
     "unicorn" => "eslint-plugin-unicorn",
     "vitest" => "eslint-plugin-vitest",
     "node" => "eslint-plugin-node",
+    "security" => "eslint-plugin-security",
 };
+
+#[cfg(test)]
+mod test {
+    use std::{rc::Rc, sync::Arc};
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::AstKind;
+    use oxc_parser::Parser;
+    use oxc_semantic::{AstNode, SemanticBuilder};
+    use oxc_span::SourceType;
+
+    use crate::{ContextHost, ModuleRecord, options::LintOptions};
+
+    #[test]
+    fn test_enclosing_function() {
+        let allocator = Allocator::default();
+        let source_text = "
+            function outer() {
+                const inner = () => { a; function deepest() { b; } };
+                c;
+            }
+            d;
+        ";
+        let parser_ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+        let semantic = SemanticBuilder::new().with_cfg(true).build(&parser_ret.program).semantic;
+        let ctx = Rc::new(ContextHost::new(
+            "foo.js",
+            Rc::new(semantic),
+            Arc::new(ModuleRecord::default()),
+            LintOptions::default(),
+            Arc::default(),
+        ))
+        .spawn_for_test();
+
+        let find_node = |predicate: &dyn Fn(AstKind) -> bool| {
+            ctx.nodes().iter().find(|node| predicate(node.kind())).unwrap().id()
+        };
+        let reference = |name: &str| {
+            find_node(
+                &|kind| matches!(kind, AstKind::IdentifierReference(ident) if ident.name == name),
+            )
+        };
+        let function_name = |node_id| match ctx.enclosing_function(node_id).map(AstNode::kind) {
+            Some(AstKind::Function(func)) => func.id.as_ref().map(|id| id.name.as_str()),
+            Some(AstKind::ArrowFunctionExpression(_)) => Some("<arrow>"),
+            Some(_) => unreachable!(),
+            None => None,
+        };
+
+        assert_eq!(function_name(reference("a")), Some("<arrow>"));
+        assert_eq!(function_name(reference("b")), Some("deepest"));
+        assert_eq!(function_name(reference("c")), Some("outer"));
+        assert_eq!(function_name(reference("d")), None);
+
+        // Functions themselves resolve to the function they are nested in.
+        let arrow = find_node(&|kind| matches!(kind, AstKind::ArrowFunctionExpression(_)));
+        assert_eq!(function_name(arrow), Some("outer"));
+        let outer = find_node(
+            &|kind| matches!(kind, AstKind::Function(func) if func.name().is_some_and(|n| n == "outer")),
+        );
+        assert_eq!(function_name(outer), None);
+    }
+}
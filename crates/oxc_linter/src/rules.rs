@@ -137,6 +137,7 @@ mod eslint {
     pub mod no_self_assign;
     pub mod no_self_compare;
     pub mod no_setter_return;
+    pub mod no_shadow;
     pub mod no_shadow_restricted_names;
     pub mod no_sparse_arrays;
     pub mod no_template_curly_in_string;
@@ -307,6 +308,7 @@ mod react {
     pub mod no_children_prop;
     pub mod no_danger;
     pub mod no_danger_with_children;
+    pub mod no_deprecated;
     pub mod no_direct_mutation_state;
     pub mod no_find_dom_node;
     pub mod no_is_mounted;
@@ -316,6 +318,7 @@ mod react {
     pub mod no_string_refs;
     pub mod no_unescaped_entities;
     pub mod no_unknown_property;
+    pub mod no_unstable_nested_components;
     pub mod prefer_es6_class;
     pub mod react_in_jsx_scope;
     pub mod require_render_return;
@@ -575,6 +578,10 @@ mod node {
     pub mod no_new_require;
 }
 
+mod security {
+    pub mod detect_eval_with_expression;
+}
+
 oxc_macros::declare_all_lint_rules! {
     eslint::array_callback_return,
     eslint::arrow_body_style,
@@ -677,6 +684,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_self_assign,
     eslint::no_self_compare,
     eslint::no_setter_return,
+    eslint::no_shadow,
     eslint::no_shadow_restricted_names,
     eslint::no_sparse_arrays,
     eslint::no_template_curly_in_string,
@@ -930,6 +938,7 @@ oxc_macros::declare_all_lint_rules! {
     react::no_children_prop,
     react::no_danger_with_children,
     react::no_danger,
+    react::no_deprecated,
     react::no_direct_mutation_state,
     react::no_find_dom_node,
     react::no_is_mounted,
@@ -938,6 +947,7 @@ oxc_macros::declare_all_lint_rules! {
     react::no_string_refs,
     react::no_unescaped_entities,
     react::no_unknown_property,
+    react::no_unstable_nested_components,
     react::prefer_es6_class,
     react::react_in_jsx_scope,
     react::require_render_return,
@@ -949,6 +959,7 @@ oxc_macros::declare_all_lint_rules! {
     react_perf::jsx_no_new_array_as_prop,
     react_perf::jsx_no_new_function_as_prop,
     react_perf::jsx_no_new_object_as_prop,
+    security::detect_eval_with_expression,
     typescript::adjacent_overload_signatures,
     typescript::array_type,
     typescript::ban_ts_comment,
@@ -0,0 +1,509 @@
+use javascript_globals::GLOBALS;
+use serde::Deserialize;
+
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{ScopeId, SymbolFlags};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::symbol::SymbolId;
+
+use crate::{context::LintContext, rule::Rule};
+
+fn no_shadow_diagnostic(name: &str, span: Span, shadowed_span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{name}' is already declared in the upper scope."))
+        .with_help(format!("Rename '{name}' to avoid shadowing the outer declaration."))
+        .with_labels([
+            span.primary_label(format!("'{name}' is redeclared here")),
+            shadowed_span.label(format!("'{name}' is first declared here")),
+        ])
+}
+
+fn no_shadow_global_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{name}' is already a global variable."))
+        .with_help(format!("Rename '{name}' to avoid shadowing the global variable."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoShadow(Box<NoShadowConfig>);
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoShadowConfig {
+    builtin_globals: bool,
+    hoist: Hoist,
+    allow: Vec<String>,
+    ignore_on_initialization: bool,
+    ignore_type_value_shadow: bool,
+}
+
+impl Default for NoShadowConfig {
+    fn default() -> Self {
+        Self {
+            builtin_globals: false,
+            hoist: Hoist::default(),
+            allow: vec![],
+            ignore_on_initialization: false,
+            ignore_type_value_shadow: true,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Hoist {
+    All,
+    #[default]
+    Functions,
+    Never,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows variable declarations from shadowing variables declared in an outer scope.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Shadowing is the process by which a local variable shares the same name as a variable in
+    /// its containing scope. The local variable makes the outer one unreachable, and it is easy
+    /// to mistake one for the other when reading or changing the code.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// var a = 3;
+    /// function b() {
+    ///     var a = 10;
+    /// }
+    ///
+    /// var c = function () {
+    ///     var a = 10;
+    /// };
+    ///
+    /// function d(a) {
+    ///     a = 10;
+    /// }
+    ///
+    /// if (true) {
+    ///     let a = 5;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// var a = 3;
+    /// function b() {
+    ///     var c = 10;
+    /// }
+    ///
+    /// // A function expression may share the name of the variable it initializes.
+    /// var d = function d() {};
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### builtinGlobals
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Also report declarations that shadow built-in and configured global variables, such as
+    /// `Object`. Top-level declarations are only reported in modules, where they have their own
+    /// scope.
+    ///
+    /// #### hoist
+    ///
+    /// `{ type: "all" | "functions" | "never", default: "functions" }`
+    ///
+    /// Whether to report a declaration that shadows an outer one declared later in the source:
+    /// - `"all"` reports it regardless of what the outer declaration is.
+    /// - `"functions"` only reports it when the outer declaration is a function declaration.
+    /// - `"never"` never reports it.
+    ///
+    /// ```javascript
+    /// if (true) {
+    ///     let a = 3; // reported with "all"
+    ///     let b = 6; // reported with "all" and "functions"
+    /// }
+    ///
+    /// let a = 5;
+    /// function b() {}
+    /// ```
+    ///
+    /// #### allow
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// Names that are allowed to shadow, e.g. `["done", "resolve", "reject", "cb"]`.
+    ///
+    /// #### ignoreOnInitialization
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Ignore parameters and variables of a callback or IIFE that is called while initializing
+    /// the shadowed variable, as the outer variable cannot be used before it is initialized.
+    ///
+    /// ```javascript
+    /// var x = foo(x => x); // ignored
+    /// var y = (y => y)(); // ignored
+    /// var z = z => z; // still reported: `z` is initialized by the time the function runs
+    /// ```
+    ///
+    /// #### ignoreTypeValueShadow
+    ///
+    /// `{ type: boolean, default: true }`
+    ///
+    /// Ignore a type shadowing a value of the same name, or a value shadowing a type, since
+    /// TypeScript keeps the two in separate namespaces.
+    ///
+    /// ```typescript
+    /// type Foo = number;
+    /// function f() {
+    ///     const Foo = 1; // ignored
+    /// }
+    /// ```
+    NoShadow,
+    eslint,
+    pedantic
+);
+
+impl Rule for NoShadow {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        value
+            .as_array()
+            .and_then(|arr| arr.first())
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        let scoping = ctx.scoping();
+        let flags = scoping.symbol_flags(symbol_id);
+        if flags.is_enum_member() {
+            return;
+        }
+
+        let name = scoping.symbol_name(symbol_id);
+        if self.0.allow.iter().any(|allowed| allowed == name) {
+            return;
+        }
+
+        let scope_id = scoping.symbol_scope_id(symbol_id);
+        let span = scoping.symbol_span(symbol_id);
+
+        let Some(parent_scope_id) = scoping.scope_parent_id(scope_id) else {
+            // Top-level declarations of a script are globals themselves.
+            if self.0.builtin_globals && ctx.source_type().is_module() && is_builtin(name, ctx) {
+                ctx.diagnostic(no_shadow_global_diagnostic(name, span));
+            }
+            return;
+        };
+
+        if is_signature_parameter(scope_id, flags, ctx) {
+            return;
+        }
+
+        let Some(shadowed_id) =
+            function_expression_name(scope_id, symbol_id, name, ctx).or_else(|| {
+                scoping
+                    .scope_ancestors(parent_scope_id)
+                    .find_map(|scope_id| scoping.get_binding(scope_id, name))
+            })
+        else {
+            if self.0.builtin_globals && is_builtin(name, ctx) {
+                ctx.diagnostic(no_shadow_global_diagnostic(name, span));
+            }
+            return;
+        };
+
+        if is_on_initializer(symbol_id, shadowed_id, ctx)
+            || (self.0.ignore_on_initialization && is_init_pattern(symbol_id, shadowed_id, ctx))
+            || (self.0.ignore_type_value_shadow && is_type_value_shadow(flags, shadowed_id, ctx))
+        {
+            return;
+        }
+
+        let shadowed_span = scoping.symbol_span(shadowed_id);
+        if self.0.hoist != Hoist::All && span.end < shadowed_span.start {
+            let is_function_declaration = self.0.hoist == Hoist::Functions
+                && matches!(
+                    ctx.nodes().kind(scoping.symbol_declaration(shadowed_id)),
+                    AstKind::Function(func) if func.is_declaration()
+                );
+            if !is_function_declaration {
+                return;
+            }
+        }
+
+        ctx.diagnostic(no_shadow_diagnostic(name, span, shadowed_span));
+    }
+}
+
+fn is_builtin(name: &str, ctx: &LintContext<'_>) -> bool {
+    GLOBALS["builtin"].contains_key(name) || ctx.globals().is_enabled(name)
+}
+
+/// The name of a function expression is bound in the function's own scope, where a declaration of
+/// the same name in its body replaces the binding rather than shadowing it, e.g.
+/// `(function a() { var a; })`.
+fn function_expression_name(
+    scope_id: ScopeId,
+    symbol_id: SymbolId,
+    name: &str,
+    ctx: &LintContext<'_>,
+) -> Option<SymbolId> {
+    let AstKind::Function(func) = ctx.nodes().kind(ctx.scoping().get_node_id(scope_id)) else {
+        return None;
+    };
+    let id = func.id.as_ref().filter(|id| func.is_expression() && id.name == name)?;
+    let name_symbol_id = id.symbol_id();
+    (name_symbol_id != symbol_id).then_some(name_symbol_id)
+}
+
+/// Whether the symbol is a parameter of a function type or a function without a body, e.g.
+/// `type Fn = (a: string) => void` or `declare function f(a: string): void`.
+fn is_signature_parameter(scope_id: ScopeId, flags: SymbolFlags, ctx: &LintContext<'_>) -> bool {
+    if !flags.is_function_scoped_declaration() {
+        return false;
+    }
+    match ctx.nodes().kind(ctx.scoping().get_node_id(scope_id)) {
+        AstKind::TSFunctionType(_)
+        | AstKind::TSMethodSignature(_)
+        | AstKind::TSConstructSignatureDeclaration(_) => true,
+        AstKind::Function(func) => func.body.is_none(),
+        _ => false,
+    }
+}
+
+/// Whether the symbol is the name of a function or class expression initializing the
+/// shadowed variable, e.g. `var a = function a() {}`.
+fn is_on_initializer(symbol_id: SymbolId, shadowed_id: SymbolId, ctx: &LintContext<'_>) -> bool {
+    let scoping = ctx.scoping();
+    let is_expression_name = match ctx.nodes().kind(scoping.symbol_declaration(symbol_id)) {
+        AstKind::Function(func) => func.is_expression(),
+        AstKind::Class(class) => class.is_expression(),
+        _ => false,
+    };
+    if !is_expression_name
+        || scoping.scope_parent_id(scoping.symbol_scope_id(symbol_id))
+            != Some(scoping.symbol_scope_id(shadowed_id))
+    {
+        return false;
+    }
+
+    let declarator_id = scoping.symbol_declaration(shadowed_id);
+    if !matches!(ctx.nodes().kind(declarator_id), AstKind::VariableDeclarator(_)) {
+        return false;
+    }
+    let declaration_span = ctx.nodes().parent_node(declarator_id).span();
+    let span = scoping.symbol_span(symbol_id);
+    declaration_span.start < span.start && span.end < declaration_span.end
+}
+
+/// Whether the symbol is declared in a callback or IIFE which is called while initializing the
+/// shadowed variable, e.g. `var a = foo(a => a)` or `var a = (a => a)()`.
+fn is_init_pattern(symbol_id: SymbolId, shadowed_id: SymbolId, ctx: &LintContext<'_>) -> bool {
+    let scoping = ctx.scoping();
+    let Some(function_scope_id) = scoping
+        .scope_ancestors(scoping.symbol_scope_id(symbol_id))
+        .find(|&scope_id| scoping.scope_flags(scope_id).is_var())
+    else {
+        return false;
+    };
+    if scoping.scope_parent_id(function_scope_id) != Some(scoping.symbol_scope_id(shadowed_id)) {
+        return false;
+    }
+    let function_id = scoping.get_node_id(function_scope_id);
+    match ctx.nodes().kind(function_id) {
+        AstKind::Function(func) if func.is_expression() => {}
+        AstKind::ArrowFunctionExpression(_) => {}
+        _ => return false,
+    }
+
+    let Some(call_span) = ctx
+        .nodes()
+        .ancestor_kinds(function_id)
+        .take_while(|kind| !is_sentinel(*kind))
+        .find_map(|kind| kind.as_call_expression().map(GetSpan::span))
+    else {
+        return false;
+    };
+
+    // Whether the call is in the initializer of the shadowed variable, or in the default value
+    // of the shadowed parameter.
+    let shadowed_span = scoping.symbol_span(shadowed_id);
+    for kind in ctx.nodes().ancestor_kinds(function_id) {
+        match kind {
+            AstKind::VariableDeclarator(declarator) => {
+                return declarator.id.span().contains_inclusive(shadowed_span)
+                    && declarator
+                        .init
+                        .as_ref()
+                        .is_some_and(|init| init.span().contains_inclusive(call_span));
+            }
+            AstKind::ForInStatement(stmt) if stmt.left.span().contains_inclusive(shadowed_span) => {
+                return stmt.right.span().contains_inclusive(call_span);
+            }
+            AstKind::ForOfStatement(stmt) if stmt.left.span().contains_inclusive(shadowed_span) => {
+                return stmt.right.span().contains_inclusive(call_span);
+            }
+            AstKind::AssignmentPattern(pattern)
+                if pattern.left.span().contains_inclusive(shadowed_span) =>
+            {
+                return pattern.right.span().contains_inclusive(call_span);
+            }
+            kind if is_sentinel(kind) => return false,
+            _ => {}
+        }
+    }
+    false
+}
+
+fn is_sentinel(kind: AstKind) -> bool {
+    matches!(
+        kind,
+        AstKind::Function(_)
+            | AstKind::Class(_)
+            | AstKind::ArrowFunctionExpression(_)
+            | AstKind::CatchClause(_)
+            | AstKind::ImportDeclaration(_)
+            | AstKind::ExportNamedDeclaration(_)
+    )
+}
+
+/// Whether one of the symbols is only a type and the other only a value.
+fn is_type_value_shadow(flags: SymbolFlags, shadowed_id: SymbolId, ctx: &LintContext<'_>) -> bool {
+    let is_value = |flags: SymbolFlags| {
+        flags.intersects(SymbolFlags::Value)
+            || (flags.contains(SymbolFlags::Import) && !flags.contains(SymbolFlags::TypeImport))
+    };
+    is_value(flags) != is_value(ctx.scoping().symbol_flags(shadowed_id))
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "var a=3; function b(x) { a++; return x + a; }; setTimeout(function() { b(a); }, 0);",
+            None,
+        ),
+        ("(function() { var doSomething = function doSomething() {}; doSomething() }())", None),
+        ("var arguments;\nfunction bar() { }", None),
+        ("var a=3; var b = (x) => { a++; return x + a; }; setTimeout(() => { b(a); }, 0);", None),
+        ("class A {}", None),
+        ("class A { constructor() { var a; } }", None),
+        ("(function() { var A = class A {}; })()", None),
+        ("{ var a; } var a;", None),
+        ("{ let a; } let a;", None),
+        ("{ let a; } var a;", None),
+        ("{ const a = 0; } const a = 1;", None),
+        ("function foo() { let a; } let a;", None),
+        ("function foo() { var a; } let a;", None),
+        ("function foo() { var Object = 0; }", None),
+        ("function foo(a) { } let a;", None),
+        ("function foo() { var a = function() { }; } let a;", None),
+        ("const a = function a() {};", None),
+        ("const a = class a {};", None),
+        (
+            "function foo() { let a; } function a() {}",
+            Some(serde_json::json!([{ "hoist": "never" }])),
+        ),
+        ("function foo() { let a; } let a;", Some(serde_json::json!([{ "hoist": "functions" }]))),
+        (
+            "function foo(cb) { (function (cb) { cb(42); })(cb); }",
+            Some(serde_json::json!([{ "allow": ["cb"] }])),
+        ),
+        ("const x = foo(x => x);", Some(serde_json::json!([{ "ignoreOnInitialization": true }]))),
+        ("const y = (y => y)();", Some(serde_json::json!([{ "ignoreOnInitialization": true }]))),
+        (
+            "const x = foo(function (x) { return x; });",
+            Some(serde_json::json!([{ "ignoreOnInitialization": true }])),
+        ),
+        (
+            "function f(a = foo(a => a)) {}",
+            Some(serde_json::json!([{ "ignoreOnInitialization": true }])),
+        ),
+        (
+            "for (const a of foo(a => a)) {}",
+            Some(serde_json::json!([{ "ignoreOnInitialization": true }])),
+        ),
+        ("try {} catch (e) {} try {} catch (e) {}", None),
+        // TypeScript
+        ("type Foo = string; function f() { const Foo = 1; }", None),
+        ("const Foo = 1; function f() { type Foo = string; }", None),
+        ("const a = 1; interface I { a: string } function f<a>() {}", None),
+        ("import type { Foo } from 'foo'; function f() { const Foo = 1; }", None),
+        ("const a = 1; type Fn = (a: string) => void;", None),
+        ("const a = 1; interface I { m(a: string): void; new (a: string): I; }", None),
+        ("const a = 1; declare function f(a: string): void;", None),
+        ("const a = 1; function f(a: string): void; function f(b: any) {}", None),
+        ("enum E { A, B } function f() { enum F { A } }", None),
+    ];
+
+    let fail = vec![
+        ("function a(x) { var b = function c() { var x = 'foo'; }; }", None),
+        ("var a = (x) => { var b = () => { var x = 'foo'; }; }", None),
+        ("function a(x) { var b = function () { var x = 'foo'; }; }", None),
+        ("var x = 1; function a(x) { return ++x; }", None),
+        ("var a=3; function b() { var a=10; }", None),
+        ("var a=3; var b = () => { var a=10; };", None),
+        ("var a=3; function b() { var a=10; }; setTimeout(function() { b(); }, 0);", None),
+        ("var a=3; function b() { var a=10; var b=0; }; setTimeout(function() { b(); }, 0);", None),
+        ("var x = 1; { let x = 2; }", None),
+        ("let x = 1; { const x = 2; }", None),
+        ("{ let a; } function a() {}", None),
+        ("{ let a; } function a() {}", Some(serde_json::json!([{ "hoist": "functions" }]))),
+        ("{ let a; } let a;", Some(serde_json::json!([{ "hoist": "all" }]))),
+        ("function foo() { let a; } var a;", Some(serde_json::json!([{ "hoist": "all" }]))),
+        ("function a() { var a; }", None),
+        ("(function a() { function a(){} })()", None),
+        ("class A { constructor() { var A; } }", None),
+        ("(function() { var A = class A { constructor() { var A; } }; })()", None),
+        ("try {} catch (e) { (function (e) {}) }", None),
+        ("const a = 1; function f() { try {} catch (a) {} }", None),
+        (
+            "function foo() { var Object = 0; }",
+            Some(serde_json::json!([{ "builtinGlobals": true }])),
+        ),
+        ("var Object = 0;", Some(serde_json::json!([{ "builtinGlobals": true }]))),
+        ("function foo(cb) { (function (cb) { cb(42); })(cb); }", None),
+        ("const x = x => x;", Some(serde_json::json!([{ "ignoreOnInitialization": true }]))),
+        ("const x = foo(x => x);", None),
+        (
+            "const x = 1; const y = foo(x => x);",
+            Some(serde_json::json!([{ "ignoreOnInitialization": true }])),
+        ),
+        (
+            "const x = () => foo(x => x);",
+            Some(serde_json::json!([{ "ignoreOnInitialization": true }])),
+        ),
+        ("import foo from 'foo'; function f(foo) {}", None),
+        // TypeScript
+        ("type Foo = string; function f() { type Foo = number; }", None),
+        (
+            "type Foo = string; function f() { const Foo = 1; }",
+            Some(serde_json::json!([{ "ignoreTypeValueShadow": false }])),
+        ),
+        ("class Foo {} function f() { class Foo {} }", None),
+        ("function f<T>() { function g<T>() {} }", None),
+        ("const a = 1; namespace N { const a = 2; }", None),
+        ("enum E { A } function f() { const E = 1; }", None),
+    ];
+
+    Tester::new(NoShadow::NAME, NoShadow::PLUGIN, pass, fail).test_and_snapshot();
+
+    // Top-level declarations of a script are globals, not shadows of one.
+    let pass = vec![(
+        "var Object = 0;",
+        Some(serde_json::json!([{ "builtinGlobals": true }])),
+        None,
+        Some(PathBuf::from("no-shadow.cjs")),
+    )];
+    Tester::new(NoShadow::NAME, NoShadow::PLUGIN, pass, vec![]).test();
+}
@@ -2,14 +2,25 @@ use std::borrow::Cow;
 
 use oxc_ast::{
     AstKind,
-    ast::{JSXAttributeItem, JSXAttributeName, JSXAttributeValue, JSXChild, JSXExpression},
+    ast::{
+        Expression, JSXAttributeItem, JSXAttributeValue, JSXChild, JSXExpression,
+        JSXOpeningElement, ObjectPropertyKind,
+    },
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{Atom, GetSpan, Span};
 use serde_json::Value;
 
-use crate::{AstNode, context::LintContext, rule::Rule, utils::get_element_type};
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        get_element_type, get_jsx_attribute, get_prop_value, get_static_string_value,
+        has_jsx_prop_ignore_case,
+    },
+};
 
 fn media_has_caption_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Missing <track> element with captions inside <audio> or <video> element")
@@ -17,6 +28,14 @@ fn media_has_caption_diagnostic(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
+fn media_has_caption_with_sources_diagnostic(source_span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Missing <track> element with captions inside <audio> or <video> element")
+        .with_help(
+            "<source> elements only provide the media, not captions. Add a <track> element with `kind=\"captions\"` as well.",
+        )
+        .with_label(source_span.label("This <source> does not provide captions"))
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct MediaHasCaption(Box<MediaHasCaptionConfig>);
 
@@ -25,6 +44,19 @@ pub struct MediaHasCaptionConfig {
     audio: Vec<Cow<'static, str>>,
     video: Vec<Cow<'static, str>>,
     track: Vec<Cow<'static, str>>,
+    source: Vec<Cow<'static, str>>,
+    /// Only accept `kind="captions"` in lower case.
+    case_sensitive_kind: bool,
+    /// Components which are never reported, e.g. ones which add captions themselves.
+    ignore_components: Vec<Cow<'static, str>>,
+    /// Do not accept a track whose `kind` is only known at runtime.
+    strict: bool,
+    /// Element categories (`"audio"`, `"video"`) which pass with a non-empty `aria-describedby`,
+    /// e.g. pointing to a transcript.
+    allow_aria_described_by: Vec<Cow<'static, str>>,
+    /// Accept an unknown component child as possibly rendering a captions track,
+    /// e.g. `<video><CaptionTrack /></video>`.
+    ignore_unknown_children: bool,
 }
 
 impl Default for MediaHasCaptionConfig {
@@ -33,6 +65,12 @@ impl Default for MediaHasCaptionConfig {
             audio: vec![Cow::Borrowed("audio")],
             video: vec![Cow::Borrowed("video")],
             track: vec![Cow::Borrowed("track")],
+            source: vec![Cow::Borrowed("source")],
+            case_sensitive_kind: false,
+            ignore_components: vec![],
+            strict: false,
+            allow_aria_described_by: vec![],
+            ignore_unknown_children: false,
         }
     }
 }
@@ -61,9 +99,62 @@ declare_oxc_lint!(
     /// <audio><track kind="captions" src="caption_file.vtt" /></audio>
     /// <video><track kind="captions" src="caption_file.vtt" /></video>
     /// ```
+    ///
+    /// Elements whose only child is a dynamic expression such as `{children}` or `{renderTracks()}`
+    /// are not reported, as the tracks cannot be known statically.
+    /// ```jsx
+    /// <video>{tracks}</video>
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `audio`, `video`, `track` and `source` are arrays of additional component names to treat
+    /// as `<audio>`, `<video>`, `<track>` and `<source>` elements. When a media element without
+    /// captions has `<source>` children, the first one is reported, as sources do not provide
+    /// captions.
+    ///
+    /// `caseSensitiveKind` (default `false`): when `true`, a track's `kind` must be exactly
+    /// `"captions"`, so `<track kind="Captions" />` is reported.
+    ///
+    /// `ignoreComponents` is an array of component names which are never reported, such as
+    /// components which add captions themselves. This applies even if the component is mapped
+    /// to `audio` or `video` in `settings.jsx-a11y.components`.
+    ///
+    /// `strict` (default `false`): a track's `kind` may be given by an expression which is only
+    /// known at runtime, such as `kind={trackKind}`. By default, such a track is assumed to
+    /// provide captions. When `true`, it is not, and the media element is reported.
+    ///
+    /// `allowAriaDescribedBy` is an array of element categories, `"audio"` and `"video"`, which
+    /// are not reported if they have an `aria-describedby` prop with a non-empty value, such as
+    /// audio content with a transcript. Defaults to `[]`.
+    ///
+    /// Examples of **correct** code for this rule with `{ "allowAriaDescribedBy": ["audio"] }`:
+    /// ```jsx
+    /// <audio src="podcast.mp3" aria-describedby="transcript" />
+    /// ```
+    ///
+    /// `ignoreUnknownChildren` (default `false`): when `true`, a media element with a child
+    /// component which is not mapped to an element, such as `<CaptionTrack />`, is not reported,
+    /// as the component may render a captions track itself. Children which are known not to be
+    /// tracks, such as `<source>`, are still reported.
+    ///
+    /// Examples of **correct** code for this rule with `{ "ignoreUnknownChildren": true }`:
+    /// ```jsx
+    /// <video><CaptionTrack /></video>
+    /// ```
+    ///
+    /// ```json
+    /// {
+    ///   "jsx-a11y/media-has-caption": [
+    ///     "error",
+    ///     { "caseSensitiveKind": true, "ignoreComponents": ["AccessibleVideo"], "strict": true }
+    ///   ]
+    /// }
+    /// ```
     MediaHasCaption,
     jsx_a11y,
-    correctness
+    correctness,
+    suggestion
 );
 
 impl Rule for MediaHasCaption {
@@ -91,6 +182,46 @@ impl Rule for MediaHasCaption {
                         track.iter().filter_map(Value::as_str).map(String::from).map(Into::into),
                     );
                 }
+                if let Some(source) = rule_config.get("source").and_then(Value::as_array) {
+                    config.source.extend(
+                        source.iter().filter_map(Value::as_str).map(String::from).map(Into::into),
+                    );
+                }
+                if let Some(ignore_components) =
+                    rule_config.get("ignoreComponents").and_then(Value::as_array)
+                {
+                    config.ignore_components.extend(
+                        ignore_components
+                            .iter()
+                            .filter_map(Value::as_str)
+                            .map(String::from)
+                            .map(Into::into),
+                    );
+                }
+                if let Some(case_sensitive_kind) =
+                    rule_config.get("caseSensitiveKind").and_then(Value::as_bool)
+                {
+                    config.case_sensitive_kind = case_sensitive_kind;
+                }
+                if let Some(strict) = rule_config.get("strict").and_then(Value::as_bool) {
+                    config.strict = strict;
+                }
+                if let Some(ignore_unknown_children) =
+                    rule_config.get("ignoreUnknownChildren").and_then(Value::as_bool)
+                {
+                    config.ignore_unknown_children = ignore_unknown_children;
+                }
+                if let Some(categories) =
+                    rule_config.get("allowAriaDescribedBy").and_then(Value::as_array)
+                {
+                    config.allow_aria_described_by.extend(
+                        categories
+                            .iter()
+                            .filter_map(Value::as_str)
+                            .map(String::from)
+                            .map(Into::into),
+                    );
+                }
                 break;
             }
         }
@@ -105,32 +236,38 @@ impl Rule for MediaHasCaption {
 
         let element_name = get_element_type(ctx, jsx_el);
 
-        let is_audio_or_video =
-            self.0.audio.contains(&element_name) || self.0.video.contains(&element_name);
-
-        // Bail out if the element is not an <audio /> or <video /> element.
-        if !is_audio_or_video {
+        // Bail out if the element is ignored, by either its own name or the element it is mapped to.
+        if self.0.ignore_components.iter().any(|ignored| {
+            *ignored == element_name || *ignored == ctx.source_range(jsx_el.name.span())
+        }) {
             return;
         }
 
-        let muted = jsx_el.attributes.iter().any(|attr_item| {
-            let JSXAttributeItem::Attribute(attr) = attr_item else { return false };
-
-            let JSXAttributeName::Identifier(iden) = &attr.name else { return false };
+        let category = if self.0.audio.contains(&element_name) {
+            "audio"
+        } else if self.0.video.contains(&element_name) {
+            "video"
+        } else {
+            // Bail out if the element is not an <audio /> or <video /> element.
+            return;
+        };
 
-            if iden.name == "muted" {
-                return match &attr.value {
-                    Some(JSXAttributeValue::ExpressionContainer(exp)) => match &exp.expression {
-                        JSXExpression::BooleanLiteral(boolean) => boolean.value,
-                        _ => false,
-                    },
-                    Some(JSXAttributeValue::StringLiteral(lit)) => lit.value == "true",
-                    None => true, // e.g. <video muted></video>
-                    _ => false,
-                };
-            }
+        // Bail out if the element is described by e.g. a transcript, and this is allowed.
+        // (e.g. `<audio aria-describedby="transcript" />`)
+        if self.0.allow_aria_described_by.iter().any(|allowed| allowed == category)
+            && has_aria_described_by(jsx_el)
+        {
+            return;
+        }
 
-            false
+        let muted = get_jsx_attribute(jsx_el, "muted").is_some_and(|attr| match &attr.value {
+            Some(JSXAttributeValue::ExpressionContainer(exp)) => match &exp.expression {
+                JSXExpression::BooleanLiteral(boolean) => boolean.value,
+                _ => false,
+            },
+            Some(JSXAttributeValue::StringLiteral(lit)) => lit.value == "true",
+            None => true, // e.g. <video muted></video>
+            _ => false,
         });
 
         // Bail out if the element is muted as captions are not required for muted media. (e.g <video muted />)
@@ -142,6 +279,12 @@ impl Rule for MediaHasCaption {
             return;
         };
 
+        // Bail out if the only child is dynamic, as it may render a captions track.
+        // (e.g. `<video>{children}</video>`)
+        if has_dynamic_children(&parent.children) {
+            return;
+        }
+
         let has_caption = if parent.children.is_empty() {
             ctx.diagnostic(media_has_caption_diagnostic(parent.opening_element.span));
             false
@@ -150,18 +293,13 @@ impl Rule for MediaHasCaption {
                 JSXChild::Element(child_el) => {
                     let child_name = get_element_type(ctx, &child_el.opening_element);
 
-                    self.0.track.contains(&child_name)
-                        && child_el.opening_element.attributes.iter().any(|attr| {
-                            let JSXAttributeItem::Attribute(attr) = attr else { return false };
-                            let JSXAttributeName::Identifier(iden) = &attr.name else {
-                                return false;
-                            };
-                            if let Some(JSXAttributeValue::StringLiteral(s)) = &attr.value {
-                                return iden.name == "kind"
-                                    && s.value.eq_ignore_ascii_case("captions");
-                            }
-                            false
-                        })
+                    if self.0.track.contains(&child_name) {
+                        self.is_captions_track(&child_el.opening_element)
+                    } else {
+                        // An unknown component may render a captions track itself.
+                        // (e.g. `<video><CaptionTrack /></video>`)
+                        self.0.ignore_unknown_children && self.is_unknown_component(&child_name)
+                    }
                 }
                 _ => false,
             })
@@ -170,11 +308,191 @@ impl Rule for MediaHasCaption {
         let span = parent.span;
 
         if !has_caption {
-            ctx.diagnostic(media_has_caption_diagnostic(span));
+            let track = format!(
+                r#"<{} kind="captions" src="" srcLang="en" label="English captions" />"#,
+                self.track_component_name(&parent.children, ctx)
+            );
+            // Point at the first `<source>`, if any, as it may look like it provides captions.
+            // (e.g. `<video><source src="movie.mp4" /></video>`)
+            let first_source = parent.children.iter().find_map(|child| match child {
+                JSXChild::Element(child_el)
+                    if self
+                        .0
+                        .source
+                        .contains(&get_element_type(ctx, &child_el.opening_element)) =>
+                {
+                    Some(child_el.span)
+                }
+                _ => None,
+            });
+            let diagnostic = first_source.map_or_else(
+                || media_has_caption_diagnostic(span),
+                media_has_caption_with_sources_diagnostic,
+            );
+            ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                let fix = if parent.closing_element.is_some() {
+                    fixer.insert_text_after_range(jsx_el.span, track)
+                } else {
+                    // `<video controls />` -> `<video controls><track ... /></video>`
+                    let last_token_end = jsx_el
+                        .attributes
+                        .last()
+                        .map(GetSpan::span)
+                        .or_else(|| jsx_el.type_arguments.as_ref().map(|args| args.span))
+                        .unwrap_or_else(|| jsx_el.name.span())
+                        .end;
+                    let element_name = fixer.source_range(jsx_el.name.span());
+                    fixer.replace(
+                        Span::new(last_token_end, jsx_el.span.end),
+                        format!(">{track}</{element_name}>"),
+                    )
+                };
+                fix.with_message("Insert a captions <track> element")
+            });
         }
     }
 }
 
+/// Returns `true` if `element` has an `aria-describedby` prop with a non-empty value.
+/// A value only known at runtime, e.g. `aria-describedby={transcriptId}`, is assumed to be non-empty.
+fn has_aria_described_by(element: &JSXOpeningElement) -> bool {
+    let Some(value) =
+        has_jsx_prop_ignore_case(element, "aria-describedby").and_then(get_prop_value)
+    else {
+        return false;
+    };
+    match value {
+        JSXAttributeValue::StringLiteral(lit) => !lit.value.trim().is_empty(),
+        JSXAttributeValue::ExpressionContainer(container) => container
+            .expression
+            .as_expression()
+            .is_some_and(|expr| match get_static_string_value(expr) {
+                Some(value) => !value.trim().is_empty(),
+                None => !expr.without_parentheses().is_literal(),
+            }),
+        _ => false,
+    }
+}
+
+/// Returns `true` if the only child (ignoring whitespace) is an identifier or call expression.
+fn has_dynamic_children(children: &[JSXChild]) -> bool {
+    let mut children = children.iter().filter(|child| match child {
+        JSXChild::Text(text) => !text.value.trim().is_empty(),
+        _ => true,
+    });
+    let (Some(JSXChild::ExpressionContainer(container)), None) = (children.next(), children.next())
+    else {
+        return false;
+    };
+    matches!(container.expression, JSXExpression::Identifier(_) | JSXExpression::CallExpression(_))
+}
+
+/// The `kind` prop of a `<track>` element.
+enum TrackKind<'a> {
+    /// A string known statically, e.g. `kind="captions"` or `` kind={`captions`} ``.
+    String(Atom<'a>),
+    /// A value only known at runtime, e.g. `kind={trackKind}`.
+    Dynamic,
+    /// No value, or a value which is not a string, e.g. `kind={1}`.
+    Other,
+}
+
+impl<'a> TrackKind<'a> {
+    /// Find the last `kind` prop of `track`, including in an inline object spread such as
+    /// `{...{ kind: "captions" }}`, as later props override earlier ones.
+    fn of_track(track: &JSXOpeningElement<'a>) -> Option<Self> {
+        track.attributes.iter().rev().find_map(|attr| match attr {
+            JSXAttributeItem::Attribute(attr) => {
+                if !attr.is_identifier("kind") {
+                    return None;
+                }
+                Some(match &attr.value {
+                    Some(JSXAttributeValue::StringLiteral(lit)) => Self::String(lit.value),
+                    Some(JSXAttributeValue::ExpressionContainer(container)) => container
+                        .expression
+                        .as_expression()
+                        .map_or(Self::Other, Self::of_expression),
+                    _ => Self::Other,
+                })
+            }
+            JSXAttributeItem::SpreadAttribute(spread) => {
+                let Expression::ObjectExpression(object) = spread.argument.without_parentheses()
+                else {
+                    return None;
+                };
+                object.properties.iter().rev().find_map(|prop| match prop {
+                    ObjectPropertyKind::ObjectProperty(prop)
+                        if prop.key.is_specific_static_name("kind") =>
+                    {
+                        Some(Self::of_expression(&prop.value))
+                    }
+                    _ => None,
+                })
+            }
+        })
+    }
+
+    fn of_expression(expr: &Expression<'a>) -> Self {
+        if let Some(kind) = get_static_string_value(expr) {
+            Self::String(kind)
+        } else if expr.without_parentheses().is_literal() {
+            Self::Other
+        } else {
+            Self::Dynamic
+        }
+    }
+}
+
+impl MediaHasCaption {
+    /// Whether `track` provides captions. A `kind` only known at runtime may be `"captions"`,
+    /// so it is accepted unless the `strict` option is enabled.
+    fn is_captions_track(&self, track: &JSXOpeningElement) -> bool {
+        match TrackKind::of_track(track) {
+            Some(TrackKind::String(kind)) => self.is_captions_kind(&kind),
+            Some(TrackKind::Dynamic) => !self.0.strict,
+            Some(TrackKind::Other) | None => false,
+        }
+    }
+
+    /// Whether `name` is a component which is not mapped to an element, either in
+    /// `settings.jsx-a11y.components` or by the `audio`, `video` and `source` options.
+    fn is_unknown_component(&self, name: &str) -> bool {
+        name.starts_with(|c: char| c.is_ascii_uppercase())
+            && ![&self.0.audio, &self.0.video, &self.0.source]
+                .into_iter()
+                .flatten()
+                .any(|known| known == name)
+    }
+
+    fn is_captions_kind(&self, kind: &str) -> bool {
+        if self.0.case_sensitive_kind {
+            kind == "captions"
+        } else {
+            kind.eq_ignore_ascii_case("captions")
+        }
+    }
+
+    /// Name of the element to use for a suggested `<track>`. Prefers the name of an existing
+    /// child which resolves to a track element, then a custom component from the rule config.
+    fn track_component_name<'c, 'a>(
+        &'c self,
+        children: &[JSXChild<'a>],
+        ctx: &'c LintContext<'a>,
+    ) -> &'c str {
+        let existing = children.iter().find_map(|child| match child {
+            JSXChild::Element(child_el)
+                if self.0.track.contains(&get_element_type(ctx, &child_el.opening_element)) =>
+            {
+                Some(ctx.source_range(child_el.opening_element.name.span()))
+            }
+            _ => None,
+        });
+        existing
+            .or_else(|| self.0.track.iter().find(|track| *track != "track").map(AsRef::as_ref))
+            .unwrap_or("track")
+    }
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -233,6 +551,107 @@ fn test() {
         (r"<Audio muted></Audio>", None, Some(settings())),
         (r"<Audio muted={true}></Audio>", None, Some(settings())),
         (r"<Box as='audio' muted={true}></Box>", None, Some(settings())),
+        (r"<video>{children}</video>", None, None),
+        (r"<audio>{children}</audio>", None, None),
+        (r"<video>{renderTracks()}</video>", None, None),
+        ("<video>\n  {tracks}\n</video>", None, None),
+        (r"<Video as='video'>{tracks}</Video>", None, Some(settings())),
+        (r"<video><track kind='CAPTIONS' /></video>", None, None),
+        (r#"<video><track kind={"captions"} /></video>"#, None, None),
+        (r"<video><track kind={`captions`} /></video>", None, None),
+        (r#"<video><track kind={("captions")} /></video>"#, None, None),
+        (r#"<video><track {...{ kind: "captions" }} /></video>"#, None, None),
+        (r"<video><track {...{ kind: `captions`, src }} /></video>", None, None),
+        (r#"<video><track kind="subtitles" {...{ kind: "captions" }} /></video>"#, None, None),
+        (r#"<video><track {...{ kind: "subtitles" }} kind="captions" /></video>"#, None, None),
+        (r"<video><track kind={trackKind} /></video>", None, None),
+        (r"<video><track kind={`${prefix}captions`} /></video>", None, None),
+        (r"<video><track {...{ kind }} /></video>", None, None),
+        (
+            r#"<video><track kind={"captions"} /></video>"#,
+            Some(serde_json::json!([{ "strict": true }])),
+            None,
+        ),
+        (
+            r#"<video><track {...{ kind: "captions" }} /></video>"#,
+            Some(serde_json::json!([{ "strict": true }])),
+            None,
+        ),
+        (
+            r"<video><track kind='CAPTIONS' /></video>",
+            Some(serde_json::json!([{ "caseSensitiveKind": false }])),
+            None,
+        ),
+        (
+            r"<video><track kind='captions' /></video>",
+            Some(serde_json::json!([{ "caseSensitiveKind": true }])),
+            None,
+        ),
+        (
+            r"<AccessibleVideo />",
+            Some(
+                serde_json::json!([{ "video": ["AccessibleVideo"], "ignoreComponents": ["AccessibleVideo"] }]),
+            ),
+            None,
+        ),
+        (
+            r"<AccessibleVideo />",
+            Some(serde_json::json!([{ "ignoreComponents": ["AccessibleVideo"] }])),
+            Some(serde_json::json!({
+                "settings": { "jsx-a11y": { "components": { "AccessibleVideo": "video" } } }
+            })),
+        ),
+        (
+            r#"<Foo.Video><track kind="captions" /></Foo.Video>"#,
+            None,
+            Some(serde_json::json!({
+                "settings": { "jsx-a11y": { "components": { "Foo.Video": "video" } } }
+            })),
+        ),
+        (
+            r#"<A.B.Video><A.B.Track kind="captions" /></A.B.Video>"#,
+            None,
+            Some(serde_json::json!({
+                "settings": { "jsx-a11y": { "components": { "A.B.Video": "video", "A.B.Track": "track" } } }
+            })),
+        ),
+        (r"<Foo.Video />", None, None),
+        (r"<svg:video />", None, None),
+        (
+            r#"<audio aria-describedby="transcript" />"#,
+            Some(serde_json::json!([{ "allowAriaDescribedBy": ["audio"] }])),
+            None,
+        ),
+        (
+            r#"<audio aria-describedby={"transcript"}></audio>"#,
+            Some(serde_json::json!([{ "allowAriaDescribedBy": ["audio"] }])),
+            None,
+        ),
+        (
+            r"<audio aria-describedby={transcriptId}><source src='podcast.mp3' /></audio>",
+            Some(serde_json::json!([{ "allowAriaDescribedBy": ["audio"] }])),
+            None,
+        ),
+        (
+            r#"<Audio aria-describedby="transcript" />"#,
+            Some(serde_json::json!([{ "allowAriaDescribedBy": ["audio"] }])),
+            Some(settings()),
+        ),
+        (
+            r#"<video aria-describedby="transcript" />"#,
+            Some(serde_json::json!([{ "allowAriaDescribedBy": ["audio", "video"] }])),
+            None,
+        ),
+        (
+            r"<video><CaptionTrack /></video>",
+            Some(serde_json::json!([{ "ignoreUnknownChildren": true }])),
+            None,
+        ),
+        (
+            r"<audio><source src='podcast.mp3' /><Captions.Track /></audio>",
+            Some(serde_json::json!([{ "ignoreUnknownChildren": true }])),
+            None,
+        ),
     ];
 
     let fail = vec![
@@ -241,6 +660,23 @@ fn test() {
         (r"<audio />", None, None),
         (r"<video><track /></video>", None, None),
         (r"<video><track kind='subtitles' /></video>", None, None),
+        (r#"<video><track kind={"subtitles"} /></video>"#, None, None),
+        (r"<video><track kind={`subtitles`} /></video>", None, None),
+        (r"<video><track kind={1} /></video>", None, None),
+        (r"<video><track kind /></video>", None, None),
+        (r#"<video><track {...{ kind: "subtitles" }} /></video>"#, None, None),
+        (r#"<video><track kind="captions" {...{ kind: "subtitles" }} /></video>"#, None, None),
+        (r"<video><track {...props} /></video>", None, None),
+        (
+            r"<video><track kind={trackKind} /></video>",
+            Some(serde_json::json!([{ "strict": true }])),
+            None,
+        ),
+        (
+            r"<video><track {...{ kind: trackKind }} /></video>",
+            Some(serde_json::json!([{ "strict": true }])),
+            None,
+        ),
         (r"<Audio muted={false}></Audio>", Some(config()), None),
         (r"<Video muted={false}></Video>", Some(config()), None),
         (r"<Audio muted={false}></Audio>", None, Some(settings())),
@@ -259,7 +695,145 @@ fn test() {
         (r"<Audio><Track kind='subtitles' /></Audio>", None, Some(settings())),
         (r"<Video><Track kind='subtitles' /></Video>", None, Some(settings())),
         (r"<Box as='audio'><Track kind='subtitles' /></Box>", None, Some(settings())),
+        (r"<video>{'Foo'}</video>", None, None),
+        (r"<video>{children}<track kind='subtitles' /></video>", None, None),
+        (r"<video>{}</video>", None, None),
+        (r"<video><source src='movie.webm' /><source src='movie.mp4' /></video>", None, None),
+        (
+            r"<audio>
+                <source src='podcast.ogg' />
+                <track kind='subtitles' />
+            </audio>",
+            None,
+            None,
+        ),
+        (
+            r"<video><Source src='movie.mp4' /></video>",
+            Some(serde_json::json!([{ "source": ["Source"] }])),
+            None,
+        ),
+        (
+            r"<video><track kind='Captions' /></video>",
+            Some(serde_json::json!([{ "caseSensitiveKind": true }])),
+            None,
+        ),
+        (
+            r"<audio><track kind='CAPTIONS' /></audio>",
+            Some(serde_json::json!([{ "caseSensitiveKind": true }])),
+            None,
+        ),
+        (
+            r"<video />",
+            Some(serde_json::json!([{ "ignoreComponents": ["AccessibleVideo"] }])),
+            Some(serde_json::json!({
+                "settings": { "jsx-a11y": { "components": { "AccessibleVideo": "video" } } }
+            })),
+        ),
+        (
+            r"<Foo.Video />",
+            None,
+            Some(serde_json::json!({
+                "settings": { "jsx-a11y": { "components": { "Foo.Video": "video" } } }
+            })),
+        ),
+        (
+            r#"<A.B.Video><track kind="subtitles" /></A.B.Video>"#,
+            None,
+            Some(serde_json::json!({
+                "settings": { "jsx-a11y": { "components": { "A.B.Video": "video" } } }
+            })),
+        ),
+        (
+            r"<svg:video />",
+            None,
+            Some(serde_json::json!({
+                "settings": { "jsx-a11y": { "components": { "svg:video": "video" } } }
+            })),
+        ),
+        (r#"<audio aria-describedby="transcript" />"#, None, None),
+        (
+            r#"<video aria-describedby="transcript" />"#,
+            Some(serde_json::json!([{ "allowAriaDescribedBy": ["audio"] }])),
+            None,
+        ),
+        (
+            r#"<audio aria-describedby="" />"#,
+            Some(serde_json::json!([{ "allowAriaDescribedBy": ["audio"] }])),
+            None,
+        ),
+        (
+            r#"<audio aria-describedby={""} />"#,
+            Some(serde_json::json!([{ "allowAriaDescribedBy": ["audio"] }])),
+            None,
+        ),
+        (
+            r"<audio aria-describedby />",
+            Some(serde_json::json!([{ "allowAriaDescribedBy": ["audio"] }])),
+            None,
+        ),
+        (r"<video><CaptionTrack /></video>", None, None),
+        (
+            r"<video><source src='movie.mp4' /></video>",
+            Some(serde_json::json!([{ "ignoreUnknownChildren": true }])),
+            None,
+        ),
+        (
+            r"<video><Source src='movie.mp4' /></video>",
+            Some(serde_json::json!([{ "source": ["Source"], "ignoreUnknownChildren": true }])),
+            None,
+        ),
+        (
+            r"<Video><Track kind='subtitles' /></Video>",
+            Some(serde_json::json!([{ "ignoreUnknownChildren": true }])),
+            Some(settings()),
+        ),
+        (
+            r"<video><captionTrack /></video>",
+            Some(serde_json::json!([{ "ignoreUnknownChildren": true }])),
+            None,
+        ),
+    ];
+
+    let fix = vec![
+        (
+            r"<video><source src='movie.mp4' /></video>",
+            r#"<video><track kind="captions" src="" srcLang="en" label="English captions" /><source src='movie.mp4' /></video>"#,
+            None,
+        ),
+        (
+            r"<audio>Foo</audio>",
+            r#"<audio><track kind="captions" src="" srcLang="en" label="English captions" />Foo</audio>"#,
+            None,
+        ),
+        (
+            r"<video />",
+            r#"<video><track kind="captions" src="" srcLang="en" label="English captions" /></video>"#,
+            None,
+        ),
+        (
+            "<video\n  src='movie.mp4'\n  controls\n/>",
+            "<video\n  src='movie.mp4'\n  controls><track kind=\"captions\" src=\"\" srcLang=\"en\" label=\"English captions\" /></video>",
+            None,
+        ),
+        (
+            r"<Video />",
+            r#"<Video><Track kind="captions" src="" srcLang="en" label="English captions" /></Video>"#,
+            Some(config()),
+        ),
+        (
+            r"<Video><Track kind='subtitles' /></Video>",
+            r#"<Video><Track kind="captions" src="" srcLang="en" label="English captions" /><Track kind='subtitles' /></Video>"#,
+            Some(config()),
+        ),
+        // The name of an existing track element is reused.
+        (
+            r"<video><CaptionTrack kind='subtitles' /></video>",
+            r#"<video><CaptionTrack kind="captions" src="" srcLang="en" label="English captions" /><CaptionTrack kind='subtitles' /></video>"#,
+            Some(serde_json::json!([{ "track": ["Track", "CaptionTrack"] }])),
+        ),
     ];
 
-    Tester::new(MediaHasCaption::NAME, MediaHasCaption::PLUGIN, pass, fail).test_and_snapshot();
+    Tester::new(MediaHasCaption::NAME, MediaHasCaption::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
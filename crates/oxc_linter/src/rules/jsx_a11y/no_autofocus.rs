@@ -8,7 +8,7 @@ use crate::{
     context::LintContext,
     globals::HTML_TAG,
     rule::Rule,
-    utils::{get_element_type, has_jsx_prop},
+    utils::{get_element_type, has_jsx_prop_ignore_case},
 };
 
 fn no_autofocus_diagnostic(span: Span) -> OxcDiagnostic {
@@ -25,9 +25,9 @@ pub struct NoAutofocus {
 declare_oxc_lint!(
     /// ### What it does
     ///
-    /// Enforce that `autoFocus` prop is not used on elements. Autofocusing
-    /// elements can cause usability issues for sighted and non-sighted users,
-    /// alike.
+    /// Enforce that the `autoFocus` prop, or `autofocus` attribute, is not used
+    /// on elements. Autofocusing elements can cause usability issues for sighted
+    /// and non-sighted users, alike.
     ///
     /// ### Rule Option
     ///
@@ -44,7 +44,9 @@ declare_oxc_lint!(
     /// ```
     ///
     /// For the `ignoreNonDOM` option, this determines if developer created
-    /// components are checked.
+    /// components are checked. Components mapped to DOM elements in
+    /// `settings.jsx-a11y.components`, or rendered as a DOM element through
+    /// `settings.jsx-a11y.polymorphicPropName`, are still checked.
     ///
     /// ### Examples
     ///
@@ -54,6 +56,7 @@ declare_oxc_lint!(
     /// <div autoFocus="true" />
     /// <div autoFocus="false" />
     /// <div autoFocus={undefined} />
+    /// <input autofocus />
     /// ```
     ///
     /// Examples of **correct** code for this rule:
@@ -96,28 +99,24 @@ impl Rule for NoAutofocus {
         let AstKind::JSXElement(jsx_el) = node.kind() else {
             return;
         };
-        let Some(autofocus) = has_jsx_prop(&jsx_el.opening_element, "autoFocus") else {
+        // `autofocus` is the HTML attribute, `autoFocus` the React prop
+        let Some(JSXAttributeItem::Attribute(attr)) =
+            has_jsx_prop_ignore_case(&jsx_el.opening_element, "autoFocus")
+        else {
             return;
         };
 
-        let element_type = get_element_type(ctx, &jsx_el.opening_element);
-
         if self.ignore_non_dom {
-            if HTML_TAG.contains(element_type.as_ref()) {
-                if let JSXAttributeItem::Attribute(attr) = autofocus {
-                    ctx.diagnostic_with_fix(no_autofocus_diagnostic(attr.span), |fixer| {
-                        fixer.delete(&attr.span)
-                    });
-                }
+            // Resolves polymorphic elements and `settings.jsx-a11y.components`
+            let element_type = get_element_type(ctx, &jsx_el.opening_element);
+            if !HTML_TAG.contains(element_type.as_ref()) {
+                return;
             }
-            return;
         }
 
-        if let JSXAttributeItem::Attribute(attr) = autofocus {
-            ctx.diagnostic_with_fix(no_autofocus_diagnostic(attr.span), |fixer| {
-                fixer.delete(&attr.span)
-            });
-        }
+        ctx.diagnostic_with_fix(no_autofocus_diagnostic(attr.span), |fixer| {
+            fixer.delete(&attr.span)
+        });
     }
 }
 
@@ -140,14 +139,22 @@ fn test() {
         })
     }
 
+    fn polymorphic_settings() -> serde_json::Value {
+        serde_json::json!({
+            "settings": { "jsx-a11y": {
+                "polymorphicPropName": "as",
+            } }
+        })
+    }
+
     let pass = vec![
         ("<div />;", None, None),
-        ("<div autofocus />;", None, None),
-        ("<input autofocus='true' />;", None, None),
         ("<Foo bar />", None, None),
         ("<Button />", None, None),
         ("<Foo autoFocus />", Some(config()), None),
-        ("<div><div autofocus /></div>", Some(config()), None),
+        ("<div><Foo autofocus /></div>", Some(config()), None),
+        ("<Box as='Foo' autoFocus />", Some(config()), Some(polymorphic_settings())),
+        ("<Box autoFocus />", Some(config()), Some(polymorphic_settings())),
         ("<Button />", None, Some(settings())),
         ("<Button />", Some(config()), Some(settings())),
     ];
@@ -163,6 +170,12 @@ fn test() {
         ("<Foo autoFocus />", None, None),
         ("<Button autoFocus />", None, None),
         ("<Button autoFocus />", Some(config()), Some(settings())),
+        ("<div autofocus />;", None, None),
+        ("<input autofocus='true' />;", None, None),
+        ("<div><div autofocus /></div>", Some(config()), None),
+        ("<Box as='input' autoFocus />", None, Some(polymorphic_settings())),
+        ("<Box as='input' autoFocus />", Some(config()), Some(polymorphic_settings())),
+        ("<Box as='input' autofocus />", Some(config()), Some(polymorphic_settings())),
     ];
 
     let fix = vec![
@@ -173,6 +186,7 @@ fn test() {
         ("<input autoFocus />", "<input  />"),
         ("<div autoFocus>foo</div>", "<div >foo</div>"),
         ("<div autoFocus id='lol'>foo</div>", "<div  id='lol'>foo</div>"),
+        ("<input autofocus />", "<input  />"),
     ];
 
     Tester::new(NoAutofocus::NAME, NoAutofocus::PLUGIN, pass, fail)
@@ -26,6 +26,19 @@ pub struct CatchOrReturnConfig {
     allow_finally: bool,
     allow_then: bool,
     termination_method: Vec<CompactStr>,
+    async_await: AsyncAwait,
+}
+
+/// How promise chains in async functions are checked.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum AsyncAwait {
+    /// Check awaited promise chains too, e.g. `await foo().then(bar)`.
+    Always,
+    /// Do not check promise chains in async functions.
+    Never,
+    /// Check promise chains in async functions like anywhere else.
+    #[default]
+    Ignore,
 }
 
 impl Default for CatchOrReturnConfig {
@@ -34,6 +47,7 @@ impl Default for CatchOrReturnConfig {
             allow_finally: false,
             allow_then: false,
             termination_method: vec![CompactStr::new("catch")],
+            async_await: AsyncAwait::default(),
         }
     }
 }
@@ -74,6 +88,48 @@ declare_oxc_lint!(
     ///  return myPromise.then(doSomething)
     /// }
     /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### allowThen
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Allow `.then(onFulfilled, onRejected)` to terminate a chain, as it handles rejections.
+    ///
+    /// #### allowFinally
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Allow `.finally()` after a terminated chain, e.g. `promise.catch(handle).finally(cleanup)`.
+    ///
+    /// #### terminationMethod
+    ///
+    /// `{ type: string | string[], default: "catch" }`
+    ///
+    /// Methods which terminate a chain, e.g. `["catch", "done"]`.
+    ///
+    /// #### asyncAwait
+    ///
+    /// `{ type: "always" | "never" | "ignore", default: "ignore" }`
+    ///
+    /// How promise chains in async functions are checked. With `"always"`, awaited chains must
+    /// be terminated too, e.g. `await foo().then(bar)`. With `"never"`, promise chains inside
+    /// async functions are exempt. With `"ignore"`, async functions are checked like any other.
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "asyncAwait": "always" }`:
+    /// ```javascript
+    /// async function foo() {
+    ///   await myPromise.then(doSomething)
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with `{ "asyncAwait": "never" }`:
+    /// ```javascript
+    /// async function foo() {
+    ///   myPromise.then(doSomething)
+    /// }
+    /// ```
     CatchOrReturn,
     promise,
     restriction,
@@ -115,6 +171,16 @@ impl Rule for CatchOrReturn {
             config.allow_then = allow_then_config;
         }
 
+        if let Some(async_await_config) =
+            value.get(0).and_then(|v| v.get("asyncAwait")).and_then(serde_json::Value::as_str)
+        {
+            config.async_await = match async_await_config {
+                "always" => AsyncAwait::Always,
+                "never" => AsyncAwait::Never,
+                _ => AsyncAwait::Ignore,
+            };
+        }
+
         Self(Box::new(config))
     }
 
@@ -123,10 +189,24 @@ impl Rule for CatchOrReturn {
             return;
         };
 
-        let Expression::CallExpression(call_expr) = &expr_stmt.expression else {
-            return;
+        let call_expr = match &expr_stmt.expression {
+            Expression::CallExpression(call_expr) => call_expr,
+            // `await foo().then(bar)`
+            Expression::AwaitExpression(await_expr) if self.async_await == AsyncAwait::Always => {
+                let Expression::CallExpression(call_expr) =
+                    await_expr.argument.without_parentheses()
+                else {
+                    return;
+                };
+                call_expr
+            }
+            _ => return,
         };
 
+        if self.async_await == AsyncAwait::Never && is_in_async_function(node, ctx) {
+            return;
+        }
+
         // Check for a promise or a method call at the end of a promise for example:
         // foo().catch().randomFunc()
         if is_promise(call_expr).is_none() && !is_part_of_promise(call_expr) {
@@ -191,6 +271,14 @@ impl CatchOrReturn {
     }
 }
 
+fn is_in_async_function(node: &AstNode, ctx: &LintContext) -> bool {
+    ctx.enclosing_function(node.id()).is_some_and(|function| match function.kind() {
+        AstKind::Function(function) => function.r#async,
+        AstKind::ArrowFunctionExpression(arrow) => arrow.r#async,
+        _ => false,
+    })
+}
+
 fn is_part_of_promise(call_expr: &CallExpression) -> bool {
     let Some(member_expr) = call_expr.callee.get_member_expr() else {
         return false;
@@ -306,6 +394,32 @@ fn test() {
         ("nonPromiseExpressionStatement();", None),
         ("frank().then(go)['catch']", None),
         ("await foo().then(bar)", None),
+        // asyncAwait
+        (
+            "async function a() { frank().then(go) }",
+            Some(serde_json::json!([{ "asyncAwait": "never" }])),
+        ),
+        (
+            "const a = async () => { frank().then(go) }",
+            Some(serde_json::json!([{ "asyncAwait": "never" }])),
+        ),
+        ("async function a() { await frank().then(go) }", None),
+        (
+            "async function a() { await frank().then(go) }",
+            Some(serde_json::json!([{ "asyncAwait": "ignore" }])),
+        ),
+        (
+            "async function a() { await frank().then(go).catch(doIt) }",
+            Some(serde_json::json!([{ "asyncAwait": "always" }])),
+        ),
+        (
+            "async function a() { return frank().then(go) }",
+            Some(serde_json::json!([{ "asyncAwait": "always" }])),
+        ),
+        (
+            "async function a() { await foo() }",
+            Some(serde_json::json!([{ "asyncAwait": "always" }])),
+        ),
         // Cypress
         ("cy.get('.myClass').then(go)", None),
         ("cy.get('button').click().then()", None),
@@ -342,6 +456,25 @@ fn test() {
         ("frank().catch(go)", Some(serde_json::json!([{ "terminationMethod": "done" }]))),
         ("frank().catch(go).someOtherMethod()", None),
         ("frank()['catch'](go).someOtherMethod()", None),
+        // asyncAwait
+        ("async function a() { frank().then(go) }", None),
+        (
+            "async function a() { frank().then(go) }",
+            Some(serde_json::json!([{ "asyncAwait": "ignore" }])),
+        ),
+        (
+            "async function a() { await frank().then(go) }",
+            Some(serde_json::json!([{ "asyncAwait": "always" }])),
+        ),
+        (
+            "async function a() { await (frank().then(go)) }",
+            Some(serde_json::json!([{ "asyncAwait": "always" }])),
+        ),
+        (
+            "async function a() { function b() { frank().then(go) } }",
+            Some(serde_json::json!([{ "asyncAwait": "never" }])),
+        ),
+        ("function a() { frank().then(go) }", Some(serde_json::json!([{ "asyncAwait": "never" }]))),
     ];
 
     Tester::new(CatchOrReturn::NAME, CatchOrReturn::PLUGIN, pass, fail).test_and_snapshot();
@@ -0,0 +1,820 @@
+#[cfg(test)]
+mod tests;
+
+use oxc_ast::{
+    AstKind, AstType,
+    ast::{
+        Argument, BindingPatternKind, CallExpression, Expression, IdentifierReference,
+        JSXAttributeItem, JSXAttributeName, JSXAttributeValue, JSXElement, JSXElementName,
+        JSXFragment, JSXOpeningElement, ObjectPropertyKind, TSType,
+    },
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use serde::Deserialize;
+use tracing::{Level, enabled, trace};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{
+        InsideArrayOrIterator, IteratorMethod, get_jsx_attribute, get_jsx_attribute_value,
+        is_import, is_in_array_or_iter, is_rendered, is_within_children_to_array,
+    },
+};
+
+fn missing_key_prop_for_element_in_array(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(r#"Missing "key" prop for element in array."#).with_label(span)
+}
+
+fn missing_key_prop_for_element_in_iterator(iter_span: Span, el_span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(r#"Missing "key" prop for element in iterator."#)
+        .with_help(r#"Add a "key" prop to the element in the iterator (https://react.dev/learn/rendering-lists#keeping-list-items-in-order-with-key)."#)
+        .with_labels([
+            iter_span.label("Iterator starts here."),
+            el_span.label("Element generated here."),
+        ])
+}
+
+fn key_prop_must_be_placed_before_spread(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(r#""key" prop must be placed before any `{...spread}`"#)
+        .with_help("To avoid conflicting with React's new JSX transform: https://reactjs.org/blog/2020/09/22/introducing-the-new-jsx-transform.html")
+        .with_label(span)
+}
+
+fn key_read_from_props(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(r#""key" prop is read from props"#)
+        .with_help(r#"React does not pass "key" to components, so it is always `undefined` in props. Pass the value under another prop name, e.g. `id`."#)
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct JsxKey(Box<JsxKeyConfig>);
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct JsxKeyConfig {
+    /// Only report elements in array literals when the array is rendered.
+    only_flag_rendered_arrays: bool,
+    /// Report elements in arrays returned from `.reduce()` callbacks as iterator elements.
+    check_reduce: bool,
+    /// When to report `key` props placed after a spread attribute.
+    check_key_must_before_spread: CheckKeyMustBeforeSpread,
+    /// Methods and functions to treat as iterators, in addition to `map`, `flatMap` and `from`.
+    additional_iterator_methods: Vec<IteratorMethod>,
+    /// Report `key` props whose value is read from props, e.g. `key={props.key}`.
+    check_props_key: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum CheckKeyMustBeforeSpread {
+    /// Report unless `settings.react.version` is a version where the order does not matter.
+    #[default]
+    Auto,
+    /// Always report, regardless of `settings.react.version`.
+    Always,
+}
+
+/// First React version where placing `key` after a spread does not change how the element
+/// is created by the automatic JSX runtime.
+const KEY_AFTER_SPREAD_SAFE_VERSION: (u32, u32, u32) = (19, 0, 0);
+
+/// `tracing` target of the rule's decision trace, for debugging reports of the rule firing or
+/// not firing unexpectedly. Internal, the output is not stable.
+///
+/// `OXC_LOG=oxc_linter::trace::jsx_key=trace oxlint -A all -D react/jsx-key file.jsx`
+const TRACE_TARGET: &str = "oxc_linter::trace::jsx_key";
+
+/// Decision trace of one candidate node, logged as a single line to [`TRACE_TARGET`] by
+/// [`DecisionTrace::finish`]. Nothing is collected unless the target is enabled.
+struct DecisionTrace(Option<TraceLine>);
+
+struct TraceLine {
+    candidate: String,
+    /// Kinds of the ancestors walked by [`is_in_array_or_iter`].
+    visited: Vec<AstType>,
+    /// Decision of the walk, and the exemptions consulted, with their results.
+    steps: Vec<String>,
+}
+
+impl DecisionTrace {
+    fn new(candidate: impl FnOnce() -> String) -> Self {
+        let enabled = enabled!(target: TRACE_TARGET, Level::TRACE);
+        Self(enabled.then(|| TraceLine { candidate: candidate(), visited: vec![], steps: vec![] }))
+    }
+
+    fn visited(&mut self) -> Option<&mut Vec<AstType>> {
+        self.0.as_mut().map(|line| &mut line.visited)
+    }
+
+    fn step(&mut self, step: impl FnOnce() -> String) {
+        if let Some(line) = &mut self.0 {
+            line.steps.push(step());
+        }
+    }
+
+    fn finish(self, result: &str) {
+        let Some(line) = self.0 else { return };
+        let visited = line.visited.iter().map(|kind| format!("{kind:?}")).collect::<Vec<_>>();
+        trace!(
+            target: TRACE_TARGET,
+            "{}: walked [{}], {}, result: {result}",
+            line.candidate,
+            visited.join(" > "),
+            line.steps.join(", ")
+        );
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce `key` prop for elements in array
+    ///
+    /// ### Why is this bad?
+    ///
+    /// React requires a `key` prop for elements in an array to help identify which items have changed, are added, or are removed.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// [1, 2, 3].map(x => <App />);
+    /// [1, 2, 3]?.map(x => <BabelEslintApp />)
+    ///
+    /// const nodes = [];
+    /// nodes.push(<A />);
+    /// return <div>{nodes}</div>;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// [1, 2, 3].map(x => <App key={x} />);
+    /// [1, 2, 3]?.map(x => <BabelEslintApp key={x} />)
+    /// ```
+    ///
+    /// A `key` passed in an object literal spread into the element is recognized:
+    /// ```jsx
+    /// items.map((x) => <App {...{ key: x.id }} />);
+    /// ```
+    ///
+    /// Elements created with `React.createElement()` or `React.cloneElement()` are checked too.
+    /// Their props are reported if they are missing, `null`, or an object literal without a `key`.
+    /// ```jsx
+    /// items.map((item) => React.createElement(Row, { item }));
+    /// items.map((item) => React.cloneElement(template, null));
+    /// ```
+    ///
+    /// Elements pushed into an array are only reported when the array is a `const` or `let`
+    /// initialized to an array literal in the same function, and it is rendered but not passed
+    /// anywhere else.
+    ///
+    /// ### Options
+    ///
+    /// #### onlyFlagRenderedArrays
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// When `true`, elements in array literals are only reported if the array is rendered,
+    /// i.e. it is used as a JSX child or attribute value, returned from a function,
+    /// passed to a render call such as `root.render()`, or assigned to a variable which is.
+    /// Arrays which are only passed to other functions are not reported.
+    /// Elements created by iterators such as `.map()` are always reported.
+    ///
+    /// Examples of **correct** code for this rule with `{ "onlyFlagRenderedArrays": true }`:
+    /// ```jsx
+    /// const tabs = [<TabA />, <TabB />];
+    /// registerTabs(tabs);
+    /// ```
+    ///
+    /// #### checkReduce
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// When `true`, elements in an array literal returned from a `.reduce()` callback are
+    /// reported as elements of an iterator, since the array accumulates a list of elements.
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "checkReduce": true }`:
+    /// ```jsx
+    /// items.reduce((acc, x) => [...acc, <Item />], []);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with `{ "checkReduce": true }`:
+    /// ```jsx
+    /// items.reduce((acc, x) => [...acc, <Item key={x.id} />], []);
+    /// ```
+    ///
+    /// #### checkKeyMustBeforeSpread
+    ///
+    /// `{ type: "auto" | "always", default: "auto" }`
+    ///
+    /// A `key` prop placed after a `{...spread}` attribute is reported, because the new JSX
+    /// transform falls back to `createElement` for such elements. With `"auto"`, this is not
+    /// reported when `settings.react.version` is 19 or later. With `"always"`, it is reported
+    /// regardless of the React version.
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "checkKeyMustBeforeSpread": "always" }`:
+    /// ```jsx
+    /// <App {...props} key="app" />;
+    /// ```
+    ///
+    /// #### additionalIteratorMethods
+    ///
+    /// `{ type: Array<string | { name: string, callbackIndex?: number }>, default: [] }`
+    ///
+    /// Methods and functions whose callback returns elements of the resulting array, in
+    /// addition to `map`, `flatMap` and `Array.from`. They match both method calls and plain
+    /// function calls. The callback is the first argument, unless `callbackIndex` is given.
+    ///
+    /// Examples of **incorrect** code for this rule with
+    /// `{ "additionalIteratorMethods": ["eachElement", { "name": "map", "callbackIndex": 1 }] }`:
+    /// ```jsx
+    /// items.eachElement((x) => <Item />);
+    /// _.map(items, (x) => <Item />);
+    /// ```
+    ///
+    /// #### checkPropsKey
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// When `true`, a `key` prop whose value is read from props is reported. React does not pass
+    /// `key` to components, so `props.key` is always `undefined`. Objects named `props`, ending
+    /// in `Props`, or `this.props` are treated as props.
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "checkPropsKey": true }`:
+    /// ```jsx
+    /// <Row key={props.key} />;
+    /// <Row key={rowProps.key} />;
+    /// ```
+    JsxKey,
+    react,
+    correctness,
+    suggestion
+);
+
+impl Rule for JsxKey {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        value
+            .as_array()
+            .and_then(|arr| arr.first())
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::JSXElement(jsx_elem) => {
+                self.check_jsx_element(node, jsx_elem, ctx);
+                if self.should_check_key_before_spread(ctx) {
+                    check_jsx_element_is_key_before_spread(jsx_elem, ctx);
+                }
+                if self.0.check_props_key {
+                    check_jsx_element_key_from_props(jsx_elem, ctx);
+                }
+            }
+            AstKind::JSXFragment(jsx_frag) => {
+                self.check_jsx_fragment(node, jsx_frag, ctx);
+            }
+            AstKind::CallExpression(call) => {
+                self.check_element_call(node, call, ctx);
+            }
+
+            _ => {}
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_jsx()
+    }
+}
+
+fn check_jsx_element_is_key_before_spread<'a>(jsx_elem: &JSXElement<'a>, ctx: &LintContext<'a>) {
+    let mut key_idx_span: Option<(usize, Span)> = None;
+    let mut spread_idx: Option<usize> = None;
+
+    for (i, attr) in jsx_elem.opening_element.attributes.iter().enumerate() {
+        match attr {
+            JSXAttributeItem::Attribute(attr) => {
+                let JSXAttributeName::Identifier(ident) = &attr.name else {
+                    continue;
+                };
+                if ident.name == "key" {
+                    key_idx_span = Some((i, attr.name.span()));
+                }
+            }
+            JSXAttributeItem::SpreadAttribute(_) => spread_idx = Some(i),
+        }
+        if key_idx_span.map(|x| x.0).is_some() && spread_idx.is_some() {
+            break;
+        }
+    }
+
+    if let (Some((key_idx, key_span)), Some(spread_idx)) = (key_idx_span, spread_idx) {
+        if key_idx > spread_idx {
+            ctx.diagnostic(key_prop_must_be_placed_before_spread(key_span));
+        }
+    }
+}
+
+fn check_jsx_element_key_from_props<'a>(jsx_elem: &JSXElement<'a>, ctx: &LintContext<'a>) {
+    let Some(JSXAttributeValue::ExpressionContainer(container)) =
+        get_jsx_attribute_value(&jsx_elem.opening_element, "key")
+    else {
+        return;
+    };
+    let Some(value) = container.expression.as_expression() else {
+        return;
+    };
+    if is_props_key(value) {
+        ctx.diagnostic(key_read_from_props(value.span()));
+    }
+}
+
+/// Whether `expr` reads `key` from props, e.g. `props.key`, `rowProps?.key` or `this.props.key`.
+fn is_props_key(expr: &Expression) -> bool {
+    let member = match expr.without_parentheses() {
+        Expression::ChainExpression(chain) => chain.expression.as_member_expression(),
+        expr => expr.as_member_expression(),
+    };
+    let Some(member) = member else {
+        return false;
+    };
+    if member.static_property_name() != Some("key") {
+        return false;
+    }
+    match member.object().without_parentheses() {
+        Expression::Identifier(ident) => ident.name == "props" || ident.name.ends_with("Props"),
+        Expression::StaticMemberExpression(object) => {
+            matches!(object.object, Expression::ThisExpression(_))
+                && object.property.name == "props"
+        }
+        _ => false,
+    }
+}
+
+impl JsxKey {
+    fn check_jsx_element<'a>(
+        &self,
+        node: &AstNode<'a>,
+        jsx_elem: &JSXElement<'a>,
+        ctx: &LintContext<'a>,
+    ) {
+        let mut trace = DecisionTrace::new(|| {
+            let name = ctx.source_range(jsx_elem.opening_element.name.span());
+            format!("<{name}> at {}", jsx_elem.span.start)
+        });
+        let Some(outer) = self.is_in_array_or_iter(node, ctx, &mut trace) else {
+            trace.finish("no report");
+            return;
+        };
+        let has_key = get_jsx_attribute(&jsx_elem.opening_element, "key").is_some()
+            || has_key_in_spread_object(&jsx_elem.opening_element);
+        trace.step(|| format!("has key: {has_key}"));
+        if has_key {
+            trace.finish("no report");
+            return;
+        }
+        trace.finish("report missing key");
+
+        let diagnostic = gen_diagnostic(jsx_elem.opening_element.name.span(), &outer);
+        let diagnostic = match dynamic_component_declaration(jsx_elem, ctx) {
+            Some(span) => diagnostic.and_label(span.label("Component selected here.")),
+            None => diagnostic,
+        };
+        match suggested_key(node, &outer, ctx) {
+            Some(key) => ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                let (span, text) = key_insertion(&jsx_elem.opening_element, &key, ctx);
+                fixer.insert_text_after_range(span, text).with_message("Add `key` prop")
+            }),
+            None => ctx.diagnostic(diagnostic),
+        }
+    }
+
+    fn check_jsx_fragment<'a>(
+        &self,
+        node: &AstNode<'a>,
+        fragment: &JSXFragment<'a>,
+        ctx: &LintContext<'a>,
+    ) {
+        let mut trace = DecisionTrace::new(|| format!("<> at {}", fragment.span.start));
+        let outer = self.is_in_array_or_iter(node, ctx, &mut trace);
+        trace.finish(if outer.is_some() { "report missing key" } else { "no report" });
+        if let Some(outer) = outer {
+            let diagnostic = gen_diagnostic(fragment.opening_fragment.span, &outer);
+            let suggestion =
+                suggested_key(node, &outer, ctx).zip(fragment_component_name(node, ctx));
+            match suggestion {
+                Some((key, component)) => ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                    // Replace the tags in place, so the children keep their layout.
+                    let mut fix = fixer.new_fix_with_capacity(2);
+                    fix.push(fixer.replace(
+                        fragment.opening_fragment.span,
+                        format!("<{component} key={key}>"),
+                    ));
+                    fix.push(
+                        fixer.replace(fragment.closing_fragment.span, format!("</{component}>")),
+                    );
+                    fix.with_message(format!("Convert to `{component}` with a `key` prop"))
+                }),
+                None => ctx.diagnostic(diagnostic),
+            }
+        }
+    }
+
+    /// Check a `React.createElement()` or `React.cloneElement()` call, whose props are its
+    /// second argument.
+    fn check_element_call<'a>(
+        &self,
+        node: &AstNode<'a>,
+        call: &CallExpression<'a>,
+        ctx: &LintContext<'a>,
+    ) {
+        let Some(name) = element_call_name(call, ctx) else {
+            return;
+        };
+        let mut trace = DecisionTrace::new(|| format!("{name}() at {}", call.span.start));
+        let Some(outer) = self.is_in_array_or_iter(node, ctx, &mut trace) else {
+            trace.finish("no report");
+            return;
+        };
+        let may_have_key = props_may_have_key(call);
+        trace.step(|| format!("props may have key: {may_have_key}"));
+        if may_have_key {
+            trace.finish("no report");
+            return;
+        }
+        trace.finish("report missing key");
+        ctx.diagnostic(gen_diagnostic(call.span, &outer));
+    }
+
+    fn should_check_key_before_spread(&self, ctx: &LintContext<'_>) -> bool {
+        match self.0.check_key_must_before_spread {
+            CheckKeyMustBeforeSpread::Always => true,
+            CheckKeyMustBeforeSpread::Auto => ctx
+                .settings()
+                .react
+                .version()
+                .is_none_or(|version| version < KEY_AFTER_SPREAD_SAFE_VERSION),
+        }
+    }
+
+    /// Find whether `node` needs a key: it is in an array or an iterator, taking the rule's
+    /// options into account, and its key is not assigned by `React.Children.toArray()`.
+    fn is_in_array_or_iter<'a>(
+        &self,
+        node: &AstNode<'a>,
+        ctx: &LintContext<'a>,
+        trace: &mut DecisionTrace,
+    ) -> Option<InsideArrayOrIterator> {
+        let outer = match is_in_array_or_iter(
+            node,
+            ctx,
+            self.0.check_reduce,
+            &self.0.additional_iterator_methods,
+            trace.visited(),
+        ) {
+            Ok(outer) => outer,
+            Err(bail) => {
+                trace.step(|| format!("bail({:?}): {}", bail.kind, bail.reason));
+                let pushed = is_pushed_to_rendered_array(node, ctx);
+                trace.step(|| format!("pushed to a rendered array: {pushed}"));
+                if !pushed {
+                    return None;
+                }
+                InsideArrayOrIterator::Array
+            }
+        };
+        trace.step(|| match outer {
+            InsideArrayOrIterator::Iterator(span) => {
+                format!("Iterator({})", ctx.source_range(span))
+            }
+            InsideArrayOrIterator::Array => "Array".to_string(),
+        });
+        if self.0.only_flag_rendered_arrays && matches!(outer, InsideArrayOrIterator::Array) {
+            let rendered =
+                outermost_array(node, ctx).is_some_and(|array| is_rendered(array.id(), ctx, true));
+            trace.step(|| format!("array is rendered (onlyFlagRenderedArrays): {rendered}"));
+            if !rendered {
+                return None;
+            }
+        }
+        let to_array = is_within_children_to_array(node, ctx);
+        trace.step(|| format!("in React.Children.toArray: {to_array}"));
+        if to_array {
+            return None;
+        }
+        Some(outer)
+    }
+}
+
+/// Whether `node` is pushed into a local array which is rendered, e.g.
+/// `const nodes = []; nodes.push(<A />); return <div>{nodes}</div>;`.
+///
+/// The array must be a `const` or `let` initialized to an array literal in the same function as
+/// the push. If any reference to the array is neither a `push` / `unshift` nor rendered, the array
+/// may escape, and `false` is returned.
+fn is_pushed_to_rendered_array(node: &AstNode, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    let Some(argument) = nodes
+        .ancestors(node.id())
+        .find(|parent| !matches!(parent.kind(), AstKind::ParenthesizedExpression(_)))
+        .filter(|parent| matches!(parent.kind(), AstKind::Argument(_)))
+    else {
+        return false;
+    };
+    let call_node = nodes.parent_node(argument.id());
+    let Some(array) = push_call_array(call_node.kind()) else {
+        return false;
+    };
+
+    let Some(symbol_id) = ctx.scoping().get_reference(array.reference_id()).symbol_id() else {
+        return false;
+    };
+    let declaration = nodes.get_node(ctx.scoping().symbol_declaration(symbol_id));
+    let AstKind::VariableDeclarator(decl) = declaration.kind() else {
+        return false;
+    };
+    let is_array_literal = matches!(&decl.id.kind, BindingPatternKind::BindingIdentifier(_))
+        && !decl.kind.is_var()
+        && matches!(
+            decl.init.as_ref().map(Expression::without_parentheses),
+            Some(Expression::ArrayExpression(_))
+        );
+    if !is_array_literal {
+        return false;
+    }
+
+    let function_of = |node_id| {
+        nodes.ancestors(node_id).find(|node| node.kind().is_function_like()).map(AstNode::id)
+    };
+    if function_of(call_node.id()) != function_of(declaration.id()) {
+        return false;
+    }
+
+    let mut rendered = false;
+    for reference in ctx.symbol_references(symbol_id) {
+        let parent = nodes.parent_node(reference.node_id());
+        let is_push = matches!(parent.kind(), AstKind::StaticMemberExpression(_))
+            && push_call_array(nodes.parent_kind(parent.id())).is_some();
+        if is_push {
+            continue;
+        }
+        if !is_rendered(reference.node_id(), ctx, false) {
+            return false;
+        }
+        rendered = true;
+    }
+    rendered
+}
+
+/// If `kind` is a call `array.push(...)` or `array.unshift(...)`, returns `array`.
+fn push_call_array(kind: AstKind<'_>) -> Option<&IdentifierReference<'_>> {
+    let AstKind::CallExpression(call) = kind else {
+        return None;
+    };
+    let Expression::StaticMemberExpression(member) = &call.callee else {
+        return None;
+    };
+    if !matches!(member.property.name.as_str(), "push" | "unshift") {
+        return None;
+    }
+    match &member.object {
+        Expression::Identifier(ident) => Some(ident),
+        _ => None,
+    }
+}
+
+/// If `call` creates an element with `React.createElement()` or `React.cloneElement()`, or with
+/// `createElement` or `cloneElement` imported from `react`, returns the name of the function.
+fn element_call_name<'a>(call: &CallExpression<'a>, ctx: &LintContext<'a>) -> Option<&'a str> {
+    const REACT: &str = "React";
+    let is_element_function = |name: &str| matches!(name, "createElement" | "cloneElement");
+
+    match call.callee.without_parentheses() {
+        Expression::Identifier(ident) => {
+            let name = ident.name.as_str();
+            (is_element_function(name) && is_import(ctx, name, name, REACT)).then_some(name)
+        }
+        callee => {
+            let member = callee.as_member_expression()?;
+            let (_, name) = member.static_property_info()?;
+            let Expression::Identifier(object) = member.object() else {
+                return None;
+            };
+            (is_element_function(name) && is_import(ctx, object.name.as_str(), REACT, REACT))
+                .then_some(name)
+        }
+    }
+}
+
+/// Whether the props of a `createElement()` or `cloneElement()` call may contain a `key`.
+///
+/// Props which cannot be checked statically, such as a variable or an object with a spread
+/// property, are assumed to contain one.
+fn props_may_have_key(call: &CallExpression) -> bool {
+    for (i, argument) in call.arguments.iter().enumerate() {
+        match argument {
+            // `createElement(...args)`
+            Argument::SpreadElement(_) => return true,
+            _ if i == 1 => {
+                return match argument.to_expression().without_parentheses() {
+                    Expression::NullLiteral(_) => false,
+                    Expression::Identifier(ident) if ident.name == "undefined" => false,
+                    Expression::ObjectExpression(object) => {
+                        object.properties.iter().any(|property| match property {
+                            ObjectPropertyKind::SpreadProperty(_) => true,
+                            ObjectPropertyKind::ObjectProperty(property) => {
+                                // `{ [name]: value }` may be `key`.
+                                (property.computed && property.key.static_name().is_none())
+                                    || property.key.is_specific_static_name("key")
+                            }
+                        })
+                    }
+                    _ => true,
+                };
+            }
+            _ => {}
+        }
+    }
+    // No props, e.g. `createElement(Row)`.
+    false
+}
+
+/// Whether `opening` spreads an object literal with a `key` property, e.g. `<App {...{ key: x }} />`.
+///
+/// Spreading anything else, e.g. `<App {...props} />`, may or may not pass a `key`, so is not
+/// counted.
+fn has_key_in_spread_object(opening: &JSXOpeningElement) -> bool {
+    opening.attributes.iter().any(|attribute| {
+        let JSXAttributeItem::SpreadAttribute(spread) = attribute else {
+            return false;
+        };
+        let Expression::ObjectExpression(object) = spread.argument.without_parentheses() else {
+            return false;
+        };
+        object.properties.iter().any(|property| match property {
+            ObjectPropertyKind::ObjectProperty(property) => {
+                property.key.is_specific_static_name("key")
+            }
+            ObjectPropertyKind::SpreadProperty(_) => false,
+        })
+    })
+}
+
+/// If `jsx_elem` renders a component chosen at runtime, e.g. `El` in
+/// `const El = cond ? A : B; items.map(() => <El />)`, returns the span of `El`'s declaration.
+fn dynamic_component_declaration(jsx_elem: &JSXElement, ctx: &LintContext) -> Option<Span> {
+    let JSXElementName::IdentifierReference(ident) = &jsx_elem.opening_element.name else {
+        return None;
+    };
+    let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+    let AstKind::VariableDeclarator(decl) =
+        ctx.nodes().kind(ctx.scoping().symbol_declaration(symbol_id))
+    else {
+        return None;
+    };
+    // Only a choice between components, e.g. `cond ? A : B`, `Custom || Default` or
+    // `components[type]`. `memo(Row)` or `styled.div` is the same component on every render.
+    let is_selected = match decl.init.as_ref()?.get_inner_expression() {
+        Expression::ConditionalExpression(_) | Expression::LogicalExpression(_) => true,
+        Expression::ComputedMemberExpression(member) => member.static_property_name().is_none(),
+        _ => false,
+    };
+    is_selected.then(|| ctx.scoping().symbol_span(symbol_id))
+}
+
+/// Value of the `key` prop suggested for `node`, e.g. `{item.id}` for
+/// `items.map((item) => <li>{item.id}</li>)`.
+///
+/// Only a value which identifies the item is suggested: an `id` or `key` property of the item
+/// parameter which the callback reads, or the item parameter itself when it is known to be a
+/// string or a number. The whole item, or the item's index, would make a poor key, so there is
+/// no suggestion otherwise.
+fn suggested_key(
+    node: &AstNode,
+    outer: &InsideArrayOrIterator,
+    ctx: &LintContext,
+) -> Option<String> {
+    let InsideArrayOrIterator::Iterator(_) = outer else {
+        return None;
+    };
+    let callback = ctx.nodes().ancestors(node.id()).find(|parent| {
+        matches!(parent.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_))
+    })?;
+    let params = match callback.kind() {
+        AstKind::Function(func) => &func.params,
+        AstKind::ArrowFunctionExpression(arrow) => &arrow.params,
+        _ => unreachable!(),
+    };
+    let param = &params.items.first()?.pattern;
+    let BindingPatternKind::BindingIdentifier(ident) = &param.kind else {
+        return None;
+    };
+    let name = &ident.name;
+
+    let symbol_id = ident.symbol_id();
+    let id_property = ["id", "key"].into_iter().find(|property| {
+        ctx.symbol_references(symbol_id).any(|reference| {
+            matches!(
+                ctx.nodes().parent_kind(reference.node_id()),
+                AstKind::StaticMemberExpression(member) if member.property.name == property
+            )
+        })
+    });
+    if let Some(property) = id_property {
+        return Some(format!("{{{name}.{property}}}"));
+    }
+
+    let is_primitive = match &param.type_annotation {
+        Some(annotation) => matches!(
+            annotation.type_annotation,
+            TSType::TSStringKeyword(_) | TSType::TSNumberKeyword(_)
+        ),
+        None => iterates_primitive_literals(callback, ctx),
+    };
+    is_primitive.then(|| format!("{{{name}}}"))
+}
+
+/// Whether `callback` is passed to an iterator method called on an array literal of strings
+/// and numbers, e.g. `["a", "b"].map(callback)`.
+fn iterates_primitive_literals(callback: &AstNode, ctx: &LintContext) -> bool {
+    let Some(AstKind::CallExpression(call)) =
+        ctx.nodes().ancestors(callback.id()).map(AstNode::kind).find(|kind| {
+            !matches!(kind, AstKind::Argument(_) | AstKind::ParenthesizedExpression(_))
+        })
+    else {
+        return false;
+    };
+    let Some(Expression::ArrayExpression(array)) =
+        call.callee.get_member_expr().map(|member| member.object().without_parentheses())
+    else {
+        return false;
+    };
+    array.elements.iter().all(|element| {
+        matches!(
+            element.as_expression().map(Expression::without_parentheses),
+            Some(Expression::StringLiteral(_) | Expression::NumericLiteral(_))
+        )
+    })
+}
+
+/// Name of the fragment component to convert `<>` to, if one is in scope at `node`: an imported
+/// `Fragment`, or `React.Fragment`.
+fn fragment_component_name(node: &AstNode, ctx: &LintContext) -> Option<&'static str> {
+    let scoping = ctx.scoping();
+    let imported_fragment = scoping.find_binding(node.scope_id(), "Fragment").is_some_and(|id| {
+        matches!(ctx.nodes().kind(scoping.symbol_declaration(id)), AstKind::ImportSpecifier(_))
+    });
+    if imported_fragment {
+        Some("Fragment")
+    } else {
+        scoping.find_binding(node.scope_id(), "React").map(|_| "React.Fragment")
+    }
+}
+
+/// Where and what to insert to add `key={key}` to `opening`, following the layout of the
+/// existing attributes: on its own line with the same indentation if they are one per line,
+/// or after the element name otherwise.
+fn key_insertion(opening: &JSXOpeningElement, key: &str, ctx: &LintContext) -> (Span, String) {
+    let name_end = opening.type_arguments.as_ref().map_or(opening.name.span(), |args| args.span);
+    let Some(first_attribute) = opening.attributes.first() else {
+        return (name_end, format!(" key={key}"));
+    };
+    let between = ctx.source_range(Span::new(name_end.end, first_attribute.span().start));
+    match between.rfind('\n') {
+        Some(newline) => {
+            let line = &between[newline + 1..];
+            let indent = &line[..line.len() - line.trim_start().len()];
+            (name_end, format!("\n{indent}key={key}"))
+        }
+        None => (name_end, format!(" key={key}")),
+    }
+}
+
+/// Find the outermost of the directly nested array literals containing `node`.
+fn outermost_array<'a, 'b>(
+    node: &'b AstNode<'a>,
+    ctx: &'b LintContext<'a>,
+) -> Option<&'b AstNode<'a>> {
+    let mut array = ctx
+        .nodes()
+        .ancestors(node.id())
+        .find(|parent| matches!(parent.kind(), AstKind::ArrayExpression(_)))?;
+    loop {
+        let parent = ctx.nodes().parent_node(array.id());
+        if !matches!(parent.kind(), AstKind::ArrayExpression(_)) {
+            return Some(array);
+        }
+        array = parent;
+    }
+}
+
+fn gen_diagnostic(span: Span, outer: &InsideArrayOrIterator) -> OxcDiagnostic {
+    match outer {
+        InsideArrayOrIterator::Array => missing_key_prop_for_element_in_array(span),
+        InsideArrayOrIterator::Iterator(v) => missing_key_prop_for_element_in_iterator(*v, span),
+    }
+}
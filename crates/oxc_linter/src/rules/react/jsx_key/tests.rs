@@ -0,0 +1,1379 @@
+//! Behavioral specification of `react/jsx-key`.
+//!
+//! Every clause in [`SPEC`] names the [`TestGroup`] that pins it down. `test_spec` checks
+//! that each clause has a group with at least one passing and one failing case, and that
+//! no group is left without a clause, so a behavior change shows up as a change to a clause
+//! and its group rather than as a loose test case.
+
+use super::JsxKey;
+use crate::{
+    rule::{Rule, RuleMeta},
+    tester::{TestGroup, Tester},
+};
+
+struct Clause {
+    /// Name of the [`TestGroup`] specifying this clause.
+    group: &'static str,
+    behavior: &'static str,
+}
+
+const SPEC: &[Clause] = &[
+    // Where elements need a `key`
+    Clause {
+        group: "array_literals",
+        behavior: "Elements placed directly in an array literal need a `key`. Keys are not \
+                   checked for uniqueness, and arrays without elements are ignored.",
+    },
+    Clause {
+        group: "iterator_callbacks",
+        behavior: "Elements returned from a `map` callback need a `key`, whether the \
+                   callback is an arrow or a function, the call is optional, or the element \
+                   is returned through `&&`, `||`, `??`, nested conditionals, the last \
+                   expression of a sequence, or any of several `return`s, including in \
+                   `switch` and `try` statements. Only the returned elements without a `key` \
+                   are reported, each once.",
+    },
+    Clause {
+        group: "array_from",
+        behavior: "Elements returned from the mapping callback of `Array.from` need a `key`.",
+    },
+    Clause {
+        group: "nested_arrays",
+        behavior: "Elements in nested array literals, including arrays returned from \
+                   `flatMap` callbacks, need a `key`.",
+    },
+    Clause {
+        group: "render_roots",
+        behavior: "Arrays and iterators passed to `createRoot().render()`, \
+                   `hydrateRoot().render()`, `ReactDOM.render()` and `render()` are checked.",
+    },
+    Clause {
+        group: "async_callbacks",
+        behavior: "Elements returned from async callbacks, directly or through `await`, need \
+                   a `key`; elements that are awaited but not returned do not.",
+    },
+    Clause {
+        group: "pushed_arrays",
+        behavior: "Elements pushed or unshifted into a local `const` or `let` array that is \
+                   only rendered in JSX or returned need a `key`.",
+    },
+    Clause {
+        group: "create_element",
+        behavior: "`createElement` and `cloneElement` calls from `React` or `react` need a \
+                   `key` in their props, unless the props may contain one, e.g. a spread, \
+                   a computed key or a non-literal props object.",
+    },
+    // What counts as an element
+    Clause {
+        group: "element_names",
+        behavior: "Any element name is checked: intrinsic, member, namespaced and component \
+                   variables alike.",
+    },
+    Clause {
+        group: "ts_wrappers",
+        behavior: "Parenthesized and non-null-asserted elements are checked as the element \
+                   itself.",
+    },
+    Clause {
+        group: "fragments",
+        behavior: "A shorthand fragment cannot take a `key` and is reported in an array or \
+                   iterator; a keyed `Fragment` is not.",
+    },
+    // Where elements do not need a `key`
+    Clause {
+        group: "nested_elements",
+        behavior: "Only the element in the array, or returned from the callback, is checked. \
+                   Its children, and elements in object literals or functions that are not \
+                   returned, are not.",
+    },
+    Clause {
+        group: "outside_iterators",
+        behavior: "Elements outside array literals and iterator callbacks are not checked, \
+                   nor are callbacks passed to other functions and methods.",
+    },
+    Clause {
+        group: "children_to_array",
+        behavior: "Iterators passed to `Children.toArray` from `react` are exempt, as \
+                   `toArray` assigns keys; other `toArray` calls are not.",
+    },
+    // `key` placement
+    Clause {
+        group: "key_in_spread_object",
+        behavior: "A `key` property of an object literal spread into the element counts as a \
+                   `key`. Spreading anything else does not, as the `key` cannot be known.",
+    },
+    Clause {
+        group: "key_before_spread",
+        behavior: "A `key` placed after a spread attribute is reported.",
+    },
+    Clause {
+        group: "key_before_spread_react_version",
+        behavior: "From React 19, per `settings.react.version`, a `key` after a spread is \
+                   allowed, unless `checkKeyMustBeforeSpread` is `\"always\"`.",
+    },
+    // Options
+    Clause {
+        group: "only_flag_rendered_arrays",
+        behavior: "With `onlyFlagRenderedArrays`, array literals are only checked when they \
+                   are rendered in JSX, returned, or passed to a render root.",
+    },
+    Clause {
+        group: "check_reduce",
+        behavior: "Arrays returned from `reduce` callbacks are checked as array literals, or \
+                   as iterators with `checkReduce`.",
+    },
+    Clause {
+        group: "additional_iterator_methods",
+        behavior: "`additionalIteratorMethods` adds methods, or functions with a \
+                   `callbackIndex`, whose callbacks are checked like `map`'s.",
+    },
+    Clause {
+        group: "check_props_key",
+        behavior: "With `checkPropsKey`, a `key` whose value is read from `props.key`, \
+                   `*Props.key` or `this.props.key` is reported.",
+    },
+];
+
+fn groups() -> Vec<TestGroup> {
+    vec![
+        TestGroup::new(
+            "array_literals",
+            vec![
+                (r"[<App key={0} />, <App key={1} />];", None),
+                (r#"const spans = [<span key="notunique"/>,<span key="notunique"/>];"#, None),
+                (
+                    r"
+            // testrule.jsx
+            const trackLink = () => {};
+            const getAnalyticsUiElement = () => {};
+
+            const onTextButtonClick = (e, item) => trackLink([, getAnalyticsUiElement(item), item.name], e);
+            ",
+                    None,
+                ),
+                (
+                    r#"
+          const foo: (JSX.Element | string)[] = [
+            "text",
+            <Fragment key={1}>hello world<sup>superscript</sup></Fragment>,
+          ];
+        "#,
+                    None,
+                ),
+            ],
+            vec![
+                (r"[<App />];", None),
+                (r"[<App {...key} />];", None),
+                (r"[<App key={0}/>, <App />];", None),
+            ],
+        ),
+        TestGroup::new(
+            "iterator_callbacks",
+            vec![
+                (r"[1, 2, 3].map(function(x) { return <App key={x} /> });", None),
+                (r"[1, 2, 3].map(x => <App key={x} />);", None),
+                (r"[1, 2 ,3].map(x => x && <App x={x} key={x} />);", None),
+                (
+                    r#"[1, 2 ,3].map(x => x ? <App x={x} key="1" /> : <OtherApp x={x} key="2" />);"#,
+                    None,
+                ),
+                (r"[1, 2, 3].map(x => { return <App key={x} /> });", None),
+                (r"[1, 2, 3].map(function(x) { return; });", None),
+                // Elements which cannot be the value of the callback
+                (r"[1, 2, 3].map(x => (<App />, x));", None),
+                (r"[1, 2, 3].map(x => <App /> && x);", None),
+                (r"[1, 2, 3].map(x => (<App /> ? x : null));", None),
+                (r"[1, 2, 3].map(x => { <App />; return x; });", None),
+                (r"[1, 2, 3].map(function(x) { <App />; });", None),
+                (r"[1, 2, 3].map(x => x ?? <App key={x} />);", None),
+                (
+                    r"
+            list.map(item => {
+                if (item.header) {
+                    return <Header key={item.id} />;
+                } else if (item.footer) {
+                    return <Footer key={item.id} />;
+                }
+                const label = item.label;
+                return <Row key={item.id} label={label} />;
+            });
+            ",
+                    None,
+                ),
+                (
+                    r"
+            list.map(function (item) {
+                switch (item.type) {
+                    case 'a':
+                        return <A key={item.id} />;
+                    case 'b':
+                        return item.ok ? <B key={item.id} /> : <C key={item.id} />;
+                    default:
+                        return null;
+                }
+            });
+            ",
+                    None,
+                ),
+                (
+                    r#"
+            function Component({ allRatings }) {
+                return (
+                  <RatingDetailsStyles>
+                    {Object.entries(allRatings)?.map(([key, value], index) => {
+                      const rate = value?.split(/(?=[%, /])/);
+
+                      if (!rate) return null;
+
+                      return (
+                        <li key={`${entertainment.tmdbId}${index}`}>
+                          <img src={`/assets/rating/${key}.png`} />
+                          <span className="rating-details--rate">{rate?.[0]}</span>
+                          <span className="rating-details--rate-suffix">{rate?.[1]}</span>
+                        </li>
+                      );
+                    })}
+                  </RatingDetailsStyles>
+                );
+              }
+              "#,
+                    None,
+                ),
+            ],
+            vec![
+                (r"[1, 2 ,3].map(function(x) { return <App /> });", None),
+                (r"[1, 2 ,3].map(x => <App />);", None),
+                (r"[1, 2 ,3].map(x => x && <App x={x} />);", None),
+                (r#"[1, 2 ,3].map(x => x ? <App x={x} key="1" /> : <OtherApp x={x} />);"#, None),
+                (r#"[1, 2 ,3].map(x => x ? <App x={x} /> : <OtherApp x={x} key="2" />);"#, None),
+                (r"[1, 2 ,3].map(x => { return <App /> });", None),
+                (r"[1, 2, 3]?.map(x => <BabelEslintApp />)", None),
+                (r"[1, 2, 3]?.map(x => <TypescriptEslintApp />)", None),
+                (r"[1, 2, 3]?.map(x => <><OxcCompilerHello /></>)", None),
+                (
+                    r"
+            list.map(item => {
+                if (item.header) {
+                    return <Header key={item.id} />;
+                } else if (item.footer) {
+                    return <KeylessFooter />;
+                }
+                const label = item.label;
+                return <Row key={item.id} label={label} />;
+            });
+            ",
+                    None,
+                ),
+                (
+                    r"
+            list.map(function (item) {
+                switch (item.type) {
+                    case 'a':
+                        return <A key={item.id} />;
+                    case 'b':
+                        return item.ok ? <KeylessB /> : <C key={item.id} />;
+                    case 'c':
+                        return <KeylessC />;
+                    default:
+                        return null;
+                }
+            });
+            ",
+                    None,
+                ),
+                (
+                    r"
+            list.map(item => {
+                if (!item) return null;
+                if (item.loading) return <KeylessSpinner />;
+                return item.error && <KeylessError /> || <Row key={item.id} />;
+            });
+            ",
+                    None,
+                ),
+                (
+                    r"
+                const Test = () => {
+                  const list = [1, 2, 3, 4, 5];
+
+                  return (
+                    <div>
+                      {list.map(item => {
+                        if (item < 2) {
+                          return <div>{item}</div>;
+                        }
+
+                        return <div />;
+                      })}
+                    </div>
+                  );
+                };
+            ",
+                    None,
+                ),
+                (
+                    r"
+                const TestO = () => {
+                  const list = [1, 2, 3, 4, 5];
+
+                  return (
+                    <div>
+                      {list.map(item => {
+                        if (item < 2) {
+                          return <div>{item}</div>;
+                        } else if (item < 5) {
+                          return <div></div>
+                        }  else {
+                          return <div></div>
+                        }
+
+                        return <div />;
+                      })}
+                    </div>
+                  );
+                };
+            ",
+                    None,
+                ),
+                (
+                    r"
+                const TestCase = () => {
+                  const list = [1, 2, 3, 4, 5];
+
+                  return (
+                    <div>
+                      {list.map(item => {
+                        if (item < 2) return <div>{item}</div>;
+                        else if (item < 5) return <div />;
+                        else return <div />;
+                      })}
+                    </div>
+                  );
+                };
+          ",
+                    None,
+                ),
+                (
+                    r"
+                const TestCase = () => {
+                  const list = [1, 2, 3, 4, 5];
+
+                  return (
+                    <div>
+                      {list.map(item => <Text foo bar baz qux onClick={() => onClickHandler()} onPointerDown={() => onPointerDownHandler()} onMouseDown={() => onMouseDownHandler()} />)}
+                    </div>
+                  );
+                };
+          ",
+                    None,
+                ),
+                (r"[1, 2, 3].map(x => x ?? <App />);", None),
+                (r"[1, 2, 3].map(x => (cond && <App />) || <OtherApp />);", None),
+                (r"[1, 2, 3].map(x => (sideEffect(), <App />));", None),
+                (
+                    r"[1, 2, 3].map(x => a ? <A key={x} /> : b ? <B /> : c ? <C key={x} /> : <D />);",
+                    None,
+                ),
+                (
+                    r"
+            list.map(function (item) {
+                try {
+                    return load(item) ?? <KeylessFallback />;
+                } catch {
+                    return <KeylessError />;
+                }
+            });
+            ",
+                    None,
+                ),
+            ],
+        ),
+        TestGroup::new(
+            "array_from",
+            vec![
+                (r"Array.from([1, 2, 3], function(x) { return <App key={x} /> });", None),
+                (r"Array.from([1, 2, 3], (x => <App key={x} />));", None),
+                (r"Array.from([1, 2, 3], (x => {return <App key={x} />}));", None),
+                (r"Array.from([1, 2, 3], someFn);", None),
+                (r"Array.from([1, 2, 3]);", None),
+            ],
+            vec![
+                (r"Array.from([1, 2 ,3], function(x) { return <App /> });", None),
+                (r"Array.from([1, 2 ,3], (x => { return <App /> }));", None),
+                (r"Array.from([1, 2 ,3], (x => <App />));", None),
+            ],
+        ),
+        TestGroup::new(
+            "element_names",
+            vec![
+                (r"items.map((x) => <li key={x} />);", None),
+                (r"items.map((x) => <motion.div key={x} />);", None),
+                (r"items.map((x) => <svg:rect key={x} xlink:href={x} />);", None),
+                (r"const El = cond ? A : B; items.map((x) => <El key={x} />);", None),
+            ],
+            vec![
+                (r"items.map((x) => <li />);", None),
+                (r"items.map((x) => <Row />);", None),
+                (r"items.map((x) => <motion.div />);", None),
+                (r"items.map((x) => <svg:rect xlink:href={x} />);", None),
+                (r"[<svg:rect />, <motion.div />];", None),
+                (r"const El = cond ? A : B; items.map((x) => <El />);", None),
+                (r"const El = cond ? A : B; [<El />];", None),
+                (r"const El = Custom || Default; items.map((x) => <El />);", None),
+                (r"const El = components[x.type]; items.map((x) => <El />);", None),
+                (r"const El = memo(Row); items.map((x) => <El />);", None),
+                (r"const El = styled.div; items.map((x) => <El />);", None),
+                (r"const El = icons['home']; items.map((x) => <El />);", None),
+                (r"function List({ as: El }) { return items.map((x) => <El />); }", None),
+            ],
+        ),
+        TestGroup::new(
+            "ts_wrappers",
+            vec![
+                (r"items.map((x) => (<Row key={x} />)!);", None),
+                (r"items.map((x) => getRow(x)!);", None),
+            ],
+            vec![
+                (r"items.map((x) => (<Row />)!);", None),
+                (r"items.map((x) => { return (<Row />)!; });", None),
+                (r"items.map((x) => ((<Row />)!)!);", None),
+                (r"[(<Row />)!];", None),
+            ],
+        ),
+        TestGroup::new(
+            "fragments",
+            vec![
+                (
+                    r"Array.from({ length: 3 }, (_, i) => <React.Fragment key={i}>{i}</React.Fragment>);",
+                    None,
+                ),
+                (
+                    r"Array.from({ length: 3 }, (_, i) => <Fragment key={i}><>{i}</></Fragment>);",
+                    None,
+                ),
+                (r"<></>;", None),
+            ],
+            vec![
+                (r"Array.from({ length: 3 }, (_, i) => <>{i}</>);", None),
+                (r"Array.from({ length: 3 }, function (_, i) { return <>{i}</>; });", None),
+                (r"Array.from(items, (item) => (<><A /><B /></>));", None),
+                ("[1, 2, 3].map(x => <>{x}</>);", None),
+                ("[<></>];", None),
+            ],
+        ),
+        TestGroup::new(
+            "nested_arrays",
+            vec![
+                (r#"[[<A key="a" />, <B key="b" />], [<C key="c" />]].flat();"#, None),
+                (r#"items.flatMap(x => [<A key="a" />, [<B key="b" />]]);"#, None),
+            ],
+            vec![
+                (r"[[<A />, <B />], [<C />]].flat();", None),
+                (r#"items.flatMap(x => [<A />, <B key="k" />, <C />]);"#, None),
+                (r"items.flatMap(x => { return [<A />, [<B />]]; });", None),
+            ],
+        ),
+        TestGroup::new(
+            "nested_elements",
+            vec![
+                (
+                    r#"
+            function Component(props) {
+              return hasPayment ? (
+                <div className="stuff">
+                  <BookingDetailSomething {...props} />
+                  {props.modal && props.calculatedPrice && (
+                    <SomeOtherThing items={props.something} discount={props.discount} />
+                  )}
+                </div>
+              ) : null;
+            }
+            "#,
+                    None,
+                ),
+                (
+                    r#"
+            import React, { FC, useRef, useState } from 'react';
+
+            import './ResourceVideo.sass';
+            import VimeoVideoPlayInModal from '../vimeoVideoPlayInModal/VimeoVideoPlayInModal';
+
+            type Props = {
+              videoUrl: string;
+              videoTitle: string;
+            };
+
+            const ResourceVideo: FC<Props> = ({
+              videoUrl,
+              videoTitle,
+            }: Props): JSX.Element => {
+              return (
+                <div className="resource-video">
+                  <VimeoVideoPlayInModal videoUrl={videoUrl} />
+                  <h3>{videoTitle}</h3>
+                </div>
+              );
+            };
+
+            export default ResourceVideo;
+            "#,
+                    None,
+                ),
+                (
+                    r#"
+            const columns: ColumnDef<User>[] = [{
+              accessorKey: 'lastName',
+              header: ({ column }) => <DataTableColumnHeader column={column} title="Last Name" />,
+              cell: ({ row }) => <div>{row.getValue('lastName')}</div>,
+              enableSorting: true,
+              enableHiding: false,
+            }]
+        "#,
+                    None,
+                ),
+                (
+                    r#"
+            const columns: ColumnDef<User>[] = [{
+              accessorKey: 'lastName',
+              header: function ({ column }) { return <DataTableColumnHeader column={column} title="Last Name" /> },
+              cell: ({ row }) => <div>{row.getValue('lastName')}</div>,
+              enableSorting: true,
+              enableHiding: false,
+            }]
+        "#,
+                    None,
+                ),
+                (
+                    r#"
+            const router = createBrowserRouter([
+              {
+                path: "/",
+                element: <Root />,
+                children: [
+                  {
+                    path: "team",
+                    element: <Team />,
+                  },
+                ],
+              },
+            ]);
+        "#,
+                    None,
+                ),
+                (
+                    r#"
+        function App() {
+          return (
+            <div className="App">
+              {[1, 2, 3, 4, 5].map((val) => {
+                const text = () => <strong>{val}</strong>;
+                return null
+              })}
+            </div>
+          );
+        }"#,
+                    None,
+                ),
+                (
+                    r#"
+        function App() {
+          return (
+            <div className="App">
+              {[1, 2, 3, 4, 5].map((val) => {
+                const text = <strong>{val}</strong>;
+                return <button key={val}>{text}</button>;
+              })}
+            </div>
+          );
+        }"#,
+                    None,
+                ),
+                (
+                    r"
+        MyStory.decorators = [
+          (Component) => <div><Component /></div>
+        ];
+        ",
+                    None,
+                ),
+                (
+                    r"
+        MyStory.decorators = [
+          (Component) => {
+            const store = useMyStore();
+            return <Provider store={store}><Component /></Provider>;
+          }
+        ];
+        ",
+                    None,
+                ),
+            ],
+            vec![(
+                r"
+                const TestCase = () => {
+                  const list = [1, 2, 3, 4, 5];
+
+                  return (
+                    <div>
+                      {list.map(item => (<div>
+                        <Text foo bar baz qux onClick={() => onClickHandler()} onPointerDown={() => onPointerDownHandler()} onMouseDown={() => onMouseDownHandler()} />
+                        </div>)
+                      )}
+                    </div>
+                  );
+                };
+          ",
+                None,
+            )],
+        ),
+        TestGroup::new(
+            "outside_iterators",
+            vec![
+                (r"fn()", None),
+                (r"[1, 2, 3].map(function () {})", None),
+                (r"<App />;", None),
+                (r"[1, 2, 3].foo(x => <App />);", None),
+                (r"var App = () => <div />;", None),
+                (r"foo(() => <div />);", None),
+                (r"foo(() => <></>);", None),
+                (
+                    r"
+            const baz = foo?.bar?.()?.[1] ?? 'qux';
+
+            qux()?.map()
+
+            const directiveRanges = comments?.map(tryParseTSDirective)
+            ",
+                    None,
+                ),
+                (
+                    r#"
+            import { observable } from "mobx";
+
+            export interface ClusterFrameInfo {
+              frameId: number;
+              processId: number;
+            }
+
+            export const clusterFrameMap = observable.map<string, ClusterFrameInfo>();
+          "#,
+                    None,
+                ),
+                (
+                    r"const DummyComponent: FC<{ children: ReactNode }> = ({ children }) => { const wrappedChildren = Children.map(children, (child) => { return <div>{child}</div>; }); return <main>{wrappedChildren}</main>; };",
+                    None,
+                ),
+            ],
+            vec![(r"const Row = () => <li />; items.map((x) => <Row />);", None)],
+        ),
+        TestGroup::new(
+            "children_to_array",
+            vec![
+                (
+                    r"{React.Children.toArray(items.map((item) => {
+            return (
+              <div>
+             {item}
+             </div>
+              );}))}
+        ",
+                    None,
+                ),
+                (
+                    r#"import { Children } from "react";
+        Children.toArray([1, 2 ,3].map(x => <App />));
+        "#,
+                    None,
+                ),
+                (
+                    r#"import React from "react";
+        React.Children.toArray([1, 2 ,3].map(x => <App />));
+        "#,
+                    None,
+                ),
+                (r"React.Children.toArray([1, 2 ,3].map(x => <App />));", None),
+                (
+                    r"{React.Children.toArray(items.map((item) => {
+           return (
+             <>
+              {item}
+             </>
+            );
+           }))}
+        ",
+                    None,
+                ),
+            ],
+            vec![
+                (r"foo.Children.toArray([1, 2 ,3].map(x => <App />));", None),
+                (
+                    r"
+        import Act from 'react';
+        import { Children as ReactChildren } from 'react';
+
+        const { Children } = Act;
+        const { toArray } = Children;
+
+        Act.Children.toArray([1, 2 ,3].map(x => <App />));
+        Act.Children.toArray(Array.from([1, 2 ,3], x => <App />));
+        Children.toArray([1, 2 ,3].map(x => <App />));
+        Children.toArray(Array.from([1, 2 ,3], x => <App />));
+        ",
+                    None,
+                ),
+            ],
+        ),
+        TestGroup::new(
+            "render_roots",
+            vec![
+                (r#"createRoot(el).render([<App key="app" />, <Toaster key="toaster" />]);"#, None),
+                (r"createRoot(el).render(routes.map((r) => <Route key={r.path} />));", None),
+                (r#"ReactDOM.render([<App key="app" />, <Toaster key="toaster" />], el);"#, None),
+            ],
+            vec![
+                (r#"createRoot(el).render([<App key="app" />, <Toaster />]);"#, None),
+                (r"createRoot(el).render(routes.map((r) => <Route />));", None),
+                (r#"hydrateRoot(el).render([<App key="app" />, <Toaster />]);"#, None),
+                (r"hydrateRoot(el).render(routes.map((r) => <Route />));", None),
+                (r#"ReactDOM.render([<App key="app" />, <Toaster />], el);"#, None),
+                (r"ReactDOM.render(routes.map((r) => <Route />), el);", None),
+                (r"render([<App />], el);", None),
+            ],
+        ),
+        TestGroup::new(
+            "async_callbacks",
+            vec![
+                (r"items.map(async (i) => { await (<Row />); return null; });", None),
+                (r"items.map((i) => <Row key={i} data={use(promise)} />);", None),
+            ],
+            vec![
+                (r"items.map(async (i) => await (<Row />));", None),
+                (r"items.map(async (i) => { return await (<Row />); });", None),
+                (r"items.map(async (i) => { await (<Loading />); return <Row />; });", None),
+                (
+                    r"items.map(async (i) => { const data = await fetch(i); return <Row data={data} />; });",
+                    None,
+                ),
+                (r"items.map((i) => <Row data={use(promise)} />);", None),
+            ],
+        ),
+        TestGroup::new(
+            "pushed_arrays",
+            vec![
+                (
+                    r"function App() { const nodes = []; nodes.push(<A key='a' />); return <div>{nodes}</div>; }",
+                    None,
+                ),
+                (
+                    r"function App() { const nodes = []; nodes.push(<A />); register(nodes); return <div>{nodes}</div>; }",
+                    None,
+                ),
+                (
+                    r"function App() { const nodes = []; nodes.push(<A />); const copy = nodes; return <div>{copy}</div>; }",
+                    None,
+                ),
+                (r"function App() { const nodes = []; nodes.push(<A />); }", None),
+                (
+                    r"function App() { let nodes = []; nodes.push(<A />); nodes = []; return <div>{nodes}</div>; }",
+                    None,
+                ),
+                (
+                    r"function App() { var nodes = []; nodes.push(<A />); return <div>{nodes}</div>; }",
+                    None,
+                ),
+                (
+                    r"function App() { const nodes = getNodes(); nodes.push(<A />); return <div>{nodes}</div>; }",
+                    None,
+                ),
+                (
+                    r"function App() { const nodes = []; items.forEach(() => nodes.push(<A />)); return <div>{nodes}</div>; }",
+                    None,
+                ),
+                (
+                    r"function App() { const nodes = []; nodes.push({ el: <A /> }); return <div>{nodes}</div>; }",
+                    None,
+                ),
+                (
+                    r"function App() { const nodes = []; other.push(<A />); return <div>{nodes}</div>; }",
+                    None,
+                ),
+            ],
+            vec![
+                (
+                    r"function App() { const nodes = []; nodes.push(<A />); return <div>{nodes}</div>; }",
+                    None,
+                ),
+                (
+                    r"function App() { const nodes = []; nodes.push(<A key='a' />, (<B />)); nodes.unshift(<></>); return nodes; }",
+                    None,
+                ),
+                (
+                    r"function App() { let nodes = [<A key='a' />]; if (cond) nodes.push(<B />); return <ul>{cond ? nodes : null}</ul>; }",
+                    None,
+                ),
+                (
+                    r"const App = () => { const nodes = []; nodes.push(<A />); return <div>{nodes}</div>; };",
+                    None,
+                ),
+            ],
+        ),
+        TestGroup::new(
+            "create_element",
+            vec![
+                (r"items.map((item) => React.createElement(Row, { key: item.id, item }));", None),
+                (r"items.map((item) => React.createElement(Row, { ...item }));", None),
+                (r"items.map((item) => React.createElement(Row, props));", None),
+                (r"items.map((item) => React.createElement(...args));", None),
+                (r"items.map((item) => React.createElement(Row, { [name]: item }));", None),
+                (r"items.map((item) => React.cloneElement(template, { key: item.id }));", None),
+                (
+                    r"import { createElement } from 'react'; items.map((item) => createElement(Row, { key: item.id }));",
+                    None,
+                ),
+                (
+                    r"import * as React from 'react'; items.map((item) => React.cloneElement(template, { key: item.id, item }));",
+                    None,
+                ),
+                (
+                    r"import { createElement } from 'preact'; items.map((item) => createElement(Row, null));",
+                    None,
+                ),
+                (r"React.createElement(Row, null);", None),
+                (r"items.map((item) => document.createElement('li'));", None),
+                (
+                    r"React.Children.toArray(items.map((item) => React.createElement(Row, null)));",
+                    None,
+                ),
+            ],
+            vec![
+                (r"items.map((item) => React.createElement(Row, { item }));", None),
+                (r"items.map((item) => React.createElement(Row, null));", None),
+                (r"items.map((item) => React.createElement(Row));", None),
+                (r"items.map((item) => React.cloneElement(template, { item }));", None),
+                (r"items.map((item) => React.cloneElement(template));", None),
+                (r"items.map((item) => React.cloneElement(template, null));", None),
+                (r"[React.createElement(Row, null), React.createElement(Row, null)];", None),
+                (
+                    r"import { createElement } from 'react'; items.map((item) => createElement(Row, null));",
+                    None,
+                ),
+                (
+                    r"import { cloneElement } from 'react'; items.map((item) => cloneElement(template, { item }));",
+                    None,
+                ),
+                (
+                    r"import * as React from 'react'; items.map((item) => React.createElement(Row, { item }));",
+                    None,
+                ),
+                (
+                    r"import React from 'react'; items.map(function (item) { return React.cloneElement(template, null); });",
+                    None,
+                ),
+            ],
+        ),
+        TestGroup::new(
+            "key_in_spread_object",
+            vec![
+                (r"[<App {...{ key: 0 }} />];", None),
+                (r"[1, 2, 3].map(x => <App {...{ key: x }} />);", None),
+                (r"[1, 2, 3].map(x => <App {...props} {...{ id: x, 'key': x }} />);", None),
+            ],
+            vec![
+                (r"[<App {...key} />];", None),
+                (r"[<App {...{ id: 0 }} />];", None),
+                (r"[1, 2, 3].map(x => <App {...{ [key]: x }} />);", None),
+                (r"[1, 2, 3].map(x => <App {...{ ...props }} />);", None),
+            ],
+        ),
+        TestGroup::new(
+            "key_before_spread",
+            vec![
+                (r"<App {...{}} />;", None),
+                (r#"<App key="keyBeforeSpread" {...{}} />;"#, None),
+                (r#"<div key="keyBeforeSpread" {...{}} />;"#, None),
+            ],
+            vec![
+                (r#"[<App {...obj} key="keyAfterSpread" />];"#, None),
+                (r#"[<div {...obj} key="keyAfterSpread" />];"#, None),
+            ],
+        ),
+        TestGroup::new(
+            "only_flag_rendered_arrays",
+            vec![
+                (
+                    r"const tabs = [<TabA />, <TabB />]; registerTabs(tabs);",
+                    Some(serde_json::json!([{ "onlyFlagRenderedArrays": true }])),
+                ),
+                (
+                    r"registerTabs([<TabA />, <TabB />]);",
+                    Some(serde_json::json!([{ "onlyFlagRenderedArrays": true }])),
+                ),
+                (
+                    r"const routes = { home: [<Home />] };",
+                    Some(serde_json::json!([{ "onlyFlagRenderedArrays": true }])),
+                ),
+                (
+                    r"ReactDOM.render(<App />, [<A />]);",
+                    Some(serde_json::json!([{ "onlyFlagRenderedArrays": true }])),
+                ),
+            ],
+            vec![
+                (
+                    r#"createRoot(el).render([<App key="app" />, <Toaster />]);"#,
+                    Some(serde_json::json!([{ "onlyFlagRenderedArrays": true }])),
+                ),
+                (
+                    r#"ReactDOM.render([<App key="app" />, <Toaster />], el);"#,
+                    Some(serde_json::json!([{ "onlyFlagRenderedArrays": true }])),
+                ),
+                (
+                    r"hydrateRoot(el, [<App />]);",
+                    Some(serde_json::json!([{ "onlyFlagRenderedArrays": true }])),
+                ),
+                (
+                    r"const el = <div>{[<A />, <B />]}</div>;",
+                    Some(serde_json::json!([{ "onlyFlagRenderedArrays": true }])),
+                ),
+                (
+                    r"const tabs = [<TabA />, <TabB />]; const el = <Tabs>{tabs}</Tabs>;",
+                    Some(serde_json::json!([{ "onlyFlagRenderedArrays": true }])),
+                ),
+                (
+                    r"function Tabs() { return [<TabA />]; }",
+                    Some(serde_json::json!([{ "onlyFlagRenderedArrays": true }])),
+                ),
+                (
+                    r"const Tabs = () => (cond ? [<TabA />] : null);",
+                    Some(serde_json::json!([{ "onlyFlagRenderedArrays": true }])),
+                ),
+                (
+                    r"registerTabs([1, 2].map(x => <Tab />));",
+                    Some(serde_json::json!([{ "onlyFlagRenderedArrays": true }])),
+                ),
+            ],
+        ),
+        TestGroup::new(
+            "check_reduce",
+            vec![
+                (
+                    r"items.reduce((acc, x) => [...acc, <Item key={x.id} />], []);",
+                    Some(serde_json::json!([{ "checkReduce": true }])),
+                ),
+                (
+                    r"items.reduce((acc, x) => { return [...acc, <Item key={x.id} />]; }, []);",
+                    Some(serde_json::json!([{ "checkReduce": true }])),
+                ),
+                (
+                    r"items.reduce((acc, x) => <Wrapper>{acc}</Wrapper>, <Root />);",
+                    Some(serde_json::json!([{ "checkReduce": true }])),
+                ),
+            ],
+            vec![
+                (r"items.reduce((acc, x) => [...acc, <Item />], []);", None),
+                (
+                    r"items.reduce((acc, x) => [...acc, <Item />], []);",
+                    Some(serde_json::json!([{ "checkReduce": true }])),
+                ),
+                (
+                    r"items.reduce(function (acc, x) { return [...acc, <Item />]; }, []);",
+                    Some(serde_json::json!([{ "checkReduce": true }])),
+                ),
+            ],
+        ),
+        TestGroup::new(
+            "additional_iterator_methods",
+            vec![
+                (r"items.eachElement((x) => <Item />);", None),
+                (r"_.map(items, (x) => <Item />);", None),
+                (
+                    r"items.eachElement((x) => <Item key={x.id} />);",
+                    Some(serde_json::json!([{ "additionalIteratorMethods": ["eachElement"] }])),
+                ),
+                (
+                    r"eachElement(items, (x) => <Item key={x.id} />);",
+                    Some(
+                        serde_json::json!([{ "additionalIteratorMethods": [{ "name": "eachElement", "callbackIndex": 1 }] }]),
+                    ),
+                ),
+                (
+                    r"items.eachElement(<Item />, (x) => x);",
+                    Some(
+                        serde_json::json!([{ "additionalIteratorMethods": [{ "name": "eachElement", "callbackIndex": 1 }] }]),
+                    ),
+                ),
+                (
+                    r"items.forEachElement((x) => <Item />);",
+                    Some(serde_json::json!([{ "additionalIteratorMethods": ["eachElement"] }])),
+                ),
+            ],
+            vec![
+                (
+                    r"items.eachElement((x) => <Item />);",
+                    Some(serde_json::json!([{ "additionalIteratorMethods": ["eachElement"] }])),
+                ),
+                (
+                    r"items.eachElement(function (x) { return <Item />; });",
+                    Some(
+                        serde_json::json!([{ "additionalIteratorMethods": [{ "name": "eachElement" }] }]),
+                    ),
+                ),
+                (
+                    r"eachElement(items, (x) => <Item />);",
+                    Some(
+                        serde_json::json!([{ "additionalIteratorMethods": [{ "name": "eachElement", "callbackIndex": 1 }] }]),
+                    ),
+                ),
+                (
+                    r"_.map(items, (x) => <Item />);",
+                    Some(
+                        serde_json::json!([{ "additionalIteratorMethods": [{ "name": "map", "callbackIndex": 1 }] }]),
+                    ),
+                ),
+                (
+                    r"items.map((x) => <Item />);",
+                    Some(
+                        serde_json::json!([{ "additionalIteratorMethods": [{ "name": "map", "callbackIndex": 1 }] }]),
+                    ),
+                ),
+            ],
+        ),
+        TestGroup::new(
+            "check_props_key",
+            vec![
+                (r"<Row key={props.key} />;", None),
+                (r"<Row key={x} id={x} />;", Some(serde_json::json!([{ "checkPropsKey": true }]))),
+                (r"<Row key={props.id} />;", Some(serde_json::json!([{ "checkPropsKey": true }]))),
+                (r"<Row key={item.key} />;", Some(serde_json::json!([{ "checkPropsKey": true }]))),
+                (
+                    r"<Row key={props[key]} />;",
+                    Some(serde_json::json!([{ "checkPropsKey": true }])),
+                ),
+                (r"<Row id={props.key} />;", Some(serde_json::json!([{ "checkPropsKey": true }]))),
+            ],
+            vec![
+                (r"<Row key={props.key} />;", Some(serde_json::json!([{ "checkPropsKey": true }]))),
+                (
+                    r"<Row key={someProps.key} />;",
+                    Some(serde_json::json!([{ "checkPropsKey": true }])),
+                ),
+                (
+                    r"<Row key={props?.key} />;",
+                    Some(serde_json::json!([{ "checkPropsKey": true }])),
+                ),
+                (
+                    r"<Row key={props['key']} />;",
+                    Some(serde_json::json!([{ "checkPropsKey": true }])),
+                ),
+                (
+                    r"class List { render() { return <Row key={this.props.key} />; } }",
+                    Some(serde_json::json!([{ "checkPropsKey": true }])),
+                ),
+                (
+                    r"function Row(props) { return <li key={(props.key)} />; }",
+                    Some(serde_json::json!([{ "checkPropsKey": true }])),
+                ),
+            ],
+        ),
+    ]
+}
+
+fn react_version_groups() -> Vec<TestGroup> {
+    let settings = |version: &str| {
+        Some(serde_json::json!({ "settings": { "react": { "version": version } } }))
+    };
+    let always = Some(serde_json::json!([{ "checkKeyMustBeforeSpread": "always" }]));
+
+    vec![TestGroup::new(
+        "key_before_spread_react_version",
+        vec![
+            (r#"<App {...props} key="app" />"#, None, settings("19.0.0")),
+            (r#"<App {...props} key="app" />"#, None, settings("19.1")),
+            (r#"<App {...props} key="app" />"#, None, settings("20")),
+            (r#"<App key="app" {...props} />"#, always.clone(), settings("19.0.0")),
+        ],
+        vec![
+            (r#"<App {...props} key="app" />"#, None, None),
+            (r#"<App {...props} key="app" />"#, None, settings("detect")),
+            (r#"<App {...props} key="app" />"#, None, settings("18.3.1")),
+            (r#"<App {...props} key="app" />"#, always.clone(), settings("19.0.0")),
+            (r#"<App {...props} key="app" />"#, always, None),
+        ],
+    )]
+}
+
+#[test]
+fn test() {
+    let fix = vec![
+        (
+            r"items.map((x) => <App id={x.id} />);",
+            r"items.map((x) => <App key={x.id} id={x.id} />);",
+        ),
+        (
+            r"items.map((x) => <App<T> a={x.key} b />);",
+            r"items.map((x) => <App<T> key={x.key} a={x.key} b />);",
+        ),
+        (r#"["a", "b"].map((x) => <App />);"#, r#"["a", "b"].map((x) => <App key={x} />);"#),
+        (
+            r"names.map((name: string) => <App />);",
+            r"names.map((name: string) => <App key={name} />);",
+        ),
+        (
+            "items.map((item) => (\n  <Item\n    id={item.id}\n    title={item.title}\n  />\n));",
+            "items.map((item) => (\n  <Item\n    key={item.id}\n    id={item.id}\n    title={item.title}\n  />\n));",
+        ),
+        (
+            "function List() {\n\treturn (\n\t\t<ul>\n\t\t\t{items.map((item) => (\n\t\t\t\t<Item\n\t\t\t\t\tid={item.id}\n\t\t\t\t/>\n\t\t\t))}\n\t\t</ul>\n\t);\n}",
+            "function List() {\n\treturn (\n\t\t<ul>\n\t\t\t{items.map((item) => (\n\t\t\t\t<Item\n\t\t\t\t\tkey={item.id}\n\t\t\t\t\tid={item.id}\n\t\t\t\t/>\n\t\t\t))}\n\t\t</ul>\n\t);\n}",
+        ),
+        (
+            r"import React from 'react'; items.map((x) => <>{x.id}</>);",
+            r"import React from 'react'; items.map((x) => <React.Fragment key={x.id}>{x.id}</React.Fragment>);",
+        ),
+        (
+            "import { Fragment } from 'react';\nitems.map((x) => (\n  <>\n    <dt>{x.id}</dt>\n    <dd />\n  </>\n));",
+            "import { Fragment } from 'react';\nitems.map((x) => (\n  <Fragment key={x.id}>\n    <dt>{x.id}</dt>\n    <dd />\n  </Fragment>\n));",
+        ),
+        // No obvious key.
+        (r"items.map((x) => <App />);", r"items.map((x) => <App />);"),
+        (r"items.map((x) => <App a={x.name} />);", r"items.map((x) => <App a={x.name} />);"),
+        (r"[<App />, <App />];", r"[<App />, <App />];"),
+        (r"items.map(({ id }) => <App />);", r"items.map(({ id }) => <App />);"),
+        (
+            r"Array.from({ length: 3 }, (_, i) => <App />);",
+            r"Array.from({ length: 3 }, (_, i) => <App />);",
+        ),
+        // Neither `React` nor `Fragment` is in scope.
+        (r"items.map((x) => <>{x.id}</>);", r"items.map((x) => <>{x.id}</>);"),
+    ];
+
+    Tester::from_groups(JsxKey::NAME, JsxKey::PLUGIN, groups()).expect_fix(fix).test_and_snapshot();
+}
+
+#[test]
+fn test_key_before_spread_react_version() {
+    // The suggested `key` is placed before any spread.
+    let fix = vec![(
+        r"items.map((x) => <App id={x.id} {...props} />);",
+        r"items.map((x) => <App key={x.id} id={x.id} {...props} />);",
+    )];
+
+    Tester::from_groups(JsxKey::NAME, JsxKey::PLUGIN, react_version_groups())
+        .with_snapshot_suffix("react_version")
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
+
+/// Names of the elements reported in `source_text`, sorted.
+fn reported_elements(source_text: &str) -> Vec<String> {
+    use std::{rc::Rc, sync::Arc};
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use crate::{ContextHost, ModuleRecord, options::LintOptions};
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::jsx()).parse();
+    let semantic = SemanticBuilder::new().with_cfg(true).build(&ret.program).semantic;
+    let host = Rc::new(ContextHost::new(
+        "test.jsx",
+        Rc::new(semantic),
+        Arc::new(ModuleRecord::default()),
+        LintOptions::default(),
+        Arc::default(),
+    ));
+    let ctx = Rc::clone(&host)
+        .spawn_for_test()
+        .with_plugin_name(JsxKey::PLUGIN)
+        .with_rule_name(JsxKey::NAME)
+        .with_rule_fix_capabilities(JsxKey::FIX);
+    let rule = JsxKey::default();
+    for node in ctx.nodes() {
+        rule.run(node, &ctx);
+    }
+
+    let mut elements = host
+        .take_diagnostics()
+        .iter()
+        .map(|message| {
+            let labels = message.error.labels.as_ref().unwrap();
+            let element =
+                labels.iter().find(|label| label.label() == Some("Element generated here."));
+            let element = element.unwrap();
+            source_text[element.offset()..element.offset() + element.len()].to_string()
+        })
+        .collect::<Vec<_>>();
+    elements.sort_unstable();
+    elements
+}
+
+/// Each keyless element which can be returned from the callback is reported once.
+#[test]
+fn test_every_returned_element_reported_once() {
+    let source_text = r"
+items.map((item) => {
+    switch (item.type) {
+        case 'header':
+            return <Header />;
+        case 'footer':
+            return <Footer />;
+        case 'row':
+            try {
+                return <Row />;
+            } finally {
+                log(item);
+            }
+        default:
+            return <Fallback />;
+    }
+});
+";
+
+    assert_eq!(reported_elements(source_text), ["Fallback", "Footer", "Header", "Row"]);
+}
+
+/// Rows built by a `map` inside `useMemo` and rendered as children of another element are
+/// reported once, at the element returned from the `map` callback.
+#[test]
+fn test_use_memo_rows_rendered_as_children() {
+    let source_text = r"
+function List({ items }) {
+    const rows = useMemo(() => items.map(x => <Row />), [items]);
+    return <ul>{rows}</ul>;
+}
+
+function KeyedList({ items }) {
+    const rows = useMemo(() => items.map(x => <Row key={x.id} />), [items]);
+    return <ul>{rows}</ul>;
+}
+
+function BlockBodyList({ items }) {
+    const rows = React.useMemo(() => {
+        return items.map(function (x) {
+            return <Item />;
+        });
+    }, [items]);
+    return <ul>{rows}</ul>;
+}
+";
+
+    assert_eq!(reported_elements(source_text), ["Item", "Row"]);
+}
+
+/// Every clause is specified by a test group with both passing and failing cases, and every
+/// test group specifies a clause.
+#[test]
+fn test_spec() {
+    let groups = groups().into_iter().chain(react_version_groups()).collect::<Vec<_>>();
+
+    for (i, clause) in SPEC.iter().enumerate() {
+        assert!(
+            SPEC[..i].iter().all(|other| other.group != clause.group),
+            "clause `{}` is specified twice",
+            clause.group
+        );
+        assert!(!clause.behavior.is_empty(), "clause `{}` has no behavior", clause.group);
+        let group = groups.iter().find(|group| group.name == clause.group).unwrap_or_else(|| {
+            panic!("clause `{}` has no test group", clause.group);
+        });
+        assert!(!group.pass.is_empty(), "clause `{}` has no passing cases", clause.group);
+        assert!(!group.fail.is_empty(), "clause `{}` has no failing cases", clause.group);
+    }
+
+    for (i, group) in groups.iter().enumerate() {
+        assert!(
+            groups[..i].iter().all(|other| other.name != group.name),
+            "test group `{}` is defined twice",
+            group.name
+        );
+        assert!(
+            SPEC.iter().any(|clause| clause.group == group.name),
+            "test group `{}` is not referenced by any clause",
+            group.name
+        );
+    }
+}
+
+/// The decision trace doubles as documentation of how the rule classifies elements.
+#[test]
+fn test_trace() {
+    use std::{
+        io,
+        rc::Rc,
+        sync::{Arc, Mutex},
+    };
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use crate::{ContextHost, ModuleRecord, options::LintOptions};
+
+    #[derive(Clone, Default)]
+    struct TraceBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for TraceBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let source_text = r"
+items.map((item) => <Item key={item.id} />);
+React.Children.toArray(items.map((item) => <Item />));
+items.forEach((item) => { list.push(<Item />); });
+[<Item />];
+items.map((item) => <><Item /></>);
+items.reduce((acc, item) => [...acc, <Item />], []);
+";
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::jsx()).parse();
+    let semantic = SemanticBuilder::new().with_cfg(true).build(&ret.program).semantic;
+    let ctx = Rc::new(ContextHost::new(
+        "trace.jsx",
+        Rc::new(semantic),
+        Arc::new(ModuleRecord::default()),
+        LintOptions::default(),
+        Arc::default(),
+    ))
+    .spawn_for_test()
+    .with_plugin_name(JsxKey::PLUGIN)
+    .with_rule_name(JsxKey::NAME)
+    .with_rule_fix_capabilities(JsxKey::FIX);
+
+    let buffer = TraceBuffer::default();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::TRACE)
+        .with_writer({
+            let buffer = buffer.clone();
+            move || buffer.clone()
+        })
+        .with_ansi(false)
+        .without_time()
+        .with_level(false)
+        .with_target(false)
+        .finish();
+    tracing::subscriber::with_default(subscriber, || {
+        let rule = JsxKey::default();
+        for node in ctx.nodes() {
+            rule.run(node, &ctx);
+        }
+    });
+
+    let trace = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+    insta::with_settings!({
+        snapshot_path => "../../../snapshots",
+        prepend_module_to_snapshot => false,
+        omit_expression => true,
+    }, {
+        insta::assert_snapshot!("react_jsx_key_trace", trace);
+    });
+}
@@ -2,7 +2,9 @@ use crate::{
     AstNode,
     context::{ContextHost, LintContext},
     fixer::{RuleFix, RuleFixer},
+    module_record::ImportImportName,
     rule::Rule,
+    utils::is_import,
 };
 use oxc_allocator::Vec as ArenaVec;
 use oxc_ast::{
@@ -73,7 +75,7 @@ impl Rule for JsxNoUselessFragment {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::JSXElement(jsx_elem) => {
-                if !is_jsx_fragment(&jsx_elem.opening_element) {
+                if !is_jsx_fragment(&jsx_elem.opening_element, ctx) {
                     return;
                 }
                 self.check_element(node, jsx_elem, ctx);
@@ -259,7 +261,7 @@ fn can_fix(node: &AstNode, children: &ArenaVec<JSXChild<'_>>, ctx: &LintContext)
             .name
             .get_identifier_name()
             .is_some_and(|ident| ident.chars().all(char::is_lowercase))
-            && !is_jsx_fragment(&el.opening_element)
+            && !is_jsx_fragment(&el.opening_element, ctx)
         {
             return false;
         }
@@ -321,12 +323,17 @@ fn is_html_element(elem_name: &JSXElementName) -> bool {
     ident.name.starts_with(char::is_lowercase)
 }
 
-fn is_jsx_fragment(elem: &JSXOpeningElement) -> bool {
+/// Returns `true` for `<Fragment>` and `<React.Fragment>`, including where `Fragment` or `React`
+/// are imported from `react` under another name.
+fn is_jsx_fragment<'a>(elem: &'a JSXOpeningElement<'a>, ctx: &LintContext<'a>) -> bool {
     match &elem.name {
-        JSXElementName::IdentifierReference(ident) => ident.name == "Fragment",
+        JSXElementName::IdentifierReference(ident) => {
+            ident.name == "Fragment" || is_fragment_import(ctx, &ident.name)
+        }
         JSXElementName::MemberExpression(mem_expr) => {
             if let JSXMemberExpressionObject::IdentifierReference(ident) = &mem_expr.object {
-                ident.name == "React" && mem_expr.property.name == "Fragment"
+                mem_expr.property.name == "Fragment"
+                    && (ident.name == "React" || is_import(ctx, &ident.name, "React", "react"))
             } else {
                 false
             }
@@ -337,6 +344,15 @@ fn is_jsx_fragment(elem: &JSXOpeningElement) -> bool {
     }
 }
 
+/// Returns `true` if `local_name` is bound by `import { Fragment as local_name } from 'react'`.
+fn is_fragment_import(ctx: &LintContext, local_name: &str) -> bool {
+    ctx.module_record().import_entries.iter().any(|import| {
+        import.module_request.name() == "react"
+            && import.local_name.name() == local_name
+            && matches!(&import.import_name, ImportImportName::Name(name) if name.name() == "Fragment")
+    })
+}
+
 fn has_less_than_two_children(children: &oxc_allocator::Vec<'_, JSXChild<'_>>) -> bool {
     let non_padding_children = children.iter().filter(|v| is_padding_spaces(v)).collect::<Vec<_>>();
 
@@ -391,6 +407,10 @@ fn test() {
         (r"<Fragment key={item.id}>{item.value}</Fragment>", None),
         (r"<Fooo content={<>eeee ee eeeeeee eeeeeeee</>} />", None),
         (r"<>{foos.map(foo => foo)}</>", None),
+        (r"import { Suspense } from 'react'; <Suspense><Foo /></Suspense>", None),
+        (r"import { Fragment as F } from 'preact'; <F><Foo /></F>", None),
+        (r"import React from 'preact'; <R.Fragment><Foo /></R.Fragment>", None),
+        (r"import { Fragment as F } from 'react'; <F key={id}><Foo /></F>", None),
         (r"<>{moo}</>", Some(json!([{ "allowExpressions": true }]))),
         (
             r"
@@ -465,6 +485,9 @@ fn test() {
             None,
         ),
         (r"<><Foo>{moo}</Foo></>", None),
+        (r"import { Fragment as F } from 'react'; <F><Foo /></F>", None),
+        (r"import R from 'react'; <R.Fragment><Foo /></R.Fragment>", None),
+        (r"import * as R from 'react'; <div><R.Fragment>foo</R.Fragment></div>", None),
     ];
 
     let fix = vec![
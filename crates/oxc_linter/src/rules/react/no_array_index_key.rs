@@ -1,15 +1,20 @@
 use oxc_ast::{
     AstKind,
     ast::{
-        Argument, CallExpression, Expression, JSXAttributeItem, JSXAttributeName,
-        JSXAttributeValue, JSXElement, JSXExpression, ObjectPropertyKind, PropertyKey,
+        Argument, BinaryOperator, BindingPatternKind, CallExpression, Expression, FormalParameter,
+        FormalParameters, IdentifierReference, JSXAttribute, JSXAttributeValue, ObjectPropertyKind,
+        PropertyKey,
     },
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
+use oxc_semantic::SymbolId;
 use oxc_span::Span;
 
-use crate::{AstNode, ast_util::is_method_call, context::LintContext, rule::Rule};
+use crate::{
+    AstNode, ast_util::is_method_call, context::LintContext, rule::Rule,
+    utils::iterator_index_param,
+};
 
 fn no_array_index_key_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Usage of Array index in keys is not allowed")
@@ -47,137 +52,172 @@ declare_oxc_lint!(
     ///     <Hello key={thing.id} />
     /// ));
     /// ```
+    ///
+    /// The index is also detected when it is used in a template literal (`` key={`item-${index}`} ``),
+    /// concatenated with a string, converted with `String(index)` or `index.toString()`, or
+    /// assigned to another variable first.
+    ///
+    /// When the element is read by an `id`, `_id` or `uuid` property elsewhere in the callback,
+    /// or one of these properties is destructured from it, a suggestion is offered to use that
+    /// property as the key instead.
     NoArrayIndexKey,
     react,
     perf,
+    suggestion
 );
 
-fn check_jsx_element<'a>(
-    jsx: &'a JSXElement,
-    node: &'a AstNode,
-    ctx: &'a LintContext,
-    prop_name: &'static str,
-) {
-    let Some(index_param_name) = find_index_param_name(node, ctx) else {
-        return;
-    };
-
-    for attr in &jsx.opening_element.attributes {
-        let JSXAttributeItem::Attribute(attr) = attr else {
-            return;
-        };
-
-        let JSXAttributeName::Identifier(ident) = &attr.name else {
-            return;
-        };
-
-        if ident.name.as_str() != prop_name {
-            return;
-        }
-
-        let Some(JSXAttributeValue::ExpressionContainer(container)) = &attr.value else {
-            return;
-        };
-
-        let JSXExpression::Identifier(expr) = &container.expression else {
-            return;
-        };
+/// Property names which are assumed to uniquely identify an element, in order of preference.
+const ID_PROPERTY_NAMES: [&str; 3] = ["id", "_id", "uuid"];
 
-        if expr.name.as_str() == index_param_name {
-            ctx.diagnostic(no_array_index_key_diagnostic(attr.span));
-        }
+fn check_jsx_attribute<'a>(attr: &JSXAttribute<'a>, ctx: &LintContext<'a>) {
+    if !attr.is_identifier("key") {
+        return;
     }
+    let Some(JSXAttributeValue::ExpressionContainer(container)) = &attr.value else {
+        return;
+    };
+    let Some(expr) = container.expression.as_expression() else {
+        return;
+    };
+    check_key(expr, attr.span, ctx);
 }
 
-fn check_react_clone_element<'a>(
-    call_expr: &'a CallExpression,
-    node: &'a AstNode,
-    ctx: &'a LintContext,
-) {
-    let Some(index_param_name) = find_index_param_name(node, ctx) else {
+fn check_react_clone_element<'a>(call_expr: &CallExpression<'a>, ctx: &LintContext<'a>) {
+    if !is_method_call(call_expr, Some(&["React"]), Some(&["cloneElement"]), Some(2), Some(3)) {
+        return;
+    }
+    let Some(Argument::ObjectExpression(obj_expr)) = call_expr.arguments.get(1) else {
         return;
     };
 
-    if is_method_call(call_expr, Some(&["React"]), Some(&["cloneElement"]), Some(2), Some(3)) {
-        let Some(Argument::ObjectExpression(obj_expr)) = call_expr.arguments.get(1) else {
-            return;
+    for prop_kind in &obj_expr.properties {
+        let ObjectPropertyKind::ObjectProperty(prop) = prop_kind else {
+            continue;
         };
 
-        for prop_kind in &obj_expr.properties {
-            let ObjectPropertyKind::ObjectProperty(prop) = prop_kind else {
-                continue;
-            };
-
-            let PropertyKey::StaticIdentifier(key_ident) = &prop.key else {
-                continue;
-            };
-
-            let Expression::Identifier(value_ident) = &prop.value else {
-                continue;
-            };
+        let PropertyKey::StaticIdentifier(key_ident) = &prop.key else {
+            continue;
+        };
 
-            if key_ident.name.as_str() == "key" && value_ident.name.as_str() == index_param_name {
-                ctx.diagnostic(no_array_index_key_diagnostic(obj_expr.span));
-            }
+        if key_ident.name.as_str() == "key" {
+            check_key(&prop.value, obj_expr.span, ctx);
         }
     }
 }
 
-fn find_index_param_name<'a>(node: &'a AstNode, ctx: &'a LintContext) -> Option<&'a str> {
-    for ancestor in ctx.nodes().ancestors(node.id()) {
-        if let AstKind::CallExpression(call_expr) = ancestor.kind() {
-            let Expression::StaticMemberExpression(expr) = &call_expr.callee else {
-                return None;
-            };
+fn check_key<'a>(key: &Expression<'a>, span: Span, ctx: &LintContext<'a>) {
+    if let Some((index, params, position)) = find_index_reference(key, ctx) {
+        report(span, index.span, params.items.get(position - 1), ctx);
+    }
+}
 
-            if SECOND_INDEX_METHODS.contains(&expr.property.name.as_str()) {
-                return find_index_param_name_by_position(call_expr, 1);
+/// Find a reference to the index parameter of an iterator callback in the value of a `key`:
+/// `index`, `` `item-${index}` ``, `'item-' + index`, `String(index)` or `index.toString()`.
+///
+/// References to a variable initialized to the index (`const i = index;`) are also found.
+fn find_index_reference<'a, 'b>(
+    expr: &'b Expression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<(&'b IdentifierReference<'a>, &'a FormalParameters<'a>, usize)> {
+    match expr.without_parentheses() {
+        Expression::Identifier(ident) => {
+            let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+            let (params, position) = iterator_index_param(symbol_id, ctx)
+                .or_else(|| aliased_index_param(symbol_id, ctx))?;
+            Some((ident, params, position))
+        }
+        Expression::TemplateLiteral(template) => {
+            template.expressions.iter().find_map(|expr| find_index_reference(expr, ctx))
+        }
+        Expression::BinaryExpression(binary) if binary.operator == BinaryOperator::Addition => {
+            find_index_reference(&binary.left, ctx)
+                .or_else(|| find_index_reference(&binary.right, ctx))
+        }
+        Expression::CallExpression(call) => {
+            if call.callee.is_specific_id("String") {
+                let expr = call.arguments.first()?.as_expression()?;
+                return find_index_reference(expr, ctx);
             }
-
-            if THIRD_INDEX_METHODS.contains(&expr.property.name.as_str()) {
-                return find_index_param_name_by_position(call_expr, 2);
+            let member = call.callee.as_member_expression()?;
+            if member.static_property_name() == Some("toString") {
+                return find_index_reference(member.object(), ctx);
             }
+            None
         }
+        _ => None,
     }
+}
 
-    None
+/// If `symbol_id` is a variable initialized to the index parameter of an iterator callback,
+/// e.g. `i` in `items.map((item, index) => { const i = index; ... })`, find that parameter.
+fn aliased_index_param<'a>(
+    symbol_id: SymbolId,
+    ctx: &LintContext<'a>,
+) -> Option<(&'a FormalParameters<'a>, usize)> {
+    let AstKind::VariableDeclarator(decl) =
+        ctx.nodes().kind(ctx.scoping().symbol_declaration(symbol_id))
+    else {
+        return None;
+    };
+    let Expression::Identifier(init) = decl.init.as_ref()?.without_parentheses() else {
+        return None;
+    };
+    let init_symbol_id = ctx.scoping().get_reference(init.reference_id()).symbol_id()?;
+    iterator_index_param(init_symbol_id, ctx)
 }
 
-fn find_index_param_name_by_position<'a>(
-    call_expr: &'a CallExpression,
-    position: usize,
-) -> Option<&'a str> {
-    call_expr.arguments.first().and_then(|argument| match argument {
-        Argument::ArrowFunctionExpression(arrow_fn_expr) => {
-            Some(arrow_fn_expr.params.items.get(position)?.pattern.get_identifier_name()?.as_str())
+fn report(span: Span, index_span: Span, item: Option<&FormalParameter>, ctx: &LintContext) {
+    let diagnostic = no_array_index_key_diagnostic(span);
+    match item.and_then(|item| find_stable_key(item, ctx)) {
+        Some(key) => ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+            let message = format!("Use `{key}` as the key");
+            fixer.replace(index_span, key).with_message(message)
+        }),
+        None => ctx.diagnostic(diagnostic),
+    }
+}
+
+/// Find an expression which identifies the element being iterated over, based on how the
+/// element is used in the callback: `item.id` for `(item, index) => ... item.id ...`,
+/// or `id` for `({ id }, index) => ...`.
+fn find_stable_key(item: &FormalParameter, ctx: &LintContext) -> Option<String> {
+    match &item.pattern.kind {
+        BindingPatternKind::BindingIdentifier(ident) => {
+            let used_properties = ctx
+                .semantic()
+                .symbol_references(ident.symbol_id())
+                .filter_map(|reference| match ctx.nodes().parent_kind(reference.node_id()) {
+                    AstKind::StaticMemberExpression(member_expr) => {
+                        Some(member_expr.property.name.as_str())
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            ID_PROPERTY_NAMES
+                .iter()
+                .find(|name| used_properties.contains(name))
+                .map(|name| format!("{}.{name}", ident.name))
         }
-        Argument::FunctionExpression(regular_fn_expr) => Some(
-            regular_fn_expr.params.items.get(position)?.pattern.get_identifier_name()?.as_str(),
-        ),
+        BindingPatternKind::ObjectPattern(pattern) => ID_PROPERTY_NAMES.iter().find_map(|&name| {
+            pattern.properties.iter().find_map(|property| {
+                if property.computed || property.key.static_name()? != name {
+                    return None;
+                }
+                match &property.value.kind {
+                    BindingPatternKind::BindingIdentifier(ident) => Some(ident.name.to_string()),
+                    _ => None,
+                }
+            })
+        }),
         _ => None,
-    })
+    }
 }
 
-// things[`${method_name}`]((thing, index) => (<Hello key={index} />));
-const SECOND_INDEX_METHODS: [&str; 8] =
-    ["every", "filter", "find", "findIndex", "flatMap", "forEach", "map", "some"];
-
-const THIRD_INDEX_METHODS: [&str; 2] = [
-    // things.reduce((collection, thing, index) => (collection.concat(<Hello key={index} />)), []);
-    "reduce",
-    // things.reduceRight((collection, thing, index) => (collection.concat(<Hello key={index} />)), []);
-    "reduceRight",
-];
-
 impl Rule for NoArrayIndexKey {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
-            AstKind::JSXElement(jsx) => {
-                check_jsx_element(jsx, node, ctx, "key");
-            }
-            AstKind::CallExpression(call_expr) => {
-                check_react_clone_element(call_expr, node, ctx);
-            }
+            AstKind::JSXAttribute(attr) => check_jsx_attribute(attr, ctx),
+            AstKind::CallExpression(call_expr) => check_react_clone_element(call_expr, ctx),
             _ => (),
         }
     }
@@ -232,6 +272,16 @@ fn test() {
             collection.concat(<Hello key={thing.id} />)
           ), []);
         ",
+        r"things.map((index) => <Hello key={index} />);",
+        r"things.map((thing, i) => render((i) => <Hello key={i} />));",
+        r"things.map((thing, index) => {
+            const renderRow = (row, index) => <Hello key={index} />;
+            return renderRow(thing);
+          });
+        ",
+        r"things.map((thing) => { const index = getIndex(thing); return <Hello key={index} />; });",
+        r"things.map((thing, index) => <Hello key={`${thing.id}`} />);",
+        r"Array.from(things, (thing) => <Hello key={thing.id} />);",
     ];
 
     let fail = vec![
@@ -279,7 +329,58 @@ fn test() {
             collection.concat(<Hello key={index} />)
           ), []);
         ",
+        r"things.map((thing, index) => <Hello key={index} id={thing.id} />);",
+        r"things.map(({ id, name }, index) => <Hello key={index} name={name} />);",
+        r"things.map((thing, idx) => <Hello name={thing.name} key={idx} />);",
+        r"things.map((thing, index) => { const i = index; return <Hello key={i} />; });",
+        r"things.map((thing, i) => <Hello key={`item-${i}`} />);",
+        r"things.map((thing, i) => <Hello key={'item-' + i} />);",
+        r"things.map((thing, i) => <Hello key={String(i)} />);",
+        r"things.map((thing, i) => <Hello key={i.toString()} />);",
+        r"things?.map((thing, i) => <Hello key={i} />);",
+        r"Array.from(things, (thing, i) => <Hello key={i} />);",
+        r"things.map((thing, i) => thing.children.map((child) => <Hello key={i} />));",
+        r"things.map((thing, index) => <Hello key={index} name={thing.name} />);",
+        r"things.map((thing, index) => React.cloneElement(thing, { key: index, title: thing.uuid }));",
+    ];
+
+    let fix = vec![
+        (
+            r"things.map((thing, index) => <Hello key={index} id={thing.id} />);",
+            r"things.map((thing, index) => <Hello key={thing.id} id={thing.id} />);",
+        ),
+        (
+            r"things.map((thing, index) => <Hello key={index} title={thing.uuid} ref={thing._id} />);",
+            r"things.map((thing, index) => <Hello key={thing._id} title={thing.uuid} ref={thing._id} />);",
+        ),
+        (
+            r"things.map(({ id: thingId, name }, index) => <Hello key={index} name={name} />);",
+            r"things.map(({ id: thingId, name }, index) => <Hello key={thingId} name={name} />);",
+        ),
+        (
+            r"things.reduce((acc, item, index) => acc.concat(<Hello key={index} label={item.id} />), []);",
+            r"things.reduce((acc, item, index) => acc.concat(<Hello key={item.id} label={item.id} />), []);",
+        ),
+        (
+            r"things.map((thing, index) => React.cloneElement(thing, { key: index, title: thing.uuid }));",
+            r"things.map((thing, index) => React.cloneElement(thing, { key: thing.uuid, title: thing.uuid }));",
+        ),
+        (
+            r"things.map((thing, i) => <Hello key={`item-${i}`} id={thing.id} />);",
+            r"things.map((thing, i) => <Hello key={`item-${thing.id}`} id={thing.id} />);",
+        ),
+        // No id-like property is used, so there is nothing to suggest.
+        (
+            r"things.map((thing, index) => <Hello key={index} name={thing.name} />);",
+            r"things.map((thing, index) => <Hello key={index} name={thing.name} />);",
+        ),
+        (
+            r"things.map(({ name }, index) => <Hello key={index} name={name} />);",
+            r"things.map(({ name }, index) => <Hello key={index} name={name} />);",
+        ),
     ];
 
-    Tester::new(NoArrayIndexKey::NAME, NoArrayIndexKey::PLUGIN, pass, fail).test_and_snapshot();
+    Tester::new(NoArrayIndexKey::NAME, NoArrayIndexKey::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
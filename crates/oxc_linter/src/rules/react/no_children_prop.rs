@@ -1,9 +1,13 @@
 use oxc_ast::{
     AstKind,
-    ast::{Argument, JSXAttributeName, ObjectPropertyKind},
+    ast::{
+        Argument, BindingPattern, BindingPatternKind, CallExpression, Expression, JSXAttributeItem,
+        JSXAttributeName, JSXAttributeValue, JSXElement, ObjectPropertyKind,
+    },
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
+use oxc_semantic::SymbolId;
 use oxc_span::{GetSpan, Span};
 
 use crate::{AstNode, context::LintContext, rule::Rule, utils::is_create_element_call};
@@ -14,6 +18,17 @@ fn no_children_prop_diagnostic(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
+fn children_conflict_diagnostic(prop_span: Span, children_span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("The `children` prop conflicts with the children passed to the element.")
+        .with_help(
+            "Remove the `children` prop, it is overridden by the children passed to the element",
+        )
+        .with_labels([
+            prop_span.primary_label("`children` prop"),
+            children_span.label("Children passed here"),
+        ])
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct NoChildrenProp;
 
@@ -52,42 +67,174 @@ declare_oxc_lint!(
     /// React.createElement("div", {}, 'Children')
     /// React.createElement("div", 'Child 1', 'Child 2')
     /// ```
+    ///
+    /// When an element is also passed children, the `children` prop is ignored, and a conflict
+    /// is reported instead.
+    ///
+    /// `children` forwarded from a rest element, e.g. `rest.children` in
+    /// `const { title, ...rest } = props`, is not reported.
+    ///
+    /// A `children` prop with a plain string value on a self-closing element, e.g.
+    /// `<div children="Children" />`, is fixed to `<div>Children</div>`.
     NoChildrenProp,
     react,
-    correctness
+    correctness,
+    conditional_fix
 );
 
 impl Rule for NoChildrenProp {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
-            AstKind::JSXAttribute(attr) => {
-                let JSXAttributeName::Identifier(attr_ident) = &attr.name else {
-                    return;
-                };
-                if attr_ident.name == "children" {
-                    ctx.diagnostic(no_children_prop_diagnostic(attr_ident.span));
-                }
+            AstKind::JSXElement(jsx_elem) => check_jsx_element(jsx_elem, ctx),
+            AstKind::CallExpression(call_expr) => check_create_element_call(call_expr, ctx),
+            _ => {}
+        }
+    }
+}
+
+fn check_jsx_element<'a>(jsx_elem: &JSXElement<'a>, ctx: &LintContext<'a>) {
+    for attr in &jsx_elem.opening_element.attributes {
+        let JSXAttributeItem::Attribute(attr) = attr else {
+            continue;
+        };
+        let JSXAttributeName::Identifier(attr_ident) = &attr.name else {
+            continue;
+        };
+        if attr_ident.name != "children" {
+            continue;
+        }
+
+        if let Some(JSXAttributeValue::ExpressionContainer(container)) = &attr.value {
+            if container.expression.as_expression().is_some_and(|expr| is_rest_derived(expr, ctx)) {
+                continue;
             }
-            AstKind::CallExpression(call_expr) => {
-                if is_create_element_call(call_expr) {
-                    if let Some(Argument::ObjectExpression(obj_expr)) = call_expr.arguments.get(1) {
-                        if let Some(span) = obj_expr.properties.iter().find_map(|prop| {
-                            if let ObjectPropertyKind::ObjectProperty(prop) = prop {
-                                if prop.key.is_specific_static_name("children") {
-                                    return Some(prop.key.span());
-                                }
-                            }
-
-                            None
-                        }) {
-                            ctx.diagnostic(no_children_prop_diagnostic(span));
-                        }
-                    }
-                }
+        }
+
+        if let (Some(first), Some(last)) = (jsx_elem.children.first(), jsx_elem.children.last()) {
+            let children_span = Span::new(first.span().start, last.span().end);
+            ctx.diagnostic(children_conflict_diagnostic(attr_ident.span, children_span));
+            continue;
+        }
+
+        let diagnostic = no_children_prop_diagnostic(attr_ident.span);
+        match fixed_element_text(jsx_elem, attr.span, ctx) {
+            Some(text) => {
+                ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(jsx_elem.span, text));
             }
-            _ => {}
+            None => ctx.diagnostic(diagnostic),
+        }
+    }
+}
+
+fn check_create_element_call<'a>(call_expr: &CallExpression<'a>, ctx: &LintContext<'a>) {
+    if !is_create_element_call(call_expr) {
+        return;
+    }
+    let Some(Argument::ObjectExpression(obj_expr)) = call_expr.arguments.get(1) else {
+        return;
+    };
+    let Some(prop) = obj_expr.properties.iter().find_map(|prop| match prop {
+        ObjectPropertyKind::ObjectProperty(prop)
+            if prop.key.is_specific_static_name("children") =>
+        {
+            Some(prop)
+        }
+        _ => None,
+    }) else {
+        return;
+    };
+
+    if is_rest_derived(&prop.value, ctx) {
+        return;
+    }
+
+    let span = prop.key.span();
+    if let (Some(first), Some(last)) = (call_expr.arguments.get(2), call_expr.arguments.last()) {
+        let children_span = Span::new(first.span().start, last.span().end);
+        ctx.diagnostic(children_conflict_diagnostic(span, children_span));
+    } else {
+        ctx.diagnostic(no_children_prop_diagnostic(span));
+    }
+}
+
+/// Rewrite `<div children="Children" />` to `<div>Children</div>`.
+///
+/// Returns `None` unless the element is self-closing and the value is a string which means the
+/// same as JSX text.
+fn fixed_element_text(jsx_elem: &JSXElement, attr_span: Span, ctx: &LintContext) -> Option<String> {
+    if jsx_elem.closing_element.is_some() {
+        return None;
+    }
+    let opening = &jsx_elem.opening_element;
+    let value = opening.attributes.iter().find_map(|attr| match attr {
+        JSXAttributeItem::Attribute(attr) if attr.span == attr_span => attr.value.as_ref(),
+        _ => None,
+    })?;
+    let JSXAttributeValue::StringLiteral(lit) = value else {
+        return None;
+    };
+    // Source text between the quotes. JSX attribute strings and JSX text are decoded the same way.
+    let text = ctx.source_range(lit.span.shrink(1));
+    if text.trim().is_empty() || text.contains(['{', '}', '<', '>', '\n', '\r']) {
+        return None;
+    }
+
+    let name = ctx.source_range(opening.name.span());
+    let mut fixed = format!("<{name}");
+    if let Some(type_arguments) = &opening.type_arguments {
+        fixed.push_str(ctx.source_range(type_arguments.span));
+    }
+    for attr in &opening.attributes {
+        if attr.span() != attr_span {
+            fixed.push(' ');
+            fixed.push_str(ctx.source_range(attr.span()));
         }
     }
+    fixed.push('>');
+    fixed.push_str(text);
+    fixed.push_str("</");
+    fixed.push_str(name);
+    fixed.push('>');
+    Some(fixed)
+}
+
+/// Whether `expr` is read from a binding created by a rest element, e.g. `rest.children` in
+/// `const { title, ...rest } = props;`. Such `children` are usually being forwarded.
+fn is_rest_derived(expr: &Expression, ctx: &LintContext) -> bool {
+    let mut object = expr.without_parentheses();
+    while let Some(member) = object.as_member_expression() {
+        object = member.object().without_parentheses();
+    }
+    let Expression::Identifier(ident) = object else {
+        return false;
+    };
+    let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id() else {
+        return false;
+    };
+    match ctx.nodes().kind(ctx.scoping().symbol_declaration(symbol_id)) {
+        AstKind::VariableDeclarator(decl) => is_bound_by_rest(&decl.id, symbol_id),
+        AstKind::FormalParameter(param) => is_bound_by_rest(&param.pattern, symbol_id),
+        AstKind::BindingRestElement(_) => true,
+        _ => false,
+    }
+}
+
+fn is_bound_by_rest(pattern: &BindingPattern, symbol_id: SymbolId) -> bool {
+    let binds = |pattern: &BindingPattern| {
+        pattern.get_binding_identifiers().iter().any(|ident| ident.symbol_id() == symbol_id)
+    };
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(_) => false,
+        BindingPatternKind::ObjectPattern(obj) => {
+            obj.rest.as_ref().is_some_and(|rest| binds(&rest.argument))
+                || obj.properties.iter().any(|prop| is_bound_by_rest(&prop.value, symbol_id))
+        }
+        BindingPatternKind::ArrayPattern(arr) => {
+            arr.rest.as_ref().is_some_and(|rest| binds(&rest.argument))
+                || arr.elements.iter().flatten().any(|elem| is_bound_by_rest(elem, symbol_id))
+        }
+        BindingPatternKind::AssignmentPattern(assign) => is_bound_by_rest(&assign.left, symbol_id),
+    }
 }
 
 #[test]
@@ -134,6 +281,10 @@ fn test() {
         (r#"<MyComponent className="class-name" {...props} />;"#, None),
         (r#"foo(MyComponent, {...props, children: "Children"})"#, None),
         (r#"React.createElement(MyComponent, {className: "class-name", ...props});"#, None),
+        ("function Foo({ title, ...rest }) { return <Bar children={rest.children} />; }", None),
+        ("function Foo(...args) { return <Bar children={args[0].children} />; }", None),
+        ("const { title, ...rest } = props; <Bar children={rest.children} />;", None),
+        ("const [first, ...others] = items; React.createElement(Bar, { children: others });", None),
     ];
 
     #[rustfmt::skip]
@@ -153,7 +304,32 @@ fn test() {
         (r#"React.createElement(MyComponent, {children: "Children", className: "class-name"});"#, None),
         (r#"<MyComponent {...props} children="Children" />;"#, None),
         (r#"React.createElement(MyComponent, {...props, children: "Children"})"#, None),
+        (r"<div children={children}><span /></div>;", None),
+        ("function Foo({ title, children }) { return <Bar children={children} />; }", None),
+        ("const { title, rest } = props; <Bar children={rest.children} />;", None),
+    ];
+
+    let fix = vec![
+        (r#"<div children="Children" />;"#, "<div>Children</div>;"),
+        (
+            r#"<MyComponent className="class-name" children="Children" />;"#,
+            r#"<MyComponent className="class-name">Children</MyComponent>;"#,
+        ),
+        (
+            r#"<MyComponent {...props} children="Children" id="x" />;"#,
+            r#"<MyComponent {...props} id="x">Children</MyComponent>;"#,
+        ),
+        (r#"<Foo.Bar children="Children" />;"#, "<Foo.Bar>Children</Foo.Bar>;"),
+        (r#"<div children="{Children}" />;"#, r#"<div children="{Children}" />;"#),
+        (r#"<div children="" />;"#, r#"<div children="" />;"#),
+        (r"<div children={<div />} />;", r"<div children={<div />} />;"),
+        (
+            r#"<div children="Children">Children</div>;"#,
+            r#"<div children="Children">Children</div>;"#,
+        ),
     ];
 
-    Tester::new(NoChildrenProp::NAME, NoChildrenProp::PLUGIN, pass, fail).test_and_snapshot();
+    Tester::new(NoChildrenProp::NAME, NoChildrenProp::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
@@ -1,17 +1,23 @@
 use oxc_ast::{
     AstKind,
-    ast::{Argument, Expression, JSXAttributeItem, JSXAttributeName, JSXChild, ObjectPropertyKind},
+    ast::{
+        Argument, Expression, JSXAttributeItem, JSXAttributeName, JSXChild, JSXExpression,
+        ObjectPropertyKind,
+    },
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::{AstNode, context::LintContext, rule::Rule};
 
-fn no_danger_with_children_diagnostic(span: Span) -> OxcDiagnostic {
+fn no_danger_with_children_diagnostic(danger_span: Span, children_span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Only set one of `children` or `props.dangerouslySetInnerHTML`")
-			.with_help("`dangerouslySetInnerHTML` is not compatible with also passing children and React will throw a warning at runtime.")
-			.with_label(span)
+        .with_help("`dangerouslySetInnerHTML` is not compatible with also passing children and React will throw a warning at runtime.")
+        .with_labels([
+            danger_span.label("`dangerouslySetInnerHTML` is set here"),
+            children_span.label("Children are passed here"),
+        ])
 }
 
 #[derive(Debug, Default, Clone)]
@@ -53,15 +59,14 @@ impl Rule for NoDangerWithChildren {
         match node.kind() {
             AstKind::JSXElement(jsx) => {
                 // Either children are passed in as a prop like `children={}` or they are nested between the tags.
-                let has_children = has_jsx_prop(ctx, node, "children")
-                    || (!jsx.children.is_empty() && !is_line_break(&jsx.children[0]));
-                if !has_children {
+                let Some(children_span) =
+                    children_span(&jsx.children).or_else(|| find_jsx_prop(ctx, node, "children"))
+                else {
                     return;
-                }
+                };
 
-                let has_danger_prop = has_jsx_prop(ctx, node, "dangerouslySetInnerHTML");
-                if has_danger_prop {
-                    ctx.diagnostic(no_danger_with_children_diagnostic(jsx.span));
+                if let Some(danger_span) = find_jsx_prop(ctx, node, "dangerouslySetInnerHTML") {
+                    ctx.diagnostic(no_danger_with_children_diagnostic(danger_span, children_span));
                 }
             }
             AstKind::CallExpression(call_expr) => {
@@ -83,41 +88,29 @@ impl Rule for NoDangerWithChildren {
                     return;
                 };
 
-                // If there are three arguments, then it is a JSX element with children.
-                // If it's just two arguments, it only has children if the props object has a children property.
-                let has_children = if call_expr.arguments.len() == 2 {
-                    match props {
-                        Expression::ObjectExpression(obj_expr) => {
-                            is_object_with_prop_name(&obj_expr.properties, "children")
-                        }
-                        Expression::Identifier(ident) => {
-                            does_object_var_have_prop_name(ctx, node, &ident.name, "children")
-                        }
-                        _ => false,
+                let find_prop = |prop_name| match props {
+                    Expression::ObjectExpression(obj_expr) => {
+                        find_object_prop(&obj_expr.properties, prop_name)
+                    }
+                    Expression::Identifier(ident) => {
+                        find_object_var_prop(ctx, node, &ident.name, prop_name)
                     }
-                } else {
-                    true
+                    _ => None,
                 };
 
-                if !has_children {
+                // Arguments after the props are children.
+                // If it's just two arguments, it only has children if the props object has a children property.
+                let children_span = match &call_expr.arguments[2..] {
+                    [] => find_prop("children"),
+                    [first, .., last] => Some(Span::new(first.span().start, last.span().end)),
+                    [only] => Some(only.span()),
+                };
+                let Some(children_span) = children_span else {
                     return;
-                }
-
-                let has_danger_prop = match props {
-                    Expression::ObjectExpression(obj_expr) => {
-                        is_object_with_prop_name(&obj_expr.properties, "dangerouslySetInnerHTML")
-                    }
-                    Expression::Identifier(ident) => does_object_var_have_prop_name(
-                        ctx,
-                        node,
-                        &ident.name,
-                        "dangerouslySetInnerHTML",
-                    ),
-                    _ => false,
                 };
 
-                if has_danger_prop && has_children {
-                    ctx.diagnostic(no_danger_with_children_diagnostic(call_expr.span));
+                if let Some(danger_span) = find_prop("dangerouslySetInnerHTML") {
+                    ctx.diagnostic(no_danger_with_children_diagnostic(danger_span, children_span));
                 }
             }
             _ => (),
@@ -160,6 +153,8 @@ fn test() {
         r#"React.createElement("Hello", {}, "Children");"#,
         "<Hello {...undefined}>Children</Hello>",
         r#"React.createElement("Hello", undefined, "Children")"#,
+        r#"<Hello dangerouslySetInnerHTML={{ __html: "HTML" }}> </Hello>"#,
+        r#"<div dangerouslySetInnerHTML={{ __html: "HTML" }}>{/* comment */}</div>"#,
         "
         const props = {...props, scratch: {mode: 'edit'}};
         const component = shallow(<TaskEditableTitle {...props} />);
@@ -187,7 +182,6 @@ fn test() {
         </Hello>
         "#,
         r#"<Hello dangerouslySetInnerHTML={{ __html: "HTML" }} children="Children" />"#,
-        r#"<Hello dangerouslySetInnerHTML={{ __html: "HTML" }}> </Hello>"#,
         r#"
         React.createElement(
             "div",
@@ -234,88 +228,97 @@ fn test() {
         const props = { ...otherProps, dangerouslySetInnerHTML: { __html: "HTML" } };
         React.createElement("div", props);
         "#,
+        r#"<div dangerouslySetInnerHTML={{ __html: "HTML" }}> {text} </div>"#,
+        r#"React.createElement("div", { dangerouslySetInnerHTML: { __html: "HTML" } }, a, b);"#,
     ];
 
     Tester::new(NoDangerWithChildren::NAME, NoDangerWithChildren::PLUGIN, pass, fail)
         .test_and_snapshot();
 }
 
-fn is_whitespace(s: &str) -> bool {
-    s.chars().all(char::is_whitespace)
-}
-
-fn is_line_break(child: &JSXChild) -> bool {
-    let JSXChild::Text(text) = child else {
-        return false;
-    };
-    let is_multi_line = text.value.contains('\n');
-    is_multi_line && is_whitespace(text.value.as_str())
+/// Span of JSX children which React renders, from the first to the last.
+/// Text containing only whitespace, such as indentation between the tags, is not counted.
+#[expect(clippy::cast_possible_truncation)]
+fn children_span(children: &[JSXChild]) -> Option<Span> {
+    let mut spans = children.iter().filter_map(|child| match child {
+        JSXChild::Text(text) => {
+            let value = text.value.as_str();
+            let trimmed = value.trim();
+            if trimmed.is_empty() {
+                return None;
+            }
+            // Exclude surrounding whitespace, e.g. the indentation before `</div>`.
+            let start = text.span.start + (value.len() - value.trim_start().len()) as u32;
+            Some(Span::sized(start, trimmed.len() as u32))
+        }
+        JSXChild::ExpressionContainer(container)
+            if matches!(container.expression, JSXExpression::EmptyExpression(_)) =>
+        {
+            None
+        }
+        _ => Some(child.span()),
+    });
+    let first = spans.next()?;
+    let last = spans.next_back().unwrap_or(first);
+    Some(Span::new(first.start, last.end))
 }
 
-/// Given a JSX element, find the JSXAttributeItem with the given name.
-/// If there are spread props, it will search within those as well.
-fn has_jsx_prop(ctx: &LintContext, node: &AstNode, prop_name: &'static str) -> bool {
+/// Given a JSX element, find the span of the JSXAttributeItem with the given name.
+/// If there are spread props, it will search within those as well, and return the span of
+/// the property in the spread object.
+fn find_jsx_prop(ctx: &LintContext, node: &AstNode, prop_name: &'static str) -> Option<Span> {
     let AstKind::JSXElement(jsx) = node.kind() else {
-        return false;
+        return None;
     };
 
-    jsx.opening_element.attributes.iter().any(|attr| match attr {
+    jsx.opening_element.attributes.iter().find_map(|attr| match attr {
         JSXAttributeItem::Attribute(attr) => {
             let JSXAttributeName::Identifier(ident) = &attr.name else {
-                return false;
+                return None;
             };
-            ident.name == prop_name
+            (ident.name == prop_name).then_some(attr.span)
         }
         JSXAttributeItem::SpreadAttribute(attr) => {
-            let Some(ident) = attr.argument.get_identifier_reference() else {
-                return false;
-            };
-            does_object_var_have_prop_name(ctx, node, ident.name.as_str(), prop_name)
+            let ident = attr.argument.get_identifier_reference()?;
+            find_object_var_prop(ctx, node, ident.name.as_str(), prop_name)
         }
     })
 }
 
 /// Given a variable name, finds the variable and checks if it is an object that has a property
 /// by the given name, either by directly being set or by being spread into the object.
-fn does_object_var_have_prop_name(
+/// Returns the span of the property.
+fn find_object_var_prop(
     ctx: &LintContext,
     node: &AstNode,
     name: &str,
     prop_name: &str,
-) -> bool {
-    let Some(symbol) = &find_var_in_scope(ctx, node, name) else {
-        return false;
-    };
+) -> Option<Span> {
+    let symbol = find_var_in_scope(ctx, node, name)?;
 
     let AstKind::VariableDeclarator(var_decl) = symbol.kind() else {
-        return false;
+        return None;
     };
 
-    let Some(init) = &var_decl.init else {
-        return false;
+    let Some(Expression::ObjectExpression(obj_expr)) = &var_decl.init else {
+        return None;
     };
 
-    let Expression::ObjectExpression(obj_expr) = init else {
-        return false;
-    };
-
-    obj_expr.properties.iter().any(|prop| match prop {
+    obj_expr.properties.iter().find_map(|prop| match prop {
         ObjectPropertyKind::ObjectProperty(obj_prop) => {
-            obj_prop.key.static_name().is_some_and(|key| key == prop_name)
+            obj_prop.key.static_name().is_some_and(|key| key == prop_name).then_some(obj_prop.span)
         }
         ObjectPropertyKind::SpreadProperty(spread_prop) => {
-            let Some(ident) = spread_prop.argument.get_identifier_reference() else {
-                return false;
-            };
+            let ident = spread_prop.argument.get_identifier_reference()?;
             // If the next symbol is the same as the current symbol, then there is a cycle,
             // for example: `const props = {...props}`, so we will stop searching.
             if let Some(next_symbol) = find_var_in_scope(ctx, node, ident.name.as_str()) {
                 if next_symbol.id() == symbol.id() {
-                    return false;
+                    return None;
                 }
             }
 
-            does_object_var_have_prop_name(ctx, symbol, ident.name.as_str(), prop_name)
+            find_object_var_prop(ctx, symbol, ident.name.as_str(), prop_name)
         }
     })
 }
@@ -331,15 +334,15 @@ fn find_var_in_scope<'c>(
         .map(|symbol_id| ctx.semantic().symbol_declaration(symbol_id))
 }
 
-/// Returns whether a given object has a property with the given name.
-fn is_object_with_prop_name(
+/// Returns the span of the property with the given name in an object.
+fn find_object_prop(
     obj_props: &oxc_allocator::Vec<'_, ObjectPropertyKind<'_>>,
     prop_name: &str,
-) -> bool {
-    obj_props.iter().any(|prop| {
+) -> Option<Span> {
+    obj_props.iter().find_map(|prop| {
         let ObjectPropertyKind::ObjectProperty(obj_prop) = prop else {
-            return false;
+            return None;
         };
-        obj_prop.key.static_name().is_some_and(|key| key == prop_name)
+        obj_prop.key.static_name().is_some_and(|key| key == prop_name).then_some(obj_prop.span)
     })
 }
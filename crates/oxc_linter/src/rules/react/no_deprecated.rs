@@ -0,0 +1,306 @@
+use oxc_ast::{
+    AstKind, MemberExpressionKind,
+    ast::{Expression, MethodDefinition, MethodDefinitionKind},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use phf::{Map, phf_map};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{is_es6_component, is_import},
+};
+
+fn no_deprecated_diagnostic(
+    name: &str,
+    (major, minor): Version,
+    help: &'static str,
+    span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{name}` is deprecated since React {major}.{minor}"))
+        .with_help(help)
+        .with_label(span)
+}
+
+/// React version, as `(major, minor)`.
+type Version = (u32, u32);
+
+/// Check against all known deprecations if no version is configured.
+const LATEST_VERSION: Version = (u32::MAX, u32::MAX);
+
+#[derive(Debug, Clone)]
+pub struct NoDeprecated {
+    version: Version,
+}
+
+impl Default for NoDeprecated {
+    fn default() -> Self {
+        Self { version: LATEST_VERSION }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows usage of deprecated React APIs.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Deprecated APIs are removed in later major versions of React, so code which uses them
+    /// will break when React is upgraded.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// React.render(<MyComponent />, root);
+    /// React.findDOMNode(this.refs.foo);
+    /// ReactDOM.render(<MyComponent />, root);
+    ///
+    /// class Foo extends React.Component {
+    ///   componentWillMount() {}
+    ///   componentWillReceiveProps() {}
+    ///   componentWillUpdate() {}
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// createRoot(root).render(<MyComponent />);
+    ///
+    /// class Foo extends React.Component {
+    ///   UNSAFE_componentWillMount() {}
+    ///   UNSAFE_componentWillReceiveProps() {}
+    ///   UNSAFE_componentWillUpdate() {}
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// { "react/no-deprecated": ["warn", { "version": "16.9" }] }
+    /// ```
+    ///
+    /// `version` is the version of React used by the codebase. Only APIs which are deprecated
+    /// in that version are reported. Defaults to the latest version, reporting all deprecated APIs.
+    NoDeprecated,
+    react,
+    correctness
+);
+
+/// Deprecated APIs, keyed by `<module>.<property>`, with the version they were deprecated in
+/// and a migration hint.
+const DEPRECATED_APIS: Map<&'static str, (Version, &'static str)> = phf_map! {
+    "React.render" => ((0, 14), "Use `ReactDOM.render` instead."),
+    "React.unmountComponentAtNode" => ((0, 14), "Use `ReactDOM.unmountComponentAtNode` instead."),
+    "React.findDOMNode" => ((0, 14), "Use `ReactDOM.findDOMNode` instead."),
+    "React.createFactory" => ((16, 13), "Use JSX or `React.createElement` instead."),
+    "ReactDOM.render" => ((18, 0), "Use `createRoot` from `react-dom/client` instead."),
+    "ReactDOM.hydrate" => ((18, 0), "Use `hydrateRoot` from `react-dom/client` instead."),
+};
+
+/// Lifecycle methods deprecated in React 16.9, with a migration hint.
+const DEPRECATED_LIFECYCLE_METHODS: Map<&'static str, &'static str> = phf_map! {
+    "componentWillMount" => "Rename to `UNSAFE_componentWillMount`, or move the code to `componentDidMount`.",
+    "componentWillReceiveProps" => "Rename to `UNSAFE_componentWillReceiveProps`, or use `getDerivedStateFromProps` instead.",
+    "componentWillUpdate" => "Rename to `UNSAFE_componentWillUpdate`, or move the code to `componentDidUpdate`.",
+};
+
+const LIFECYCLE_METHODS_VERSION: Version = (16, 9);
+
+impl Rule for NoDeprecated {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let version = value
+            .get(0)
+            .and_then(|v| v.get("version"))
+            .and_then(serde_json::Value::as_str)
+            .and_then(parse_version)
+            .unwrap_or(LATEST_VERSION);
+
+        Self { version }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if let AstKind::MethodDefinition(method) = node.kind() {
+            self.check_lifecycle_method(node, method, ctx);
+        } else if let Some(member_expr) = node.kind().as_member_expression_kind() {
+            self.check_member_expression(&member_expr, ctx);
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_jsx()
+    }
+}
+
+impl NoDeprecated {
+    fn check_lifecycle_method<'a>(
+        &self,
+        node: &AstNode<'a>,
+        method: &MethodDefinition<'a>,
+        ctx: &LintContext<'a>,
+    ) {
+        if self.version < LIFECYCLE_METHODS_VERSION
+            || method.kind != MethodDefinitionKind::Method
+            || method.r#static
+        {
+            return;
+        }
+        let Some(name) = method.key.static_name() else {
+            return;
+        };
+        let Some(help) = DEPRECATED_LIFECYCLE_METHODS.get(name.as_ref()) else {
+            return;
+        };
+        let class_node = ctx.nodes().parent_node(ctx.nodes().parent_id(node.id()));
+        if !is_es6_component(class_node) {
+            return;
+        }
+        ctx.diagnostic(no_deprecated_diagnostic(
+            &name,
+            LIFECYCLE_METHODS_VERSION,
+            help,
+            method.key.span(),
+        ));
+    }
+
+    fn check_member_expression<'a>(
+        &self,
+        member_expr: &MemberExpressionKind<'a>,
+        ctx: &LintContext<'a>,
+    ) {
+        let Expression::Identifier(ident) = member_expr.object() else {
+            return;
+        };
+        let module = if is_import(ctx, ident.name.as_str(), "React", "react") {
+            "React"
+        } else if is_import(ctx, ident.name.as_str(), "ReactDOM", "react-dom") {
+            "ReactDOM"
+        } else {
+            return;
+        };
+        let Some(property) = member_expr.static_property_name() else {
+            return;
+        };
+        let name = format!("{module}.{property}");
+        let Some(&(deprecated_since, help)) = DEPRECATED_APIS.get(name.as_str()) else {
+            return;
+        };
+        if self.version < deprecated_since {
+            return;
+        }
+        ctx.diagnostic(no_deprecated_diagnostic(&name, deprecated_since, help, member_expr.span()));
+    }
+}
+
+/// Parse a version string such as `"16.9"` or `"18.0.0"` into `(major, minor)`.
+fn parse_version(version: &str) -> Option<Version> {
+    let mut parts = version.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().map_or(Some(0), |minor| minor.parse().ok())?;
+    Some((major, minor))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var element = React.createElement('p', {}, null);", None),
+        ("ReactDOM.findDOMNode(instance);", None),
+        ("ReactDOM.unmountComponentAtNode(container);", None),
+        ("createRoot(container).render(<App />);", None),
+        ("hydrateRoot(container, <App />);", None),
+        ("Foo.render(<App />, container);", None),
+        ("React.Children.map(children, fn);", None),
+        (
+            "import { render } from 'react-dom'; render(<App />, container);",
+            Some(serde_json::json!([{ "version": "17.0" }])),
+        ),
+        ("import React from 'foo'; React.render(<App />, container);", None),
+        ("import ReactDOM from 'foo'; ReactDOM.render(<App />, container);", None),
+        ("ReactDOM.render(<App />, container);", Some(serde_json::json!([{ "version": "17.0" }]))),
+        (
+            "ReactDOM.hydrate(<App />, container);",
+            Some(serde_json::json!([{ "version": "17.0.2" }])),
+        ),
+        ("React.createFactory('div');", Some(serde_json::json!([{ "version": "16.12" }]))),
+        ("React.render(<App />, container);", Some(serde_json::json!([{ "version": "0.13" }]))),
+        (
+            "
+            class Foo extends React.Component {
+              UNSAFE_componentWillMount() {}
+              UNSAFE_componentWillReceiveProps() {}
+              UNSAFE_componentWillUpdate() {}
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            class Foo extends React.Component {
+              componentWillMount() {}
+              componentWillReceiveProps() {}
+              componentWillUpdate() {}
+            }
+            ",
+            Some(serde_json::json!([{ "version": "16.8" }])),
+        ),
+        (
+            "
+            class Foo extends Bar {
+              componentWillMount() {}
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            class Foo extends React.Component {
+              static componentWillMount() {}
+            }
+            ",
+            None,
+        ),
+    ];
+
+    let fail = vec![
+        ("React.render(<App />, container);", None),
+        ("React.unmountComponentAtNode(container);", None),
+        ("React.findDOMNode(this.refs.foo);", None),
+        ("React.createFactory('div');", None),
+        ("ReactDOM.render(<App />, container);", None),
+        ("ReactDOM.hydrate(<App />, container);", None),
+        ("callback(React.render);", None),
+        ("React['render'](<App />, container);", None),
+        ("import React from 'react'; React.render(<App />, container);", None),
+        ("import ReactDOM from 'react-dom'; ReactDOM.render(<App />, container);", None),
+        ("import Foo from 'react-dom'; Foo.hydrate(<App />, container);", None),
+        ("ReactDOM.render(<App />, container);", Some(serde_json::json!([{ "version": "18.0" }]))),
+        ("React.createFactory('div');", Some(serde_json::json!([{ "version": "16.13.1" }]))),
+        ("React.findDOMNode(this);", Some(serde_json::json!([{ "version": "15" }]))),
+        (
+            "
+            class Foo extends React.Component {
+              componentWillMount() {}
+              componentWillReceiveProps() {}
+              componentWillUpdate() {}
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            class Foo extends PureComponent {
+              componentWillMount() {}
+            }
+            ",
+            Some(serde_json::json!([{ "version": "16.9" }])),
+        ),
+    ];
+
+    Tester::new(NoDeprecated::NAME, NoDeprecated::PLUGIN, pass, fail).test_and_snapshot();
+}
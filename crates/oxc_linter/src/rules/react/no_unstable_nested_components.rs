@@ -0,0 +1,456 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        ArrowFunctionExpression, BindingIdentifier, BindingPatternKind, Class, Expression,
+        Function, FunctionBody, JSXAttributeName, ReturnStatement,
+    },
+};
+use oxc_ast_visit::Visit;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ScopeFlags;
+use oxc_span::{GetSpan, Span};
+use serde_json::Value;
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::is_react_component_name,
+};
+
+fn nested_component_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Do not define component `{name}` inside another component."))
+        .with_help("React sees a new component type on every render, so the nested component is remounted and loses its state. Move the definition out of the parent component and pass data as props.")
+        .with_label(span)
+}
+
+fn component_as_prop_diagnostic(span: Span, prop: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Do not define a component inside another component as the `{prop}` prop."))
+        .with_help("React sees a new component type on every render, so the nested component is remounted and loses its state. Move the definition out of the parent component, or allow components as props with the `allowAsProps` option.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnstableNestedComponents {
+    allow_as_props: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow defining components inside the render of another component.
+    ///
+    /// A component is a function whose name starts with an uppercase letter, or which returns JSX.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A component defined inside another component is a new function, and therefore a new
+    /// component type, on every render. React unmounts the previous subtree and mounts a new one,
+    /// which destroys DOM nodes and state and hurts performance.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// function Component() {
+    ///   function UnstableNestedComponent() {
+    ///     return <div />;
+    ///   }
+    ///   return <UnstableNestedComponent />;
+    /// }
+    ///
+    /// function Component() {
+    ///   return <SomeComponent footer={() => <div />} />;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// function OutsideDefinedComponent() {
+    ///   return <div />;
+    /// }
+    /// function Component() {
+    ///   return <OutsideDefinedComponent />;
+    /// }
+    ///
+    /// // Render props are called as functions, not rendered as components.
+    /// function Component() {
+    ///   return <List renderItem={(item) => <div>{item}</div>} />;
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### allowAsProps
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// When `true`, components defined inline as JSX prop values are allowed, e.g.
+    /// `<Table components={{ Row: () => <tr /> }} />`. Props whose name starts with `render`
+    /// and `children` are always allowed, since they are render props.
+    NoUnstableNestedComponents,
+    react,
+    suspicious
+);
+
+impl Rule for NoUnstableNestedComponents {
+    fn from_configuration(value: Value) -> Self {
+        let allow_as_props = value
+            .get(0)
+            .and_then(|config| config.get("allowAsProps"))
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+
+        Self { allow_as_props }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if !is_component(node, ctx) {
+            return;
+        }
+
+        if let Some(prop) = prop_name(node, ctx) {
+            if self.allow_as_props || prop.starts_with("render") || prop == "children" {
+                return;
+            }
+            if get_enclosing_component(node, ctx).is_some() {
+                ctx.diagnostic(component_as_prop_diagnostic(node.kind().span(), &prop));
+            }
+            return;
+        }
+
+        let Some(ident) = component_binding(node, ctx) else {
+            return;
+        };
+        if !is_react_component_name(&ident.name) || !is_used_as_jsx(ident, ctx) {
+            return;
+        }
+        if get_enclosing_component(node, ctx).is_some() {
+            ctx.diagnostic(nested_component_diagnostic(ident.span, &ident.name));
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_jsx()
+    }
+}
+
+/// Whether `node` is a function which looks like a component: its name starts with an
+/// uppercase letter, or it returns JSX.
+fn is_component<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    match node.kind() {
+        AstKind::Function(func) => {
+            component_binding(node, ctx).is_some_and(|ident| is_react_component_name(&ident.name))
+                || func.body.as_ref().is_some_and(|body| returns_jsx(body))
+        }
+        AstKind::ArrowFunctionExpression(arrow) => {
+            component_binding(node, ctx).is_some_and(|ident| is_react_component_name(&ident.name))
+                || arrow_returns_jsx(arrow)
+        }
+        _ => false,
+    }
+}
+
+/// Find the component which `node` is defined in, by walking up its ancestors.
+fn get_enclosing_component<'a, 'b>(
+    node: &'b AstNode<'a>,
+    ctx: &'b LintContext<'a>,
+) -> Option<&'b AstNode<'a>> {
+    ctx.nodes().ancestors(node.id()).find(|parent| is_component(parent, ctx))
+}
+
+/// The identifier a function is bound to, either its own name or the variable it is
+/// assigned to, e.g. `const Foo = () => <div />`.
+fn component_binding<'a, 'b>(
+    node: &'b AstNode<'a>,
+    ctx: &'b LintContext<'a>,
+) -> Option<&'b BindingIdentifier<'a>> {
+    if let AstKind::VariableDeclarator(decl) = ctx.nodes().parent_kind(node.id()) {
+        if let BindingPatternKind::BindingIdentifier(ident) = &decl.id.kind {
+            return Some(ident);
+        }
+    }
+    match node.kind() {
+        AstKind::Function(func) => func.id.as_ref(),
+        _ => None,
+    }
+}
+
+/// The name of the JSX prop which `node` is passed as, either directly
+/// (`<Foo footer={() => <div />} />`) or as a property of an object
+/// (`<Foo components={{ Row: () => <tr /> }} />`).
+fn prop_name<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> Option<String> {
+    let mut ancestors = ctx.nodes().ancestors(node.id());
+    let mut parent = ancestors.next()?;
+    if let AstKind::ObjectProperty(prop) = parent.kind() {
+        if !prop.key.static_name().is_some_and(|name| is_react_component_name(&name)) {
+            return None;
+        }
+        if !matches!(ancestors.next()?.kind(), AstKind::ObjectExpression(_)) {
+            return None;
+        }
+        parent = ancestors.next()?;
+    }
+    let AstKind::JSXExpressionContainer(_) = parent.kind() else {
+        return None;
+    };
+    let AstKind::JSXAttribute(attr) = ancestors.next()?.kind() else {
+        return None;
+    };
+    match &attr.name {
+        JSXAttributeName::Identifier(ident) => Some(ident.name.to_string()),
+        JSXAttributeName::NamespacedName(name) => Some(name.to_string()),
+    }
+}
+
+/// Whether any reference to `ident` is the name of a JSX element, e.g. `<Foo />`.
+fn is_used_as_jsx<'a>(ident: &BindingIdentifier<'a>, ctx: &LintContext<'a>) -> bool {
+    ctx.symbol_references(ident.symbol_id()).any(|reference| {
+        matches!(ctx.nodes().parent_kind(reference.node_id()), AstKind::JSXOpeningElement(_))
+    })
+}
+
+fn arrow_returns_jsx(arrow: &ArrowFunctionExpression) -> bool {
+    match arrow.get_expression() {
+        Some(expr) => is_jsx(expr),
+        None => returns_jsx(&arrow.body),
+    }
+}
+
+fn returns_jsx(body: &FunctionBody) -> bool {
+    let mut finder = JsxReturnFinder { found: false };
+    finder.visit_function_body(body);
+    finder.found
+}
+
+fn is_jsx(expr: &Expression) -> bool {
+    match expr.get_inner_expression() {
+        Expression::JSXElement(_) | Expression::JSXFragment(_) => true,
+        Expression::ConditionalExpression(cond) => {
+            is_jsx(&cond.consequent) || is_jsx(&cond.alternate)
+        }
+        Expression::LogicalExpression(logical) => is_jsx(&logical.left) || is_jsx(&logical.right),
+        _ => false,
+    }
+}
+
+/// Finds `return` statements returning JSX, not including those in nested functions.
+struct JsxReturnFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for JsxReturnFinder {
+    fn visit_return_statement(&mut self, stmt: &ReturnStatement<'a>) {
+        if stmt.argument.as_ref().is_some_and(is_jsx) {
+            self.found = true;
+        }
+    }
+
+    fn visit_arrow_function_expression(&mut self, _expr: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _class: &Class<'a>) {}
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            r"
+            function ParentComponent() {
+              return (
+                <div>
+                  <OutsideDefinedFunctionComponent />
+                </div>
+              );
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function OutsideDefinedComponent() {
+              return <div />;
+            }
+            function ParentComponent() {
+              return <OutsideDefinedComponent />;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              const renderItem = (item) => <li>{item}</li>;
+              return <ul>{items.map(renderItem)}</ul>;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              function Helper() {
+                return <div />;
+              }
+              return <div>{Helper()}</div>;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              return <List renderItem={(item) => <li>{item}</li>} />;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              return <Route>{(match) => <Page match={match} />}</Route>;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              return <Route children={(match) => <Page match={match} />} />;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              return <button onClick={() => setOpen(true)} />;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function createComponent() {
+              function Component() {
+                return <div />;
+              }
+              return Component;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              return <SomeComponent footer={() => <div />} />;
+            }
+            ",
+            Some(serde_json::json!([{ "allowAsProps": true }])),
+        ),
+        (
+            r"
+            function ParentComponent() {
+              return <Table components={{ Row: () => <tr /> }} />;
+            }
+            ",
+            Some(serde_json::json!([{ "allowAsProps": true }])),
+        ),
+        (r"const Footer = () => <div />; const el = <SomeComponent footer={Footer} />;", None),
+    ];
+
+    let fail = vec![
+        (
+            r"
+            export default function () {
+              const UnstableNestedComponent = () => <div />;
+              return <UnstableNestedComponent />;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              function UnstableNestedComponent() {
+                return <div />;
+              }
+              return <UnstableNestedComponent />;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            const ParentComponent = () => {
+              const UnstableNestedComponent = () => <div />;
+              return (
+                <div>
+                  <UnstableNestedComponent />
+                </div>
+              );
+            };
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              const UnstableNestedComponent = function () {
+                return <div />;
+              };
+              return <UnstableNestedComponent />;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            class ParentComponent extends React.Component {
+              render() {
+                const UnstableNestedComponent = () => <div />;
+                return <UnstableNestedComponent />;
+              }
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              return <SomeComponent footer={() => <div />} />;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              return <Table components={{ Row: () => <tr /> }} />;
+            }
+            ",
+            None,
+        ),
+        (
+            r"
+            function ParentComponent() {
+              const UnstableNestedComponent = () => <div />;
+              return (
+                <SomeComponent footer={() => <div />}>
+                  <UnstableNestedComponent />
+                </SomeComponent>
+              );
+            }
+            ",
+            Some(serde_json::json!([{ "allowAsProps": true }])),
+        ),
+    ];
+
+    Tester::new(NoUnstableNestedComponents::NAME, NoUnstableNestedComponents::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
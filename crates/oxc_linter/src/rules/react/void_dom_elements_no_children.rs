@@ -1,7 +1,7 @@
 use oxc_ast::{
     AstKind,
     ast::{
-        Argument, JSXAttributeItem, JSXAttributeName, JSXElementName, ObjectPropertyKind,
+        Argument, JSXAttributeItem, JSXAttributeName, JSXChild, JSXElementName, ObjectPropertyKind,
         PropertyKey,
     },
 };
@@ -17,8 +17,7 @@ use crate::{
 };
 
 fn void_dom_elements_no_children_diagnostic(tag: &str, span: Span) -> OxcDiagnostic {
-    // TODO: use imperative phrasing
-    OxcDiagnostic::warn(format!("Void DOM element <{tag:?} /> cannot receive children."))
+    OxcDiagnostic::warn(format!("Do not pass children to the void DOM element `<{tag}>`."))
         .with_help("Remove this element's children or use a non-void element.")
         .with_label(span)
 }
@@ -41,6 +40,7 @@ declare_oxc_lint!(
     /// Examples of **incorrect** code for this rule:
     /// ```jsx
     /// <br>Children</br>
+    /// <img>{' '}</img>
     /// <br children='Children' />
     /// <br dangerouslySetInnerHTML={{ __html: 'HTML' }} />
     /// React.createElement('br', undefined, 'Children')
@@ -51,6 +51,7 @@ declare_oxc_lint!(
     /// Examples of **correct** code for this rule:
     /// ```jsx
     /// <div>Children</div>
+    /// <br> </br>
     /// <div children='Children' />
     /// <div dangerouslySetInnerHTML={{ __html: 'HTML' }} />
     /// React.createElement('div', undefined, 'Children')
@@ -95,7 +96,12 @@ impl Rule for VoidDomElementsNoChildren {
                         JSXAttributeItem::SpreadAttribute(_) => false,
                     });
 
-                if !jsx_el.children.is_empty() || has_children_attribute_or_danger {
+                // Whitespace-only text is not rendered, but an expression such as `{' '}` is.
+                let has_children = jsx_el.children.iter().any(
+                    |child| !matches!(child, JSXChild::Text(text) if text.value.trim().is_empty()),
+                );
+
+                if has_children || has_children_attribute_or_danger {
                     ctx.diagnostic(void_dom_elements_no_children_diagnostic(
                         &identifier.name,
                         identifier.span,
@@ -161,6 +167,17 @@ fn test() {
 
     let pass = vec![
         (r"<div>Foo</div>;", None),
+        (r"<br></br>;", None),
+        (r"<br> </br>;", None),
+        (
+            r"
+                <img>
+                </img>;
+            ",
+            None,
+        ),
+        (r"<Img>Foo</Img>;", None),
+        (r"<foo.img>Foo</foo.img>;", None),
         (r"<div children='Foo' />;", None),
         (r"<div dangerouslySetInnerHTML={{ __html: 'Foo' }} />;", None),
         (r"React.createElement('div', {}, 'Foo');", None),
@@ -207,6 +224,9 @@ fn test() {
 
     let fail = vec![
         (r"<br>Foo</br>;", None),
+        (r"<img>{' '}</img>;", None),
+        (r"<input><span /></input>;", None),
+        (r"<hr>{children}</hr>;", None),
         (r"<br children='Foo' />;", None),
         (r"<img {...props} children='Foo' />;", None),
         (r"<br dangerouslySetInnerHTML={{ __html: 'Foo' }} />;", None),
@@ -0,0 +1,244 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, BinaryExpression, Expression, TemplateLiteral},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::BinaryOperator;
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn eval_with_expression_diagnostic(span: Span, callee: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{callee}` is called with a non-literal argument"))
+        .with_help(
+            "Code built at runtime may contain untrusted input. Avoid evaluating strings as code.",
+        )
+        .with_label(span)
+}
+
+fn timer_with_expression_diagnostic(span: Span, callee: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{callee}` is called with a string built at runtime"))
+        .with_help(format!(
+            "A string passed to `{callee}` is evaluated as code. Pass a function instead."
+        ))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectEvalWithExpression {
+    allow_literal_strings: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows evaluating code which is not a string literal, with `eval()`, `Function()` or
+    /// `new Function()`. Passing a string built at runtime to `setTimeout()` or `setInterval()`
+    /// is also reported, as a string passed to them is evaluated too. Other non-literal
+    /// arguments to timers, such as variables, are usually functions and are not reported.
+    ///
+    /// Both are reported with the severity configured for this rule.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Code built at runtime may contain user input, which allows an attacker to run arbitrary
+    /// code in your application.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// eval(userInput);
+    /// new Function("a", body);
+    /// setTimeout("run(" + arg + ")", 100);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// eval("1 + 1");
+    /// new Function("a", "return a * 2");
+    /// setTimeout(() => run(), 100);
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### allowLiteralStrings
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Also allow strings which are static, but not a single literal: string literals joined
+    /// with `+`, and template literals whose expressions are static strings.
+    ///
+    /// Examples of **correct** code for this rule with `{ "allowLiteralStrings": true }`:
+    /// ```js
+    /// eval("var a = " + "1");
+    /// setTimeout(`run(${"1"})`, 100);
+    /// ```
+    DetectEvalWithExpression,
+    security,
+    suspicious
+);
+
+impl Rule for DetectEvalWithExpression {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let allow_literal_strings = value
+            .get(0)
+            .and_then(|v| v.get("allowLiteralStrings"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
+        Self { allow_literal_strings }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (callee, arguments, span) = match node.kind() {
+            AstKind::CallExpression(call_expr) => {
+                (&call_expr.callee, &call_expr.arguments, call_expr.span)
+            }
+            AstKind::NewExpression(new_expr) => {
+                (&new_expr.callee, &new_expr.arguments, new_expr.span)
+            }
+            _ => return,
+        };
+        let Expression::Identifier(ident) = callee.without_parentheses() else {
+            return;
+        };
+        // A local variable named e.g. `eval` is not the global function
+        if !ctx.is_reference_to_global_variable(ident) {
+            return;
+        }
+
+        match ident.name.as_str() {
+            "eval" if matches!(node.kind(), AstKind::CallExpression(_)) => {
+                if arguments.first().is_some_and(|arg| !self.is_static_argument(arg)) {
+                    ctx.diagnostic(eval_with_expression_diagnostic(span, "eval"));
+                }
+            }
+            // Every argument of the `Function` constructor is code: the parameters and the body
+            "Function" => {
+                if arguments.iter().any(|arg| !self.is_static_argument(arg)) {
+                    ctx.diagnostic(eval_with_expression_diagnostic(span, "Function"));
+                }
+            }
+            name @ ("setTimeout" | "setInterval")
+                if matches!(node.kind(), AstKind::CallExpression(_)) =>
+            {
+                let Some(first) = arguments.first() else {
+                    return;
+                };
+                if !self.is_static_argument(first) && is_runtime_string(first) {
+                    ctx.diagnostic(timer_with_expression_diagnostic(first.span(), name));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl DetectEvalWithExpression {
+    /// Whether `arg` is a string literal, or with `allowLiteralStrings`, a static string.
+    fn is_static_argument(&self, arg: &Argument) -> bool {
+        let Some(expr) = arg.as_expression() else {
+            return false;
+        };
+        if self.allow_literal_strings {
+            is_static_string(expr)
+        } else {
+            match expr.without_parentheses() {
+                Expression::StringLiteral(_) => true,
+                Expression::TemplateLiteral(template) => template.expressions.is_empty(),
+                _ => false,
+            }
+        }
+    }
+}
+
+/// Whether `expr` is a string built only from string literals.
+fn is_static_string(expr: &Expression) -> bool {
+    match expr.without_parentheses() {
+        Expression::StringLiteral(_) => true,
+        Expression::TemplateLiteral(template) => is_static_template(template),
+        Expression::BinaryExpression(binary) => is_static_concatenation(binary),
+        _ => false,
+    }
+}
+
+fn is_static_template(template: &TemplateLiteral) -> bool {
+    template.expressions.iter().all(is_static_string)
+}
+
+fn is_static_concatenation(binary: &BinaryExpression) -> bool {
+    binary.operator == BinaryOperator::Addition
+        && is_static_string(&binary.left)
+        && is_static_string(&binary.right)
+}
+
+/// Whether `arg` is a string built at runtime: a template literal, or a concatenation.
+fn is_runtime_string(arg: &Argument) -> bool {
+    match arg.as_expression().map(Expression::without_parentheses) {
+        Some(Expression::TemplateLiteral(_)) => true,
+        Some(Expression::BinaryExpression(binary)) => binary.operator == BinaryOperator::Addition,
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("eval('1 + 1')", None),
+        ("eval(`1 + 1`)", None),
+        ("eval()", None),
+        ("Function('a', 'b', 'return a + b')", None),
+        ("new Function('return 1')", None),
+        ("new Function()", None),
+        ("function f(eval) { eval(code); }", None),
+        ("let Function = Foo; new Function(code);", None),
+        ("obj.eval(code)", None),
+        ("new eval(code)", None),
+        ("setTimeout(() => run(), 100)", None),
+        ("setTimeout(function () { run(); }, 100)", None),
+        ("setTimeout('run()', 100)", None),
+        ("setInterval(`tick()`, 1000)", None),
+        ("setTimeout(callback, 100)", None),
+        ("setInterval(obj.tick, 1000)", None),
+        ("setTimeout(getCode(), 100)", None),
+        ("const setTimeout = (code) => {}; setTimeout(code);", None),
+        ("eval('var a = ' + '1')", Some(serde_json::json!([{ "allowLiteralStrings": true }]))),
+        (
+            "new Function('a', `return a + ${'1'}`)",
+            Some(serde_json::json!([{ "allowLiteralStrings": true }])),
+        ),
+        (
+            "setTimeout('run(' + '1' + ')', 100)",
+            Some(serde_json::json!([{ "allowLiteralStrings": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("eval(code)", None),
+        ("eval(`return ${code}`)", None),
+        ("eval('var a = ' + value)", None),
+        ("eval(...args)", None),
+        ("(eval)(code)", None),
+        ("Function(body)", None),
+        ("Function('a', body)", None),
+        ("new Function(code)", None),
+        ("new Function('a', `return ${expr}`)", None),
+        ("eval('var a = ' + '1')", None),
+        ("setTimeout(`run(${arg})`, 100)", None),
+        ("setInterval('tick(' + n + ')', 1000)", None),
+        ("setTimeout('run(' + arg + ')', 100)", None),
+        ("eval('var a = ' + value)", Some(serde_json::json!([{ "allowLiteralStrings": true }]))),
+        ("new Function(`${code}`)", Some(serde_json::json!([{ "allowLiteralStrings": true }]))),
+        (
+            "setTimeout('run(' + arg + ')', 100)",
+            Some(serde_json::json!([{ "allowLiteralStrings": true }])),
+        ),
+    ];
+
+    Tester::new(DetectEvalWithExpression::NAME, DetectEvalWithExpression::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -1,10 +1,16 @@
 use oxc_ast::{
     AstKind,
-    ast::{Expression, match_member_expression},
+    ast::{
+        Argument, ArrowFunctionExpression, BindingPatternKind, CallExpression, Class, Expression,
+        FormalParameters, Function, FunctionBody, IdentifierReference, ReturnStatement,
+        ThisExpression, VariableDeclaration, VariableDeclarationKind, match_member_expression,
+    },
 };
+use oxc_ast_visit::{Visit, walk};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_semantic::{Reference, ScopeFlags};
+use oxc_span::{GetSpan, Span};
 
 use crate::{AstNode, ast_util::is_method_call, context::LintContext, rule::Rule};
 
@@ -45,10 +51,23 @@ declare_oxc_lint!(
     /// const foo = [1, 2, 3];
     /// for (const element of foo) { /* ... */ }
     /// ```
+    ///
+    /// The fix replaces a `forEach()` statement whose callback is an arrow or function expression
+    /// with a `for…of` loop, iterating over `entries()` when the callback takes an index:
+    ///
+    /// ```javascript
+    /// foo.forEach((element, index) => bar(element, index));
+    /// // =>
+    /// for (const [index, element] of foo.entries()) { bar(element, index); }
+    /// ```
+    ///
+    /// It is not offered when the loop would behave differently, e.g. when the callback takes
+    /// the array as its third parameter, `return`s, declares `var`s, or is a `function` using
+    /// `this` or `arguments`.
     NoArrayForEach,
     unicorn,
     restriction,
-    pending
+    fix
 );
 
 impl Rule for NoArrayForEach {
@@ -90,13 +109,208 @@ impl Rule for NoArrayForEach {
                 return;
             };
 
-            ctx.diagnostic(no_array_for_each_diagnostic(span));
+            if let Some((statement_span, for_of)) = for_of_loop(node, call_expr, ctx) {
+                ctx.diagnostic_with_fix(no_array_for_each_diagnostic(span), |fixer| {
+                    fixer.replace(statement_span, for_of)
+                });
+            } else {
+                ctx.diagnostic(no_array_for_each_diagnostic(span));
+            }
         }
     }
 }
 
 pub const IGNORED_OBJECTS: [&str; 3] = ["Children", "r", "pIteration"];
 
+/// Build a `for…of` loop replacing the statement `array.forEach(callback)`, returning the span
+/// of the statement and the loop.
+fn for_of_loop<'a>(
+    node: &AstNode<'a>,
+    call_expr: &CallExpression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<(Span, String)> {
+    let AstKind::ExpressionStatement(statement) = ctx.nodes().parent_kind(node.id()) else {
+        return None;
+    };
+    let member_expr = call_expr.callee.as_member_expression()?;
+    if call_expr.optional || member_expr.optional() || call_expr.arguments.len() != 1 {
+        return None;
+    }
+    let array = member_expr.object();
+    if matches!(array, Expression::Super(_) | Expression::NumericLiteral(_)) {
+        return None;
+    }
+
+    let Argument::ArrowFunctionExpression(arrow) = &call_expr.arguments[0] else {
+        let Argument::FunctionExpression(func) = &call_expr.arguments[0] else {
+            return None;
+        };
+        if func.r#async || func.generator || func.id.is_some() {
+            return None;
+        }
+        let body = func.body.as_ref()?;
+        return for_of_loop_with_body(statement.span, array, &func.params, body, false, true, ctx);
+    };
+    if arrow.r#async {
+        return None;
+    }
+    for_of_loop_with_body(
+        statement.span,
+        array,
+        &arrow.params,
+        &arrow.body,
+        arrow.expression,
+        false,
+        ctx,
+    )
+}
+
+fn for_of_loop_with_body<'a>(
+    statement_span: Span,
+    array: &Expression<'a>,
+    params: &FormalParameters<'a>,
+    body: &FunctionBody<'a>,
+    is_expression_body: bool,
+    is_function: bool,
+    ctx: &LintContext<'a>,
+) -> Option<(Span, String)> {
+    // The third parameter is the array itself, which the loop has no binding for.
+    if params.rest.is_some() || params.items.is_empty() || params.items.len() > 2 {
+        return None;
+    }
+    let patterns = params.items.iter().map(|param| &param.pattern).collect::<Vec<_>>();
+    if patterns.iter().any(|pattern| {
+        pattern.type_annotation.is_some()
+            || pattern.optional
+            || matches!(pattern.kind, BindingPatternKind::AssignmentPattern(_))
+    }) {
+        return None;
+    }
+    if patterns.len() == 2 && !patterns[1].kind.is_binding_identifier() {
+        return None;
+    }
+
+    let mut body_visitor = CallbackBodyVisitor::default();
+    body_visitor.visit_function_body(body);
+    if body_visitor.has_return || body_visitor.has_var || (is_function && body_visitor.uses_this) {
+        return None;
+    }
+
+    // The array is evaluated where the loop variables are already declared.
+    let bindings =
+        patterns.iter().flat_map(|pattern| pattern.get_binding_identifiers()).collect::<Vec<_>>();
+    let mut array_visitor = CallbackBodyVisitor::default();
+    array_visitor.visit_expression(array);
+    if array_visitor.references.iter().any(|name| bindings.iter().any(|ident| ident.name == *name))
+    {
+        return None;
+    }
+
+    let body_span = if is_expression_body {
+        let expr = body.statements.first()?.span();
+        // `function`, `class`, `{` and `let [` would begin a different statement.
+        let text = ctx.source_range(expr);
+        if ["function", "async function", "class", "{", "let"]
+            .iter()
+            .any(|prefix| text.starts_with(prefix))
+        {
+            return None;
+        }
+        expr
+    } else {
+        body.span
+    };
+    let callback_span = Span::new(statement_span.start, body_span.start);
+    if ctx.has_comments_between(callback_span)
+        || ctx.has_comments_between(Span::new(body_span.end, statement_span.end))
+    {
+        return None;
+    }
+
+    let is_reassigned = bindings.iter().any(|ident| {
+        ctx.scoping().get_resolved_references(ident.symbol_id()).any(Reference::is_write)
+    });
+    let kind = if is_reassigned { "let" } else { "const" };
+    let array = ctx.source_range(array.span());
+    let element = ctx.source_range(patterns[0].span());
+    let head = match patterns.get(1) {
+        Some(index) => {
+            let index = ctx.source_range(index.span());
+            format!("for ({kind} [{index}, {element}] of {array}.entries())")
+        }
+        None => format!("for ({kind} {element} of {array})"),
+    };
+    let body = ctx.source_range(body_span);
+    let for_of =
+        if is_expression_body { format!("{head} {{ {body}; }}") } else { format!("{head} {body}") };
+
+    Some((statement_span, for_of))
+}
+
+/// Finds what in the body of a `forEach` callback would behave differently in a `for…of` loop.
+#[derive(Default)]
+struct CallbackBodyVisitor<'a> {
+    /// A `return` of the callback.
+    has_return: bool,
+    /// A `var` declared in the callback, which would be hoisted out of the loop.
+    has_var: bool,
+    /// `this` or `arguments` of the callback.
+    uses_this: bool,
+    references: Vec<&'a str>,
+    function_depth: usize,
+    arrow_depth: usize,
+}
+
+impl<'a> Visit<'a> for CallbackBodyVisitor<'a> {
+    fn visit_return_statement(&mut self, stmt: &ReturnStatement<'a>) {
+        if self.function_depth == 0 && self.arrow_depth == 0 {
+            self.has_return = true;
+        }
+        walk::walk_return_statement(self, stmt);
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        if decl.kind == VariableDeclarationKind::Var
+            && self.function_depth == 0
+            && self.arrow_depth == 0
+        {
+            self.has_var = true;
+        }
+        walk::walk_variable_declaration(self, decl);
+    }
+
+    fn visit_this_expression(&mut self, _: &ThisExpression) {
+        if self.function_depth == 0 {
+            self.uses_this = true;
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if ident.name == "arguments" && self.function_depth == 0 {
+            self.uses_this = true;
+        }
+        self.references.push(ident.name.as_str());
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        self.function_depth += 1;
+        walk::walk_function(self, func, flags);
+        self.function_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        self.arrow_depth += 1;
+        walk::walk_arrow_function_expression(self, arrow);
+        self.arrow_depth -= 1;
+    }
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        self.function_depth += 1;
+        walk::walk_class(self, class);
+        self.function_depth -= 1;
+    }
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -124,5 +338,97 @@ fn test() {
         r"return foo.forEach(element => {bar(element)});",
     ];
 
-    Tester::new(NoArrayForEach::NAME, NoArrayForEach::PLUGIN, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("foo.forEach(element => bar(element));", "for (const element of foo) { bar(element); }"),
+        (
+            "foo.forEach((element) => { bar(element); });",
+            "for (const element of foo) { bar(element); }",
+        ),
+        (
+            "foo.forEach(function (element) { bar(element); });",
+            "for (const element of foo) { bar(element); }",
+        ),
+        (
+            "foo.forEach((element, index) => bar(element, index));",
+            "for (const [index, element] of foo.entries()) { bar(element, index); }",
+        ),
+        (
+            "foo.forEach(function (element, index) { bar(element, index); })",
+            "for (const [index, element] of foo.entries()) { bar(element, index); }",
+        ),
+        (
+            "foo.forEach(({ a, b: [c] }) => bar(a, c));",
+            "for (const { a, b: [c] } of foo) { bar(a, c); }",
+        ),
+        ("foo.forEach(element => { element = 1; });", "for (let element of foo) { element = 1; }"),
+        ("foo.forEach(element => ({ element }));", "for (const element of foo) { ({ element }); }"),
+        (
+            "this.items.forEach(item => this.add(item));",
+            "for (const item of this.items) { this.add(item); }",
+        ),
+        ("(a || b).forEach(x => f(x));", "for (const x of (a || b)) { f(x); }"),
+        ("getItems().forEach(x => f(x));", "for (const x of getItems()) { f(x); }"),
+        ("if (a) foo.forEach(x => f(x));", "if (a) for (const x of foo) { f(x); }"),
+        (
+            "foo.forEach(x => { const g = () => { return x; }; function h() { return this; } g(); });",
+            "for (const x of foo) { const g = () => { return x; }; function h() { return this; } g(); }",
+        ),
+        // Not fixed
+        (
+            "foo.forEach((element, index, array) => bar(element, index, array));",
+            "foo.forEach((element, index, array) => bar(element, index, array));",
+        ),
+        (
+            "foo.forEach(element => { if (element) return; bar(element); });",
+            "foo.forEach(element => { if (element) return; bar(element); });",
+        ),
+        (
+            "foo.forEach(element => { var x = element; });",
+            "foo.forEach(element => { var x = element; });",
+        ),
+        (
+            "foo.forEach(function (element) { this.bar(element); });",
+            "foo.forEach(function (element) { this.bar(element); });",
+        ),
+        (
+            "foo.forEach(function (element) { bar(arguments); });",
+            "foo.forEach(function (element) { bar(arguments); });",
+        ),
+        (
+            "foo.forEach(element => bar(element), thisArg);",
+            "foo.forEach(element => bar(element), thisArg);",
+        ),
+        ("foo.forEach(bar);", "foo.forEach(bar);"),
+        (
+            "foo.forEach(async element => await bar(element));",
+            "foo.forEach(async element => await bar(element));",
+        ),
+        (
+            "foo.forEach((element, index = 0) => bar(element));",
+            "foo.forEach((element, index = 0) => bar(element));",
+        ),
+        (
+            "foo.forEach((element, { index }) => bar(element));",
+            "foo.forEach((element, { index }) => bar(element));",
+        ),
+        (
+            "foo.forEach((element: number) => bar(element));",
+            "foo.forEach((element: number) => bar(element));",
+        ),
+        ("element.forEach(element => bar(element));", "element.forEach(element => bar(element));"),
+        (
+            "const x = foo.forEach(element => bar(element));",
+            "const x = foo.forEach(element => bar(element));",
+        ),
+        ("foo?.forEach(element => bar(element));", "foo?.forEach(element => bar(element));"),
+        ("foo.forEach(element => function () {});", "foo.forEach(element => function () {});"),
+        (
+            "foo.forEach(element /* comment */ => bar(element));",
+            "foo.forEach(element /* comment */ => bar(element));",
+        ),
+    ];
+
+    Tester::new(NoArrayForEach::NAME, NoArrayForEach::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
@@ -8,6 +8,7 @@ use crate::{
     AstNode,
     ast_util::{call_expr_method_callee_info, is_method_call},
     context::LintContext,
+    fixer::RuleFixer,
     rule::Rule,
 };
 
@@ -42,10 +43,15 @@ declare_oxc_lint!(
     /// ```javascript
     /// if (str.includes('foo')) { }
     /// ```
+    ///
+    /// The fix replaces the comparison with a call to `includes()`, negated for
+    /// `indexOf() === -1` and `indexOf() < 0`. When `indexOf()` is called on the result of
+    /// another expression, such as a function call, it is only offered as a suggestion, as the
+    /// result may not have an `includes()` method.
     PreferIncludes,
     unicorn,
     style,
-    pending
+    conditional_fix_suggestion
 );
 
 impl Rule for PreferIncludes {
@@ -63,39 +69,71 @@ impl Rule for PreferIncludes {
             return;
         }
 
-        if matches!(
-            bin_expr.operator,
+        let right = bin_expr.right.without_parentheses();
+        let negated = match bin_expr.operator {
             BinaryOperator::StrictInequality
-                | BinaryOperator::Inequality
-                | BinaryOperator::GreaterThan
-                | BinaryOperator::StrictEquality
-                | BinaryOperator::Equality
-        ) {
-            if !is_negative_one(bin_expr.right.without_parentheses()) {
-                return;
+            | BinaryOperator::Inequality
+            | BinaryOperator::GreaterThan
+                if is_negative_one(right) =>
+            {
+                false
             }
+            BinaryOperator::StrictEquality | BinaryOperator::Equality if is_negative_one(right) => {
+                true
+            }
+            BinaryOperator::GreaterEqualThan if is_zero(right) => false,
+            BinaryOperator::LessThan if is_zero(right) => true,
+            _ => return,
+        };
 
-            ctx.diagnostic(prefer_includes_diagnostic(
-                call_expr_method_callee_info(left_call_expr).unwrap().0,
-            ));
-        }
-
-        if matches!(bin_expr.operator, BinaryOperator::GreaterEqualThan | BinaryOperator::LessThan)
-        {
-            let Expression::NumericLiteral(num_lit) = bin_expr.right.without_parentheses() else {
-                return;
-            };
+        let Some(member_expr) = left_call_expr.callee.get_inner_expression().as_member_expression()
+        else {
+            return;
+        };
+        let (property_span, _) = call_expr_method_callee_info(left_call_expr).unwrap();
+        let diagnostic = prefer_includes_diagnostic(property_span);
+
+        // `foo.indexOf(bar) !== -1` -> `foo.includes(bar)`
+        // `foo.indexOf(bar) === -1` -> `!foo.includes(bar)`
+        let fix = |fixer: RuleFixer<'_, 'a>| {
+            let call_span = left_call_expr.span;
+            let includes = format!(
+                "{}{}includes{}",
+                if negated { "!" } else { "" },
+                ctx.source_range(Span::new(call_span.start, property_span.start)),
+                ctx.source_range(Span::new(property_span.end, call_span.end)),
+            );
+            fixer.replace(bin_expr.span, includes)
+        };
 
-            if num_lit.raw.as_ref().unwrap() != "0" {
-                return;
-            }
-            ctx.diagnostic(prefer_includes_diagnostic(
-                call_expr_method_callee_info(left_call_expr).unwrap().0,
-            ));
+        // Only the results of other expressions may not have an `includes` method, e.g.
+        // `getItems().indexOf(item)`, so leave it to the user to check them.
+        if is_simple_object(member_expr.object()) {
+            ctx.diagnostic_with_fix(diagnostic, fix);
+        } else {
+            ctx.diagnostic_with_suggestion(diagnostic, fix);
         }
     }
 }
 
+/// Whether `expr` is a string or array literal, or a variable or `this`, or a static property
+/// of one, e.g. `this.items`.
+fn is_simple_object(expr: &Expression) -> bool {
+    match expr.without_parentheses() {
+        Expression::Identifier(_)
+        | Expression::ThisExpression(_)
+        | Expression::StringLiteral(_)
+        | Expression::TemplateLiteral(_)
+        | Expression::ArrayExpression(_) => true,
+        Expression::StaticMemberExpression(member_expr) => is_simple_object(&member_expr.object),
+        _ => false,
+    }
+}
+
+fn is_zero(expr: &Expression) -> bool {
+    matches!(expr, Expression::NumericLiteral(num_lit) if num_lit.raw.as_ref().unwrap() == "0")
+}
+
 fn is_negative_one(expr: &Expression) -> bool {
     let Expression::UnaryExpression(unary_expr) = expr else {
         return false;
@@ -142,7 +180,35 @@ fn test() {
         r"(a || b).indexOf('foo') === -1",
         r"foo.indexOf(bar, 0) !== -1",
         r"foo.indexOf(bar, 1) !== -1",
+        r"str.indexOf('foo') === -1",
+        r"this.items.indexOf(item) !== -1",
+        r"getItems().indexOf(item) >= 0",
+        r"foo.bar().indexOf(baz) < 0",
+    ];
+
+    let fix = vec![
+        (r"'foobar'.indexOf('foo') !== -1", r"'foobar'.includes('foo')"),
+        (r"str.indexOf('foo') != -1", r"str.includes('foo')"),
+        (r"str.indexOf('foo') > -1", r"str.includes('foo')"),
+        (r"str.indexOf('foo') >= 0", r"str.includes('foo')"),
+        (r"str.indexOf('foo') === -1", r"!str.includes('foo')"),
+        (r"str.indexOf('foo') == -1", r"!str.includes('foo')"),
+        (r"str.indexOf('foo') < 0", r"!str.includes('foo')"),
+        (r"[1,2,3].indexOf(4) !== -1", r"[1,2,3].includes(4)"),
+        (r"`foo${bar}`.indexOf('foo') < 0", r"!`foo${bar}`.includes('foo')"),
+        (r"this.items.indexOf(item) !== -1", r"this.items.includes(item)"),
+        (r"(str).indexOf('foo') === (-1)", r"!(str).includes('foo')"),
+        (r"(str.indexOf('foo')) !== -1", r"str.includes('foo')"),
+        (r"foo.indexOf(bar, 1) !== -1", r"foo.includes(bar, 1)"),
+        (r"if (str.indexOf('foo') === -1) {}", r"if (!str.includes('foo')) {}"),
+        // Suggestions
+        (r"(a || b).indexOf('foo') === -1", r"!(a || b).includes('foo')"),
+        (r"getItems().indexOf(item) >= 0", r"getItems().includes(item)"),
+        (r"foo.bar().indexOf(baz) < 0", r"!foo.bar().includes(baz)"),
+        (r"foo[bar].indexOf(baz) !== -1", r"foo[bar].includes(baz)"),
     ];
 
-    Tester::new(PreferIncludes::NAME, PreferIncludes::PLUGIN, pass, fail).test_and_snapshot();
+    Tester::new(PreferIncludes::NAME, PreferIncludes::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
@@ -1,9 +1,12 @@
-use oxc_ast::{AstKind, ast::MemberExpression};
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, CallExpression, Expression, MemberExpression},
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{AstNode, context::LintContext, fixer::RuleFixer, rule::Rule};
 
 fn prefer_string_slice_diagnostic(span: Span, method_name: &str) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("Prefer String#slice() over String#{method_name}()"))
@@ -27,16 +30,22 @@ declare_oxc_lint!(
     /// Examples of **incorrect** code for this rule:
     /// ```javascript
     /// "foo".substr(1, 2)
+    /// "foo".substring(1, 3)
     /// ```
     ///
     /// Examples of **correct** code for this rule:
     /// ```javascript
-    /// "foo".slice(1, 2)
+    /// "foo".slice(1, 3)
     /// ```
+    ///
+    /// The fix replaces `substring(start, end)` with `slice(start, end)`, and
+    /// `substr(start, length)` with `slice(start, start + length)`. It is only applied
+    /// automatically when the arguments are number literals, otherwise it is offered as a
+    /// suggestion, as the arguments may be negative, be out of order, or have side effects.
     PreferStringSlice,
     unicorn,
     pedantic,
-    fix
+    conditional_fix_suggestion
 );
 
 impl Rule for PreferStringSlice {
@@ -45,22 +54,115 @@ impl Rule for PreferStringSlice {
             return;
         };
 
-        let Some(member_expr) = call_expr.callee.get_member_expr() else {
+        let Some(MemberExpression::StaticMemberExpression(member_expr)) =
+            call_expr.callee.get_member_expr()
+        else {
             return;
         };
 
-        if let MemberExpression::StaticMemberExpression(v) = member_expr {
-            if !matches!(v.property.name.as_str(), "substr" | "substring") {
+        let method_name = member_expr.property.name.as_str();
+        let is_substr = match method_name {
+            "substr" => true,
+            "substring" => false,
+            _ => return,
+        };
+        let diagnostic = prefer_string_slice_diagnostic(member_expr.property.span, method_name);
+
+        let args =
+            call_expr.arguments.iter().map(Argument::as_expression).collect::<Option<Vec<_>>>();
+        let args = match args {
+            Some(args) if args.len() <= 2 => args,
+            // Spread arguments, or too many arguments
+            _ => {
+                ctx.diagnostic(diagnostic);
                 return;
             }
-            ctx.diagnostic_with_fix(
-                prefer_string_slice_diagnostic(v.property.span, v.property.name.as_str()),
-                |fixer| fixer.replace(v.property.span, "slice"),
-            );
+        };
+
+        let is_safe = args.iter().all(|arg| matches!(arg, Expression::NumericLiteral(_)));
+        let fix = |fixer: RuleFixer<'_, 'a>| {
+            let replacement = match args.as_slice() {
+                // `foo.substr(start, length)` -> `foo.slice(start, start + length)`
+                [start, length] if is_substr => {
+                    let end = match (start.without_parentheses(), length.without_parentheses()) {
+                        (Expression::NumericLiteral(start), Expression::NumericLiteral(length)) => {
+                            (start.value + length.value).to_string()
+                        }
+                        (Expression::NumericLiteral(start), _) if start.value == 0.0 => {
+                            ctx.source_range(length.span()).to_string()
+                        }
+                        _ => format!(
+                            "{} + {}",
+                            ctx.source_range(start.span()),
+                            parenthesize_addend(length, ctx),
+                        ),
+                    };
+                    Some(format!("{}, {end}", ctx.source_range(start.span())))
+                }
+                // `foo.substring(2, 1)` -> `foo.slice(1, 2)`
+                [Expression::NumericLiteral(start_lit), Expression::NumericLiteral(end_lit)]
+                    if start_lit.value > end_lit.value =>
+                {
+                    Some(format!(
+                        "{}, {}",
+                        ctx.source_range(end_lit.span),
+                        ctx.source_range(start_lit.span)
+                    ))
+                }
+                // `foo.substring(start, 0)` -> `foo.slice(0, start)`
+                [start, Expression::NumericLiteral(end_lit)] if end_lit.value == 0.0 => {
+                    Some(format!(
+                        "{}, {}",
+                        ctx.source_range(end_lit.span),
+                        ctx.source_range(start.span())
+                    ))
+                }
+                _ => None,
+            };
+
+            match replacement {
+                Some(replacement) => {
+                    let fixer = fixer.for_multifix();
+                    let mut fix = fixer.new_fix_with_capacity(2);
+                    fix.push(fixer.replace(member_expr.property.span, "slice"));
+                    fix.push(fixer.replace(arguments_span(call_expr), replacement));
+                    fix.with_message(format!("Replace `{method_name}` with `slice`."))
+                }
+                None => fixer.replace(member_expr.property.span, "slice"),
+            }
+        };
+
+        if is_safe {
+            ctx.diagnostic_with_fix(diagnostic, fix);
+        } else {
+            ctx.diagnostic_with_suggestion(diagnostic, fix);
         }
     }
 }
 
+/// Span covering all arguments of `call_expr`. Must only be called when there are arguments.
+fn arguments_span(call_expr: &CallExpression) -> Span {
+    let first = call_expr.arguments.first().unwrap().span();
+    let last = call_expr.arguments.last().unwrap().span();
+    Span::new(first.start, last.end)
+}
+
+/// Source text of `expr`, wrapped in parentheses if it would bind looser than `+`.
+fn parenthesize_addend<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> String {
+    let text = ctx.source_range(expr.span());
+    match expr {
+        Expression::Identifier(_)
+        | Expression::NumericLiteral(_)
+        | Expression::StringLiteral(_)
+        | Expression::ParenthesizedExpression(_)
+        | Expression::CallExpression(_)
+        | Expression::StaticMemberExpression(_)
+        | Expression::ComputedMemberExpression(_)
+        | Expression::PrivateFieldExpression(_) => text.to_string(),
+        _ => format!("({text})"),
+    }
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -140,6 +242,31 @@ fn test() {
         ("foo.bar?.baz?.substr()", "foo.bar?.baz?.slice()"),
         ("foo.bar?.baz.substring()", "foo.bar?.baz.slice()"),
         ("foo.bar.baz?.substr()", "foo.bar.baz?.slice()"),
+        (r#""foo".substr(1)"#, r#""foo".slice(1)"#),
+        (r#""foo".substr(1, 2)"#, r#""foo".slice(1, 3)"#),
+        (r#""foo".substr(0, 2)"#, r#""foo".slice(0, 2)"#),
+        (r#""foo".substring(1)"#, r#""foo".slice(1)"#),
+        (r#""foo".substring(1, 3)"#, r#""foo".slice(1, 3)"#),
+        (r#""foo".substring(2, 1)"#, r#""foo".slice(1, 2)"#),
+        // Suggestions
+        ("foo.substr(start)", "foo.slice(start)"),
+        ("foo.substr(start, length)", "foo.slice(start, start + length)"),
+        ("foo.substr(0, length)", "foo.slice(0, length)"),
+        ("foo.substring(start, end)", "foo.slice(start, end)"),
+        (r#""foo".substring(length, 0)"#, r#""foo".slice(0, length)"#),
+        (r#""foo".substring(-1, 2)"#, r#""foo".slice(-1, 2)"#),
+        (r#""foobar".substring("foo".length)"#, r#""foobar".slice("foo".length)"#),
+        (r"foo.substring(0, await 1)", r"foo.slice(0, await 1)"),
+        (r#""foo".substr(1, "abc".length)"#, r#""foo".slice(1, 1 + "abc".length)"#),
+        (
+            r#""foo".substr(bar.length, Math.min(baz, 100))"#,
+            r#""foo".slice(bar.length, bar.length + Math.min(baz, 100))"#,
+        ),
+        (r"foo.substr(start, a ? b : c)", r"foo.slice(start, start + (a ? b : c))"),
+        (r"foo.substr(0, (100, 1))", r"foo.slice(0, (100, 1))"),
+        // No fix for spread or extra arguments
+        (r"foo.substr(0, ...bar)", r"foo.substr(0, ...bar)"),
+        (r"foo.substring(1, 2, 3)", r"foo.substring(1, 2, 3)"),
     ];
 
     Tester::new(PreferStringSlice::NAME, PreferStringSlice::PLUGIN, pass, fail)
@@ -77,6 +77,27 @@ impl From<(&str, Option<Value>, Option<Value>, Option<PathBuf>)> for TestCase {
     }
 }
 
+/// A named group of pass and fail cases.
+///
+/// Used by test suites that are organized by behavior rather than as flat lists.
+/// See [`Tester::from_groups`].
+#[derive(Debug, Clone)]
+pub struct TestGroup {
+    pub name: &'static str,
+    pub pass: Vec<TestCase>,
+    pub fail: Vec<TestCase>,
+}
+
+impl TestGroup {
+    pub fn new<T: Into<TestCase>>(name: &'static str, pass: Vec<T>, fail: Vec<T>) -> Self {
+        Self {
+            name,
+            pass: pass.into_iter().map(Into::into).collect(),
+            fail: fail.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum ExpectFixKind {
     /// We expect no fix to be applied
@@ -265,6 +286,24 @@ impl Tester {
         }
     }
 
+    /// Create a tester from named groups of cases.
+    ///
+    /// Cases run, and are snapshotted, in group order.
+    pub fn from_groups(
+        rule_name: &'static str,
+        plugin_name: &'static str,
+        groups: Vec<TestGroup>,
+    ) -> Self {
+        let (pass, fail): (Vec<_>, Vec<_>) =
+            groups.into_iter().map(|group| (group.pass, group.fail)).unzip();
+        Self::new(
+            rule_name,
+            plugin_name,
+            pass.into_iter().flatten().collect(),
+            fail.into_iter().flatten().collect(),
+        )
+    }
+
     /// Change the path
     pub fn change_rule_path(mut self, path: &str) -> Self {
         self.rule_path = self.current_working_directory.join(path);
@@ -1,19 +1,28 @@
 use std::borrow::Cow;
 
+use cow_utils::CowUtils;
 use oxc_ast::{
     AstKind,
     ast::{
-        CallExpression, Expression, JSXAttributeItem, JSXAttributeName, JSXAttributeValue,
-        JSXChild, JSXElement, JSXElementName, JSXExpression, JSXMemberExpression,
-        JSXMemberExpressionObject, JSXOpeningElement, MemberExpression,
+        CallExpression, Expression, JSXAttribute, JSXAttributeItem, JSXAttributeName,
+        JSXAttributeValue, JSXChild, JSXElement, JSXElementName, JSXExpression,
+        JSXMemberExpression, JSXMemberExpressionObject, JSXOpeningElement, MemberExpression,
     },
     match_member_expression,
 };
 use oxc_ecmascript::{ToBoolean, is_global_reference::WithoutGlobalReferenceInformation};
 use oxc_semantic::AstNode;
+use oxc_span::Atom;
 
 use crate::{LintContext, OxlintSettings};
 
+pub use self::iterator::{
+    InsideArrayOrIterator, IteratorMethod, is_in_array_or_iter, is_rendered,
+    is_within_children_to_array, iterator_index_param,
+};
+
+mod iterator;
+
 pub fn is_create_element_call(call_expr: &CallExpression) -> bool {
     match &call_expr.callee {
         Expression::StaticMemberExpression(member_expr) => {
@@ -45,6 +54,27 @@ pub fn has_jsx_prop_ignore_case<'a, 'b>(
     })
 }
 
+/// Returns the attribute of `node` named `name`, e.g. `key={x}` for `"key"`.
+/// Spread attributes and namespaced names such as `xlink:href` are not matched.
+pub fn get_jsx_attribute<'a, 'b>(
+    node: &'b JSXOpeningElement<'a>,
+    name: &str,
+) -> Option<&'b JSXAttribute<'a>> {
+    node.attributes
+        .iter()
+        .find_map(|attr| attr.as_attribute().filter(|attr| attr.is_identifier(name)))
+}
+
+/// Returns the value of the attribute of `node` named `name`.
+///
+/// Returns `None` if there is no such attribute, or if it has no value, e.g. `<video muted />`.
+pub fn get_jsx_attribute_value<'a, 'b>(
+    node: &'b JSXOpeningElement<'a>,
+    name: &str,
+) -> Option<&'b JSXAttributeValue<'a>> {
+    get_jsx_attribute(node, name).and_then(|attr| attr.value.as_ref())
+}
+
 pub fn get_prop_value<'a, 'b>(item: &'b JSXAttributeItem<'a>) -> Option<&'b JSXAttributeValue<'a>> {
     item.as_attribute().and_then(|item| item.value.as_ref())
 }
@@ -62,6 +92,16 @@ pub fn get_string_literal_prop_value<'a>(item: &'a JSXAttributeItem<'_>) -> Opti
     get_prop_value(item).and_then(JSXAttributeValue::as_string_literal).map(|s| s.value.as_str())
 }
 
+/// Returns the value of an expression which is a string known statically, e.g. the value of
+/// `{"captions"}` or `` {`captions`} `` in a JSX attribute.
+pub fn get_static_string_value<'a>(expr: &Expression<'a>) -> Option<Atom<'a>> {
+    match expr.without_parentheses() {
+        Expression::StringLiteral(lit) => Some(lit.value),
+        Expression::TemplateLiteral(template) => template.single_quasi(),
+        _ => None,
+    }
+}
+
 // ref: https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/v6.9.0/src/util/isHiddenFromScreenReader.js
 pub fn is_hidden_from_screen_reader<'a>(
     ctx: &LintContext<'a>,
@@ -199,16 +239,33 @@ pub fn get_parent_component<'a, 'b>(
     ctx.nodes().ancestors(node.id()).find(|node| is_es5_component(node) || is_es6_component(node))
 }
 
-fn get_jsx_mem_expr_name<'a>(jsx_mem_expr: &JSXMemberExpression) -> Cow<'a, str> {
+fn get_jsx_mem_expr_name(jsx_mem_expr: &JSXMemberExpression) -> String {
     let prefix = match &jsx_mem_expr.object {
         JSXMemberExpressionObject::IdentifierReference(id) => Cow::Borrowed(id.name.as_str()),
         JSXMemberExpressionObject::MemberExpression(mem_expr) => {
-            Cow::Owned(format!("{}.{}", get_jsx_mem_expr_name(mem_expr), mem_expr.property.name))
+            Cow::Owned(get_jsx_mem_expr_name(mem_expr))
         }
         JSXMemberExpressionObject::ThisExpression(_) => Cow::Borrowed("this"),
     };
 
-    Cow::Owned(format!("{}.{}", prefix, jsx_mem_expr.property.name))
+    format!("{}.{}", prefix, jsx_mem_expr.property.name)
+}
+
+/// Get the name of a JSX element as written, e.g. `div`, `Foo.Bar` or `svg:circle`.
+///
+/// Only member expressions and namespaced names allocate.
+pub fn get_jsx_element_name<'a>(name: &JSXElementName<'a>) -> Cow<'a, str> {
+    match name {
+        JSXElementName::Identifier(id) => Cow::Borrowed(id.name.as_str()),
+        JSXElementName::IdentifierReference(id) => Cow::Borrowed(id.name.as_str()),
+        JSXElementName::NamespacedName(namespaced) => {
+            Cow::Owned(format!("{}:{}", namespaced.namespace.name, namespaced.name.name))
+        }
+        JSXElementName::MemberExpression(jsx_mem_expr) => {
+            Cow::Owned(get_jsx_mem_expr_name(jsx_mem_expr))
+        }
+        JSXElementName::ThisExpression(_) => Cow::Borrowed("this"),
+    }
 }
 
 /// Resolve element type(name) using jsx-a11y settings
@@ -218,15 +275,7 @@ pub fn get_element_type<'c, 'a>(
     context: &'c LintContext<'a>,
     element: &JSXOpeningElement<'a>,
 ) -> Cow<'c, str> {
-    let name = match &element.name {
-        JSXElementName::Identifier(id) => Cow::Borrowed(id.as_ref().name.as_str()),
-        JSXElementName::IdentifierReference(id) => Cow::Borrowed(id.as_ref().name.as_str()),
-        JSXElementName::NamespacedName(namespaced) => {
-            Cow::Owned(format!("{}:{}", namespaced.namespace.name, namespaced.name.name))
-        }
-        JSXElementName::MemberExpression(jsx_mem_expr) => get_jsx_mem_expr_name(jsx_mem_expr),
-        JSXElementName::ThisExpression(_) => Cow::Borrowed("this"),
-    };
+    let name = get_jsx_element_name(&element.name);
 
     let OxlintSettings { jsx_a11y, .. } = context.settings();
 
@@ -333,3 +382,97 @@ pub fn is_react_function_call(call: &CallExpression, expected_call: &str) -> boo
         true
     }
 }
+
+pub fn import_matcher<'a>(
+    ctx: &LintContext<'a>,
+    actual_local_name: &'a str,
+    expected_module_name: &'a str,
+) -> bool {
+    let expected_module_name = expected_module_name.cow_to_ascii_lowercase();
+    ctx.module_record().import_entries.iter().any(|import| {
+        import.module_request.name() == expected_module_name
+            && import.local_name.name() == actual_local_name
+    })
+}
+
+pub fn is_import<'a>(
+    ctx: &LintContext<'a>,
+    actual_local_name: &'a str,
+    expected_local_name: &'a str,
+    expected_module_name: &'a str,
+) -> bool {
+    if ctx.module_record().requested_modules.is_empty()
+        && ctx.scoping().get_bindings(ctx.scoping().root_scope_id()).is_empty()
+    {
+        return actual_local_name == expected_local_name;
+    }
+
+    import_matcher(ctx, actual_local_name, expected_module_name)
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Expression, JSXAttributeValue, JSXChild, Statement};
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use super::{get_jsx_attribute, get_jsx_attribute_value, get_jsx_element_name};
+
+    #[test]
+    fn test_get_jsx_attribute() {
+        let allocator = Allocator::default();
+        let source = r#"<App {...{ key: 0 }} xlink:key="a" key="b" muted data-id={id} key="c" />"#;
+        let ret = Parser::new(&allocator, source, SourceType::jsx()).parse();
+        let Some(Statement::ExpressionStatement(stmt)) = ret.program.body.first() else {
+            unreachable!()
+        };
+        let Expression::JSXElement(element) = &stmt.expression else { unreachable!() };
+        let opening = &element.opening_element;
+
+        // The first matching attribute, ignoring spreads and namespaced names.
+        let key = get_jsx_attribute(opening, "key").unwrap();
+        assert_eq!(&source[key.span.start as usize..key.span.end as usize], r#"key="b""#);
+        assert!(matches!(
+            get_jsx_attribute_value(opening, "key"),
+            Some(JSXAttributeValue::StringLiteral(lit)) if lit.value == "b"
+        ));
+
+        assert!(get_jsx_attribute(opening, "muted").is_some());
+        assert!(get_jsx_attribute_value(opening, "muted").is_none());
+
+        assert!(matches!(
+            get_jsx_attribute_value(opening, "data-id"),
+            Some(JSXAttributeValue::ExpressionContainer(_))
+        ));
+
+        assert!(get_jsx_attribute(opening, "Key").is_none());
+        assert!(get_jsx_attribute(opening, "id").is_none());
+    }
+
+    #[test]
+    fn test_get_jsx_element_name() {
+        let allocator = Allocator::default();
+        let source = "<><div /><Video /><Foo.Video /><A.B.Video /><this.Video /><svg:video /></>";
+        let ret = Parser::new(&allocator, source, SourceType::jsx()).parse();
+        let Some(Statement::ExpressionStatement(stmt)) = ret.program.body.first() else {
+            unreachable!()
+        };
+        let Expression::JSXFragment(fragment) = &stmt.expression else { unreachable!() };
+        let names = fragment
+            .children
+            .iter()
+            .map(|child| {
+                let JSXChild::Element(element) = child else { unreachable!() };
+                get_jsx_element_name(&element.opening_element.name)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, ["div", "Video", "Foo.Video", "A.B.Video", "this.Video", "svg:video"]);
+        // Plain names are borrowed from the AST.
+        assert!(matches!(names[0], Cow::Borrowed("div")));
+        assert!(matches!(names[1], Cow::Borrowed("Video")));
+    }
+}
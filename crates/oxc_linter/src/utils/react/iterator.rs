@@ -0,0 +1,388 @@
+//! Detection of JSX elements created in arrays and in the callbacks of iterator methods,
+//! shared by `react/jsx-key` and `react/no-array-index-key`.
+
+use oxc_ast::{
+    AstKind, AstType,
+    ast::{
+        Argument, BindingPatternKind, CallExpression, Expression, FormalParameters,
+        LogicalOperator, MemberExpression,
+    },
+};
+use oxc_semantic::{AstNode, NodeId, SymbolId};
+use oxc_span::{CompactStr, GetSpan, Span};
+use serde::Deserialize;
+
+use crate::{LintContext, utils::is_import};
+
+/// Iterator methods whose callback returns elements of the resulting array.
+const TARGET_METHODS: [&str; 3] = ["flatMap", "from", "map"];
+
+/// Iterator methods which pass the index of the element to their callback, and the position of
+/// the index parameter in the callback's parameters.
+const INDEX_PARAM_METHODS: [(&str, usize); 11] = [
+    ("every", 1),
+    ("filter", 1),
+    ("find", 1),
+    ("findIndex", 1),
+    ("flatMap", 1),
+    ("forEach", 1),
+    ("from", 1),
+    ("map", 1),
+    ("some", 1),
+    // `things.reduce((collection, thing, index) => ..., [])`
+    ("reduce", 2),
+    ("reduceRight", 2),
+];
+
+/// Position of the callback in the arguments of iterator method `method`.
+/// `1` for `Array.from(items, callback)`, `0` otherwise.
+fn callback_argument_index(method: &str) -> usize {
+    usize::from(method == "from")
+}
+
+/// A method or function whose callback returns elements of the resulting array, in addition to
+/// [`TARGET_METHODS`], e.g. `eachElement` in `items.eachElement(fn)` or `eachElement(items, fn)`.
+///
+/// Configured as either a name, with the callback as the first argument, or as
+/// `{ "name": "map", "callbackIndex": 1 }` (for `_.map(items, fn)`).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "IteratorMethodConfig")]
+pub struct IteratorMethod {
+    pub name: CompactStr,
+    pub callback_index: usize,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IteratorMethodConfig {
+    Name(CompactStr),
+    #[serde(rename_all = "camelCase")]
+    WithCallbackIndex {
+        name: CompactStr,
+        #[serde(default)]
+        callback_index: usize,
+    },
+}
+
+impl From<IteratorMethodConfig> for IteratorMethod {
+    fn from(config: IteratorMethodConfig) -> Self {
+        match config {
+            IteratorMethodConfig::Name(name) => Self { name, callback_index: 0 },
+            IteratorMethodConfig::WithCallbackIndex { name, callback_index } => {
+                Self { name, callback_index }
+            }
+        }
+    }
+}
+
+/// Whether the argument at `index` of `call` is `argument`.
+fn is_argument_at(call: &CallExpression<'_>, index: usize, argument: &Argument<'_>) -> bool {
+    call.arguments.get(index).is_some_and(|arg| arg.span() == argument.span())
+}
+
+fn is_to_array(call: &CallExpression<'_>) -> bool {
+    call.callee_name().is_some_and(|subject| subject == "toArray")
+}
+
+fn is_children<'a, 'b>(call: &'b CallExpression<'a>, ctx: &'b LintContext<'a>) -> bool {
+    const REACT: &str = "React";
+    const CHILDREN: &str = "Children";
+
+    let Some(member) = call.callee.as_member_expression() else { return false };
+
+    if let Expression::Identifier(ident) = member.object() {
+        return is_import(ctx, ident.name.as_str(), CHILDREN, REACT);
+    }
+
+    let Some(inner_member) = member.object().get_inner_expression().as_member_expression() else {
+        return false;
+    };
+
+    let Some(ident) = inner_member.object().get_identifier_reference() else { return false };
+
+    let Some(local_name) = inner_member.static_property_name() else { return false };
+
+    is_import(ctx, ident.name.as_str(), REACT, REACT) && local_name == CHILDREN
+}
+
+/// Whether `node` is inside a `React.Children.toArray()` call, which assigns keys to its elements.
+pub fn is_within_children_to_array<'a, 'b>(
+    node: &'b AstNode<'a>,
+    ctx: &'b LintContext<'a>,
+) -> bool {
+    let parents_iter = ctx.nodes().ancestors(node.id()).skip(1);
+    parents_iter
+        .filter_map(|parent_node| parent_node.kind().as_call_expression())
+        .any(|parent_call| is_children(parent_call, ctx) && is_to_array(parent_call))
+}
+
+/// Where a JSX element which needs a `key` was found.
+pub enum InsideArrayOrIterator {
+    /// An element of an array literal.
+    Array,
+    /// Returned from the callback of an iterator method. Contains the span of the method name.
+    Iterator(Span),
+}
+
+/// Why [`is_in_array_or_iter`] found that a node is not in an array or an iterator: the kind of
+/// the ancestor where the search stopped, and the reason.
+#[derive(Debug, Clone, Copy)]
+pub struct NotInArrayOrIterator {
+    pub kind: AstType,
+    pub reason: &'static str,
+}
+
+/// Find whether `node` is an element of an array literal, or is returned from the callback of an
+/// iterator method such as `.map()`.
+///
+/// If `accept_reduce` is `true`, an array literal returned from a `.reduce()` callback is treated
+/// like the result of an iterator callback, e.g. `[...acc, <Item />]`. Elements returned directly
+/// from a `.reduce()` callback are not.
+///
+/// `additional_methods` are also treated as iterator methods, whether called as a method or as a
+/// plain function.
+///
+/// If `visited` is given, the kinds of the ancestors walked are pushed to it, for tracing.
+pub fn is_in_array_or_iter<'a, 'b>(
+    node: &'b AstNode<'a>,
+    ctx: &'b LintContext<'a>,
+    accept_reduce: bool,
+    additional_methods: &[IteratorMethod],
+    mut visited: Option<&mut Vec<AstType>>,
+) -> Result<InsideArrayOrIterator, NotInArrayOrIterator> {
+    let is_array = matches!(node.kind(), AstKind::ArrayExpression(_));
+    // Functions and arrays outside the function containing `node` are not considered.
+    let containing_function = ctx.enclosing_function(node.id()).map(AstNode::id);
+    let mut node = node;
+
+    let mut is_explicit_return = false;
+    let mut argument = None;
+
+    while !matches!(node.kind(), AstKind::Program(_)) {
+        let parent = ctx.nodes().parent_node(node.id());
+        if let Some(visited) = visited.as_deref_mut() {
+            visited.push(parent.kind().ty());
+        }
+        match parent.kind() {
+            AstKind::ArrowFunctionExpression(arrow_expr) => {
+                if !is_explicit_return && !arrow_expr.expression {
+                    return bail(parent, "not returned from arrow function");
+                }
+
+                if let AstKind::ObjectProperty(_) = ctx.nodes().parent_kind(parent.id()) {
+                    return bail(parent, "object property value");
+                }
+                if Some(parent.id()) != containing_function {
+                    return bail(parent, "outside containing function");
+                }
+            }
+            AstKind::Function(_) => {
+                if !is_explicit_return {
+                    return bail(parent, "not returned from function");
+                }
+                if let AstKind::ObjectProperty(_) = ctx.nodes().parent_kind(parent.id()) {
+                    return bail(parent, "object property value");
+                }
+                if Some(parent.id()) != containing_function {
+                    return bail(parent, "outside containing function");
+                }
+            }
+            AstKind::ArrayExpression(_) => {
+                if ctx.enclosing_function(parent.id()).map(AstNode::id) != containing_function {
+                    return bail(parent, "outside containing function");
+                }
+
+                // Keep walking through directly nested arrays (e.g. `[[<A />]].flat()`),
+                // so the element is reported once, for the outermost array.
+                if !matches!(ctx.nodes().parent_kind(parent.id()), AstKind::ArrayExpression(_)) {
+                    // An array returned from a `flatMap` callback is flattened into the result,
+                    // so each of its elements is an element of the iterator. The same goes for
+                    // an array accumulated by a `reduce` callback.
+                    if let Ok(InsideArrayOrIterator::Iterator(span)) =
+                        is_in_array_or_iter(parent, ctx, accept_reduce, additional_methods, visited)
+                    {
+                        if matches!(ctx.source_range(span), "flatMap" | "reduce") {
+                            return Ok(InsideArrayOrIterator::Iterator(span));
+                        }
+                    }
+                    return Ok(InsideArrayOrIterator::Array);
+                }
+            }
+            AstKind::CallExpression(v) => {
+                let callee = &v.callee.without_parentheses();
+                let method = match callee {
+                    Expression::Identifier(ident) => Some((ident.span, ident.name.as_str(), false)),
+                    _ => callee
+                        .as_member_expression()
+                        .and_then(MemberExpression::static_property_info)
+                        .map(|(span, name)| (span, name, true)),
+                };
+
+                if let (Some((span, ident, is_member)), Some(argument)) = (method, argument) {
+                    let is_builtin = is_member
+                        && (TARGET_METHODS.contains(&ident)
+                            || (accept_reduce && is_array && ident == "reduce"))
+                        && is_argument_at(v, callback_argument_index(ident), argument);
+                    let is_additional = additional_methods.iter().any(|method| {
+                        method.name == ident && is_argument_at(v, method.callback_index, argument)
+                    });
+                    if is_builtin || is_additional {
+                        return Ok(InsideArrayOrIterator::Iterator(span));
+                    }
+                }
+
+                return bail(parent, "not the callback of an iterator method");
+            }
+            AstKind::JSXElement(_)
+            | AstKind::JSXOpeningElement(_)
+            | AstKind::ObjectProperty(_)
+            | AstKind::JSXFragment(_) => return bail(parent, "not an array element"),
+            AstKind::ReturnStatement(_) => {
+                is_explicit_return = true;
+            }
+            // Only the branches of a conditional can be its value, at any depth of nesting.
+            AstKind::ConditionalExpression(conditional) => {
+                if conditional.test.span() == node.kind().span() {
+                    return bail(parent, "test of conditional expression");
+                }
+            }
+            // Either operand of `||` and `??` can be the value. The left operand of `&&` is only
+            // the value when it is falsy, which an element never is.
+            AstKind::LogicalExpression(logical) => {
+                if logical.operator == LogicalOperator::And
+                    && logical.left.span() == node.kind().span()
+                {
+                    return bail(parent, "left operand of `&&`");
+                }
+            }
+            // Only the last expression of a sequence is its value.
+            AstKind::SequenceExpression(sequence) => {
+                if sequence.expressions.last().is_none_or(|last| last.span() != node.kind().span())
+                {
+                    return bail(parent, "discarded by sequence expression");
+                }
+            }
+            // An awaited element is consumed by the `await`. It's only rendered if the result
+            // of the `await` is, e.g. `items.map(async (x) => await (<Item />))`.
+            AstKind::AwaitExpression(_) => {
+                if !is_rendered(parent.id(), ctx, false) {
+                    return bail(parent, "awaited value is not rendered");
+                }
+            }
+            AstKind::Argument(arg) => {
+                argument = Some(arg);
+            }
+            _ => {}
+        }
+        node = parent;
+    }
+
+    bail(node, "reached program")
+}
+
+fn bail(
+    node: &AstNode,
+    reason: &'static str,
+) -> Result<InsideArrayOrIterator, NotInArrayOrIterator> {
+    Err(NotInArrayOrIterator { kind: node.kind().ty(), reason })
+}
+
+/// Whether the value of the expression `node_id` flows into render output: it is used as a JSX
+/// child or attribute value, or returned from a function.
+///
+/// If `follow_variables` is `true`, a value assigned to a variable is rendered if any reference
+/// to that variable is.
+pub fn is_rendered(node_id: NodeId, ctx: &LintContext<'_>, follow_variables: bool) -> bool {
+    for parent in ctx.nodes().ancestors(node_id) {
+        match parent.kind() {
+            AstKind::ParenthesizedExpression(_)
+            | AstKind::TSAsExpression(_)
+            | AstKind::TSSatisfiesExpression(_)
+            | AstKind::TSNonNullExpression(_)
+            | AstKind::TSTypeAssertion(_)
+            | AstKind::ConditionalExpression(_)
+            | AstKind::LogicalExpression(_) => {}
+            AstKind::JSXExpressionContainer(_) | AstKind::ReturnStatement(_) => return true,
+            AstKind::Argument(arg) => return is_render_call_argument(arg, parent.id(), ctx),
+            // Expression body of an arrow function
+            AstKind::ExpressionStatement(_) => {
+                let body = ctx.nodes().parent_node(parent.id());
+                return matches!(body.kind(), AstKind::FunctionBody(_))
+                    && matches!(
+                        ctx.nodes().parent_kind(body.id()),
+                        AstKind::ArrowFunctionExpression(arrow) if arrow.expression
+                    );
+            }
+            AstKind::VariableDeclarator(decl) if follow_variables => {
+                let BindingPatternKind::BindingIdentifier(ident) = &decl.id.kind else {
+                    return false;
+                };
+                return ctx
+                    .symbol_references(ident.symbol_id())
+                    .any(|reference| is_rendered(reference.node_id(), ctx, false));
+            }
+            _ => return false,
+        }
+    }
+    false
+}
+
+/// Whether `arg` is the children argument of a React DOM render call: `render(children, el)`,
+/// `root.render(children)`, `hydrate(children, el)` or `hydrateRoot(el, children)`.
+fn is_render_call_argument(arg: &Argument, arg_node_id: NodeId, ctx: &LintContext<'_>) -> bool {
+    let AstKind::CallExpression(call) = ctx.nodes().parent_kind(arg_node_id) else {
+        return false;
+    };
+    let name = match call.callee.without_parentheses() {
+        Expression::Identifier(ident) => Some(ident.name.as_str()),
+        callee => callee.as_member_expression().and_then(MemberExpression::static_property_name),
+    };
+    let index = match name {
+        Some("render" | "hydrate") => 0,
+        Some("hydrateRoot") => 1,
+        _ => return false,
+    };
+    call.arguments.get(index).is_some_and(|children| children.span() == arg.span())
+}
+
+/// If `symbol_id` is the index parameter of a callback passed to an iterator method, e.g. `i` in
+/// `items.map((item, i) => ...)`, returns the callback's parameters and the position of the index
+/// parameter in them.
+pub fn iterator_index_param<'a>(
+    symbol_id: SymbolId,
+    ctx: &LintContext<'a>,
+) -> Option<(&'a FormalParameters<'a>, usize)> {
+    let nodes = ctx.nodes();
+    let param_node = nodes.get_node(ctx.scoping().symbol_declaration(symbol_id));
+    let AstKind::FormalParameter(param) = param_node.kind() else {
+        return None;
+    };
+    if !matches!(&param.pattern.kind, BindingPatternKind::BindingIdentifier(ident) if ident.symbol_id() == symbol_id)
+    {
+        return None;
+    }
+
+    let params_node = nodes.parent_node(param_node.id());
+    let AstKind::FormalParameters(params) = params_node.kind() else {
+        return None;
+    };
+    let position = params.items.iter().position(|item| item.span == param.span)?;
+
+    let callback_node = nodes.parent_node(params_node.id());
+    if !matches!(callback_node.kind(), AstKind::ArrowFunctionExpression(_) | AstKind::Function(_)) {
+        return None;
+    }
+    let argument_node = nodes.parent_node(callback_node.id());
+    let AstKind::Argument(argument) = argument_node.kind() else {
+        return None;
+    };
+    let AstKind::CallExpression(call) = nodes.parent_kind(argument_node.id()) else {
+        return None;
+    };
+
+    let method =
+        call.callee.without_parentheses().as_member_expression()?.static_property_name()?;
+    let &(_, index_position) = INDEX_PARAM_METHODS.iter().find(|(name, _)| *name == method)?;
+    let is_callback = is_argument_at(call, callback_argument_index(method), argument);
+    (is_callback && position == index_position).then_some((params, index_position))
+}
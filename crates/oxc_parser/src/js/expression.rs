@@ -1385,9 +1385,13 @@ impl<'a> ParserImpl<'a> {
             self.error(diagnostics::await_expression(self.cur_token().span()));
         }
         self.bump_any();
-        let argument = self.context(Context::Await, Context::empty(), |p| {
+        let has_pure_comment = self.lexer.trivia_builder.previous_token_has_pure_comment();
+        let mut argument = self.context(Context::Await, Context::empty(), |p| {
             p.parse_simple_unary_expression(lhs_span)
         });
+        if has_pure_comment {
+            Self::set_pure_on_call_or_new_expr(&mut argument);
+        }
         self.ast.expression_await(self.end_span(span), argument)
     }
 
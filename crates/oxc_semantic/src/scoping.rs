@@ -370,6 +370,15 @@ impl Scoping {
         });
     }
 
+    /// Reserve capacity for at least `additional` more references resolved to `symbol_id`.
+    ///
+    /// Does not reserve capacity in the references table itself. Use [`Scoping::reserve`] for that.
+    pub fn reserve_resolved_references(&mut self, symbol_id: SymbolId, additional: usize) {
+        self.cell.with_dependent_mut(|_allocator, cell| {
+            cell.resolved_references[symbol_id.index()].reserve(additional);
+        });
+    }
+
     /// Delete a reference.
     pub fn delete_reference(&mut self, reference_id: ReferenceId) {
         let Some(symbol_id) = self.get_reference(reference_id).symbol_id() else { return };
@@ -139,6 +139,9 @@ struct SuperMethodInfo<'a> {
 
 pub struct ArrowFunctionConverter<'a> {
     mode: ArrowFunctionConverterMode,
+    /// `spec` option of `transform-arrow-functions` plugin.
+    /// Keep `this` as is inside converted arrow functions, and bind the function with `.bind(this)`.
+    spec: bool,
     this_var_stack: SparseStack<BoundIdentifier<'a>>,
     arguments_var_stack: SparseStack<BoundIdentifier<'a>>,
     constructor_super_stack: NonEmptyStack<bool>,
@@ -159,9 +162,11 @@ impl ArrowFunctionConverter<'_> {
         } else {
             ArrowFunctionConverterMode::Disabled
         };
+        let spec = env.es2015.arrow_function.is_some_and(|options| options.spec);
         // `SparseStack`s are created with 1 empty entry, for `Program`
         Self {
             mode,
+            spec,
             this_var_stack: SparseStack::new(),
             arguments_var_stack: SparseStack::new(),
             constructor_super_stack: NonEmptyStack::new(false),
@@ -451,7 +456,8 @@ impl<'a> Traverse<'a, TransformState<'a>> for ArrowFunctionConverter<'a> {
                 unreachable!()
             };
 
-            *expr = Self::transform_arrow_function_expression(arrow_function_expr, ctx);
+            let func = Self::transform_arrow_function_expression(arrow_function_expr, ctx);
+            *expr = if self.spec { self.bind_this(func, ctx) } else { func };
         }
     }
 
@@ -498,6 +504,15 @@ impl<'a> ArrowFunctionConverter<'a> {
         self.mode == ArrowFunctionConverterMode::AsyncOnly
     }
 
+    /// Check if `this` inside non-async arrow functions can be kept as is.
+    ///
+    /// This is the case when arrow functions are not converted at all, or when they are
+    /// converted in `spec` mode, where the new function is bound with `.bind(this)`.
+    #[inline]
+    fn keeps_this_in_arrow(&self) -> bool {
+        self.is_async_only() || self.spec
+    }
+
     fn get_this_identifier(
         &mut self,
         span: Span,
@@ -590,7 +605,7 @@ impl<'a> ArrowFunctionConverter<'a> {
                 | Ancestor::StaticBlockBody(_) => return None,
                 // Arrow function
                 Ancestor::ArrowFunctionExpressionParams(func) => {
-                    return if self.is_async_only() && !*func.r#async() {
+                    return if self.keeps_this_in_arrow() && !*func.r#async() {
                         // Continue checking the parent to see if it's inside an async function.
                         continue;
                     } else {
@@ -598,7 +613,7 @@ impl<'a> ArrowFunctionConverter<'a> {
                     };
                 }
                 Ancestor::ArrowFunctionExpressionBody(func) => {
-                    return if self.is_async_only() && !*func.r#async() {
+                    return if self.keeps_this_in_arrow() && !*func.r#async() {
                         // Continue checking the parent to see if it's inside an async function.
                         continue;
                     } else {
@@ -666,6 +681,23 @@ impl<'a> ArrowFunctionConverter<'a> {
         )
     }
 
+    /// Bind a function converted from an arrow function to the outer `this`.
+    ///
+    /// `function() {}` -> `function() {}.bind(this)`
+    ///
+    /// If the outer `this` is itself inside an arrow function which is not bound
+    /// (an async arrow function), `_this` is used instead.
+    fn bind_this(&mut self, func: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let this = self
+            .get_this_identifier(SPAN, ctx)
+            .map_or_else(|| ctx.ast.expression_this(SPAN), Expression::Identifier);
+        let arguments = ctx.ast.vec1(Argument::from(this));
+        let property = ctx.ast.identifier_name(SPAN, "bind");
+        let callee =
+            Expression::from(ctx.ast.member_expression_static(SPAN, func, property, false));
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
     /// Check whether the given [`Ancestor`] is a class method-like node.
     fn is_class_method_like_ancestor(ancestor: Ancestor) -> bool {
         match ancestor {
@@ -64,6 +64,10 @@
 //!
 //! Unlike other "common" utilities, this one has no transformer. It adds imports to the program
 //! via `ModuleImports` transform.
+//!
+//! Before the program is traversed, [`HelperLoaderStore::collect_existing_helpers`] looks for
+//! helpers which are already loaded at top level of the program (e.g. when transforming output of
+//! a previous transform). Those bindings are reused, instead of adding a duplicate import.
 
 use std::{borrow::Cow, cell::RefCell};
 
@@ -73,9 +77,12 @@ use serde::Deserialize;
 use oxc_allocator::Vec as ArenaVec;
 use oxc_ast::{
     NONE,
-    ast::{Argument, CallExpression, Expression},
+    ast::{
+        Argument, BindingPatternKind, CallExpression, Expression, IdentifierReference,
+        ImportDeclarationSpecifier, ModuleExportName, Program, Statement, VariableDeclarator,
+    },
 };
-use oxc_semantic::{ReferenceFlags, SymbolFlags};
+use oxc_semantic::{ReferenceFlags, Scoping, SymbolFlags};
 use oxc_span::{Atom, SPAN, Span};
 use oxc_traverse::BoundIdentifier;
 
@@ -144,11 +151,13 @@ pub enum Helper {
     AsyncGeneratorDelegate,
     AsyncIterator,
     AsyncToGenerator,
+    CreateForOfIterator,
     ObjectSpread2,
     WrapAsyncGenerator,
     Extends,
     ObjectDestructuringEmpty,
     ObjectWithoutProperties,
+    ObjectWithoutPropertiesLoose,
     ToPropertyKey,
     DefineProperty,
     ClassPrivateFieldInitSpec,
@@ -177,11 +186,13 @@ impl Helper {
             Self::AsyncGeneratorDelegate => "asyncGeneratorDelegate",
             Self::AsyncIterator => "asyncIterator",
             Self::AsyncToGenerator => "asyncToGenerator",
+            Self::CreateForOfIterator => "createForOfIteratorHelper",
             Self::ObjectSpread2 => "objectSpread2",
             Self::WrapAsyncGenerator => "wrapAsyncGenerator",
             Self::Extends => "extends",
             Self::ObjectDestructuringEmpty => "objectDestructuringEmpty",
             Self::ObjectWithoutProperties => "objectWithoutProperties",
+            Self::ObjectWithoutPropertiesLoose => "objectWithoutPropertiesLoose",
             Self::ToPropertyKey => "toPropertyKey",
             Self::DefineProperty => "defineProperty",
             Self::ClassPrivateFieldInitSpec => "classPrivateFieldInitSpec",
@@ -209,24 +220,138 @@ impl Helper {
     }
 }
 
+/// Global variable which helpers are accessed from in [`HelperLoaderMode::External`] mode.
+const HELPER_VAR: &str = "babelHelpers";
+
 /// Stores the state of the helper loader in [`TransformCtx`].
 pub struct HelperLoaderStore<'a> {
     module_name: Cow<'static, str>,
     mode: HelperLoaderMode,
     /// Loaded helpers, determined what helpers are loaded and what imports should be added.
     loaded_helpers: RefCell<FxHashMap<Helper, BoundIdentifier<'a>>>,
+    /// Helpers already loaded in the program before it was transformed, keyed by helper name.
+    existing_helpers: RefCell<FxHashMap<Atom<'a>, BoundIdentifier<'a>>>,
     pub(crate) used_helpers: RefCell<FxHashMap<Helper, String>>,
 }
 
-impl HelperLoaderStore<'_> {
+impl<'a> HelperLoaderStore<'a> {
     pub fn new(options: &HelperLoaderOptions) -> Self {
         Self {
             module_name: options.module_name.clone(),
             mode: options.mode,
             loaded_helpers: RefCell::new(FxHashMap::default()),
+            existing_helpers: RefCell::new(FxHashMap::default()),
             used_helpers: RefCell::new(FxHashMap::default()),
         }
     }
+
+    /// Find helpers which are already loaded at top level of `program`, so they can be reused.
+    ///
+    /// Recognised forms are:
+    /// * Runtime mode: `import helperName from "<module_name>/helpers/helperName";`
+    ///   or `var helperName = require("<module_name>/helpers/helperName");`.
+    /// * External mode: `var helperName = babelHelpers.helperName;`.
+    ///
+    /// Bindings which are reassigned are not reused.
+    pub fn collect_existing_helpers(&self, program: &Program<'a>, scoping: &Scoping) {
+        let mut existing_helpers = self.existing_helpers.borrow_mut();
+        for stmt in &program.body {
+            match stmt {
+                Statement::ImportDeclaration(decl) => {
+                    if !matches!(self.mode, HelperLoaderMode::Runtime) || decl.import_kind.is_type()
+                    {
+                        continue;
+                    }
+                    let Some(helper_name) = self.runtime_helper_name(decl.source.value) else {
+                        continue;
+                    };
+                    let Some(specifiers) = &decl.specifiers else { continue };
+                    let [specifier] = specifiers.as_slice() else { continue };
+                    let local = match specifier {
+                        ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                            &specifier.local
+                        }
+                        ImportDeclarationSpecifier::ImportSpecifier(specifier)
+                            if specifier.import_kind.is_value()
+                                && matches!(
+                                    &specifier.imported,
+                                    ModuleExportName::IdentifierName(name)
+                                        if name.name == "default"
+                                ) =>
+                        {
+                            &specifier.local
+                        }
+                        _ => continue,
+                    };
+                    let symbol_id = local.symbol_id();
+                    existing_helpers
+                        .entry(helper_name)
+                        .or_insert_with(|| BoundIdentifier::new(local.name, symbol_id));
+                }
+                Statement::VariableDeclaration(decl) => {
+                    for declarator in &decl.declarations {
+                        if let Some((helper_name, binding)) =
+                            self.existing_helper_in_declarator(declarator, scoping)
+                        {
+                            existing_helpers.entry(helper_name).or_insert(binding);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn existing_helper_in_declarator(
+        &self,
+        declarator: &VariableDeclarator<'a>,
+        scoping: &Scoping,
+    ) -> Option<(Atom<'a>, BoundIdentifier<'a>)> {
+        let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+            return None;
+        };
+        let symbol_id = ident.symbol_id();
+        if scoping.symbol_is_mutated(symbol_id) {
+            return None;
+        }
+
+        let is_global = |ident: &IdentifierReference| {
+            scoping.get_reference(ident.reference_id()).symbol_id().is_none()
+        };
+        let helper_name = match (self.mode, declarator.init.as_ref()?) {
+            (HelperLoaderMode::Runtime, Expression::CallExpression(call)) => {
+                let Expression::Identifier(callee) = &call.callee else { return None };
+                if callee.name != "require" || !is_global(callee) {
+                    return None;
+                }
+                let [Argument::StringLiteral(source)] = call.arguments.as_slice() else {
+                    return None;
+                };
+                self.runtime_helper_name(source.value)?
+            }
+            (HelperLoaderMode::External, Expression::StaticMemberExpression(member)) => {
+                let Expression::Identifier(object) = &member.object else { return None };
+                if object.name != HELPER_VAR || !is_global(object) {
+                    return None;
+                }
+                member.property.name
+            }
+            _ => return None,
+        };
+        Some((helper_name, BoundIdentifier::new(ident.name, symbol_id)))
+    }
+
+    /// Get helper name from a runtime helper import source e.g. `@oxc-project/runtime/helpers/foo`.
+    fn runtime_helper_name(&self, source: Atom<'a>) -> Option<Atom<'a>> {
+        let helper_name =
+            source.as_str().strip_prefix(&*self.module_name)?.strip_prefix("/helpers/")?;
+        (!helper_name.is_empty() && !helper_name.contains('/')).then(|| Atom::from(helper_name))
+    }
+
+    /// Take binding of a helper which was already loaded before the program was transformed.
+    fn take_existing_helper(&self, helper: Helper) -> Option<BoundIdentifier<'a>> {
+        self.existing_helpers.borrow_mut().remove(helper.name())
+    }
 }
 
 // Public methods implemented directly on `TransformCtx`, as they need access to `TransformCtx::module_imports`.
@@ -267,9 +392,7 @@ impl<'a> TransformCtx<'a> {
             HelperLoaderMode::Runtime => {
                 helper_loader.transform_for_runtime_helper(helper, source, self, ctx)
             }
-            HelperLoaderMode::External => {
-                HelperLoaderStore::transform_for_external_helper(helper, ctx)
-            }
+            HelperLoaderMode::External => helper_loader.transform_for_external_helper(helper, ctx),
             HelperLoaderMode::Inline => {
                 unreachable!("Inline helpers are not supported yet");
             }
@@ -287,9 +410,10 @@ impl<'a> HelperLoaderStore<'a> {
         ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
         let mut loaded_helpers = self.loaded_helpers.borrow_mut();
-        let binding = loaded_helpers
-            .entry(helper)
-            .or_insert_with(|| Self::get_runtime_helper(helper, source, transform_ctx, ctx));
+        let binding = loaded_helpers.entry(helper).or_insert_with(|| {
+            self.take_existing_helper(helper)
+                .unwrap_or_else(|| Self::get_runtime_helper(helper, source, transform_ctx, ctx))
+        });
         binding.create_read_expression(ctx)
     }
 
@@ -318,8 +442,18 @@ impl<'a> HelperLoaderStore<'a> {
         ctx.ast.atom_from_strs_array([&self.module_name, "/helpers/", helper.name()])
     }
 
-    fn transform_for_external_helper(helper: Helper, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
-        static HELPER_VAR: &str = "babelHelpers";
+    fn transform_for_external_helper(
+        &self,
+        helper: Helper,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut loaded_helpers = self.loaded_helpers.borrow_mut();
+        if let Some(binding) = loaded_helpers.get(&helper) {
+            return binding.create_read_expression(ctx);
+        }
+        if let Some(binding) = self.take_existing_helper(helper) {
+            return loaded_helpers.entry(helper).or_insert(binding).create_read_expression(ctx);
+        }
 
         let symbol_id = ctx.scoping().find_binding(ctx.current_scope_id(), HELPER_VAR);
         let object =
@@ -122,7 +122,6 @@ pub struct CompilerAssumptions {
     pub set_public_class_fields: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub set_spread_properties: bool,
 
     #[serde(default)]
@@ -133,3 +132,38 @@ pub struct CompilerAssumptions {
     #[deprecated = "Not Implemented"]
     pub super_is_callable_constructor: bool,
 }
+
+impl CompilerAssumptions {
+    /// Names of the enabled assumptions, as spelled in Babel's config.
+    #[expect(deprecated)]
+    pub fn enabled_names(&self) -> Vec<&'static str> {
+        [
+            ("arrayLikeIsIterable", self.array_like_is_iterable),
+            ("constantReexports", self.constant_reexports),
+            ("constantSuper", self.constant_super),
+            ("enumerableModuleMeta", self.enumerable_module_meta),
+            ("ignoreFunctionLength", self.ignore_function_length),
+            ("ignoreToPrimitiveHint", self.ignore_to_primitive_hint),
+            ("iterableIsArray", self.iterable_is_array),
+            ("mutableTemplateObject", self.mutable_template_object),
+            ("noClassCalls", self.no_class_calls),
+            ("noDocumentAll", self.no_document_all),
+            ("noIncompleteNsImportDetection", self.no_incomplete_ns_import_detection),
+            ("noNewArrows", self.no_new_arrows),
+            ("noUninitializedPrivateFieldAccess", self.no_uninitialized_private_field_access),
+            ("objectRestNoSymbols", self.object_rest_no_symbols),
+            ("privateFieldsAsSymbols", self.private_fields_as_symbols),
+            ("privateFieldsAsProperties", self.private_fields_as_properties),
+            ("pureGetters", self.pure_getters),
+            ("setClassMethods", self.set_class_methods),
+            ("setComputedProperties", self.set_computed_properties),
+            ("setPublicClassFields", self.set_public_class_fields),
+            ("setSpreadProperties", self.set_spread_properties),
+            ("skipForOfIteratorClosing", self.skip_for_of_iterator_closing),
+            ("superIsCallableConstructor", self.super_is_callable_constructor),
+        ]
+        .into_iter()
+        .filter_map(|(name, enabled)| enabled.then_some(name))
+        .collect()
+    }
+}
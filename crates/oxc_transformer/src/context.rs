@@ -4,8 +4,11 @@ use std::{
     path::{Path, PathBuf},
 };
 
+use rustc_hash::FxHashMap;
+
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_span::SourceType;
+use oxc_semantic::ScopeId;
+use oxc_span::{SourceType, Span};
 
 use crate::{
     CompilerAssumptions, Module, TransformOptions,
@@ -14,6 +17,7 @@ use crate::{
         statement_injector::StatementInjectorStore, top_level_statements::TopLevelStatementsStore,
         var_declarations::VarDeclarationsStore,
     },
+    es2017::DecisionLogStore,
     state::TransformState,
 };
 
@@ -47,6 +51,11 @@ pub struct TransformCtx<'a> {
     pub statement_injector: StatementInjectorStore<'a>,
     /// Manage inserting statements at top of program globally
     pub top_level_statements: TopLevelStatementsStore<'a>,
+    /// Record how async functions are lowered
+    pub decision_log: DecisionLogStore,
+    /// Spans of the original `async` keyword of async functions lowered to generator functions,
+    /// keyed by the scope of the generator function
+    pub async_keyword_spans: RefCell<FxHashMap<ScopeId, Span>>,
 
     // State for multiple plugins interacting
     /// `true` if class properties plugin is enabled
@@ -72,6 +81,8 @@ impl TransformCtx<'_> {
             var_declarations: VarDeclarationsStore::new(),
             statement_injector: StatementInjectorStore::new(),
             top_level_statements: TopLevelStatementsStore::new(),
+            decision_log: DecisionLogStore::new(options.env.es2017.decision_log),
+            async_keyword_spans: RefCell::new(FxHashMap::default()),
             is_class_properties_plugin_enabled: options.env.es2022.class_properties.is_some(),
         }
     }
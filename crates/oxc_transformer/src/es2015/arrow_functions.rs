@@ -8,7 +8,8 @@
 //!
 //! Implementation is incomplete at present. Still TODO:
 //!
-//! * `spec` option.
+//! * `spec` option: only `.bind(this)` is implemented. The `newArrowCheck` runtime check and
+//!   naming of arrow functions are not.
 //! * Handle `arguments` in arrow functions.
 //! * Handle `new.target` in arrow functions.
 //! * Handle arrow function in function params (`function f(g = () => this) {}`).
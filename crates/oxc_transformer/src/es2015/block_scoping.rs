@@ -0,0 +1,886 @@
+//! ES2015 Block Scoping
+//!
+//! This plugin transforms `let` and `const` declarations to `var` declarations.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! let a = 1;
+//! {
+//!   let a = 2;
+//!   const b = a;
+//! }
+//!
+//! for (let i = 0; i < 3; i++) {
+//!   fns.push(() => i);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var a = 1;
+//! {
+//!   var _a = 2;
+//!   var b = _a;
+//! }
+//!
+//! var _loop = function (i) {
+//!   fns.push(() => i);
+//! };
+//! for (var i = 0; i < 3; i++) {
+//!   _loop(i);
+//! }
+//! ```
+//!
+//! ## Implementation
+//!
+//! Bindings declared with `let` or `const` in a block are moved to the enclosing function scope.
+//! When a binding would clash with a binding of the same name in an enclosing scope, or with a
+//! global, it is renamed. Renaming is decided when entering the block, before any references to
+//! the binding are visited, and applied to identifiers as they are visited.
+//!
+//! A loop body which captures a `let` or `const` binding declared in the loop in a closure is moved
+//! into a `_loop` function, called once per iteration, so each iteration still gets its own copy
+//! of the binding. Bindings declared in the loop head are passed as arguments. `continue` in the
+//! body becomes `return`.
+//!
+//! `let x;` inside a loop body becomes `var x = void 0;`, so `x` is reset on each iteration.
+//!
+//! ## Options
+//!
+//! ### `throwIfClosureRequired`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Report an error instead of moving a loop body into a function.
+//!
+//! ## Missing features
+//!
+//! * Temporal dead zone checks (`tdz` option), and `_readOnlyError` for assignments to `const`.
+//! * A loop body which needs to be moved into a function, but contains `break`, `return`, a
+//!   `continue` or `break` to an outer label, `yield`, `await`, `this`, `arguments`, `super`,
+//!   `new.target`, a `var` declaration, or an assignment to a binding declared in the loop head.
+//!   Such loops are reported as an error, and their `let` and `const` declarations are left as is.
+//!   Until this is implemented, the plugin is not enabled by `targets`, only by explicit options.
+//! * `let` and `const` declarations inserted by other plugins after this plugin has visited
+//!   the enclosing block are left as is.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-block-scoping>
+//! * `let` and `const` specification: <https://tc39.es/ecma262/#sec-let-and-const-declarations>
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Deserialize;
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{AstBuilder, NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ReferenceId, ScopeFlags, ScopeId, Scoping, SymbolFlags, SymbolId};
+use oxc_span::{Atom, GetSpan, SPAN, Span};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::is_multiple_statements_allowed,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct BlockScopingOptions {
+    /// Report an error instead of moving a loop body into a function.
+    pub throw_if_closure_required: bool,
+}
+
+pub struct BlockScoping<'a, 'ctx> {
+    options: BlockScopingOptions,
+    ctx: &'ctx TransformCtx<'a>,
+    /// Bindings which were declared with `let` or `const`
+    converted: FxHashSet<SymbolId>,
+    /// Bindings which have been given a new name, to be applied to their identifiers
+    renamed: FxHashMap<SymbolId, Atom<'a>>,
+    /// Span of the loop statement being skipped, because it cannot be transformed.
+    /// `let` and `const` declarations inside it are left as is.
+    skipped_loop: Option<Span>,
+}
+
+impl<'a, 'ctx> BlockScoping<'a, 'ctx> {
+    pub fn new(options: BlockScopingOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            options,
+            ctx,
+            converted: FxHashSet::default(),
+            renamed: FxHashMap::default(),
+            skipped_loop: None,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for BlockScoping<'a, '_> {
+    /// Check whether a loop can be transformed before any of its declarations are converted.
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.skipped_loop.is_some() {
+            return;
+        }
+        let Some((loop_stmt, label)) = Self::as_loop(stmt, ctx) else {
+            return;
+        };
+        if let Some(error) = self.check_loop(loop_stmt, label, ctx) {
+            self.ctx.error(error);
+            self.skipped_loop = Some(stmt.span());
+        }
+    }
+
+    fn enter_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_bindings(block.scope_id(), ctx);
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.hoist_bindings(stmt.scope_id(), ctx);
+    }
+
+    fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_bindings(stmt.scope_id(), ctx);
+    }
+
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_bindings(stmt.scope_id(), ctx);
+    }
+
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_bindings(stmt.scope_id(), ctx);
+    }
+
+    /// Convert `let` and `const` declarations to `var`.
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.skipped_loop.is_some()
+            || !matches!(decl.kind, VariableDeclarationKind::Let | VariableDeclarationKind::Const)
+        {
+            return;
+        }
+
+        let reset_each_iteration =
+            decl.kind == VariableDeclarationKind::Let && Self::is_in_loop_body(ctx);
+        decl.kind = VariableDeclarationKind::Var;
+        for declarator in &mut decl.declarations {
+            declarator.kind = VariableDeclarationKind::Var;
+            if reset_each_iteration && declarator.init.is_none() {
+                declarator.init = Some(ctx.ast.void_0(SPAN));
+            }
+            for ident in declarator.id.get_binding_identifiers() {
+                let symbol_id = ident.symbol_id();
+                let flags = ctx.scoping_mut().symbol_flags_mut(symbol_id);
+                flags.remove(SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable);
+                flags.insert(SymbolFlags::FunctionScopedVariable);
+                self.converted.insert(symbol_id);
+            }
+        }
+    }
+
+    fn enter_binding_identifier(
+        &mut self,
+        ident: &mut BindingIdentifier<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.renamed.is_empty() {
+            return;
+        }
+        if let Some(&name) = self.renamed.get(&ident.symbol_id()) {
+            ident.name = name;
+        }
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.renamed.is_empty() {
+            return;
+        }
+        let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+        if let Some(&name) = symbol_id.and_then(|symbol_id| self.renamed.get(&symbol_id)) {
+            ident.name = name;
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(span) = self.skipped_loop {
+            if span == stmt.span() && Self::as_loop(stmt, ctx).is_some() {
+                self.skipped_loop = None;
+            }
+            return;
+        }
+
+        // A labelled loop is transformed from its label, as `var _loop` has to be inserted
+        // before the label.
+        let (loop_stmt, label) = match &mut *stmt {
+            Statement::LabeledStatement(labeled) => {
+                let LabeledStatement { label, body, .. } = labeled.as_mut();
+                (body, Some(label.name))
+            }
+            _ if matches!(ctx.parent(), Ancestor::LabeledStatementBody(_)) => return,
+            _ => (&mut *stmt, None),
+        };
+
+        let Some(loop_declaration) = self.move_loop_body_into_function(loop_stmt, label, ctx)
+        else {
+            return;
+        };
+
+        if is_multiple_statements_allowed(ctx) {
+            self.ctx.statement_injector.insert_before(stmt, loop_declaration);
+        } else {
+            let scope_id = ctx.create_child_scope_of_current(ScopeFlags::empty());
+            let loop_stmt = stmt.take_in(ctx.ast);
+            *stmt = ctx.ast.statement_block_with_scope_id(
+                SPAN,
+                ctx.ast.vec_from_array([loop_declaration, loop_stmt]),
+                scope_id,
+            );
+        }
+    }
+}
+
+impl<'a> BlockScoping<'a, '_> {
+    /// Returns the loop of `stmt` and its label, if `stmt` is a loop, or a labelled loop.
+    ///
+    /// A labelled loop is handled from its label, so the loop itself is skipped.
+    fn as_loop<'s>(
+        stmt: &'s Statement<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<(&'s Statement<'a>, Option<Atom<'a>>)> {
+        let (loop_stmt, label) = match stmt {
+            Statement::LabeledStatement(labeled) => (&labeled.body, Some(labeled.label.name)),
+            _ if matches!(ctx.parent(), Ancestor::LabeledStatementBody(_)) => return None,
+            _ => (stmt, None),
+        };
+        matches!(
+            loop_stmt,
+            Statement::ForStatement(_)
+                | Statement::ForInStatement(_)
+                | Statement::ForOfStatement(_)
+                | Statement::WhileStatement(_)
+                | Statement::DoWhileStatement(_)
+        )
+        .then_some((loop_stmt, label))
+    }
+
+    /// Returns an error if the body of `loop_stmt` would need to be moved into a function,
+    /// but cannot be, or `throwIfClosureRequired` is set.
+    fn check_loop(
+        &self,
+        loop_stmt: &Statement<'a>,
+        label: Option<Atom<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<OxcDiagnostic> {
+        let (head, body) = match loop_stmt {
+            Statement::ForStatement(stmt) => match &stmt.init {
+                Some(ForStatementInit::VariableDeclaration(decl)) => (Some(&**decl), &stmt.body),
+                _ => (None, &stmt.body),
+            },
+            Statement::ForInStatement(stmt) => match &stmt.left {
+                ForStatementLeft::VariableDeclaration(decl) => (Some(&**decl), &stmt.body),
+                _ => (None, &stmt.body),
+            },
+            Statement::ForOfStatement(stmt) if !stmt.r#await => match &stmt.left {
+                ForStatementLeft::VariableDeclaration(decl) => (Some(&**decl), &stmt.body),
+                _ => (None, &stmt.body),
+            },
+            Statement::WhileStatement(stmt) => (None, &stmt.body),
+            Statement::DoWhileStatement(stmt) => (None, &stmt.body),
+            _ => return None,
+        };
+        let head_symbol_ids = self.head_symbol_ids(head, ctx.scoping());
+
+        let mut analyzer =
+            LoopBodyAnalyzer::new(&head_symbol_ids, &self.converted, label, ctx.scoping());
+        analyzer.visit_statement(body);
+        if !analyzer.requires_closure() {
+            return None;
+        }
+        if self.options.throw_if_closure_required {
+            return Some(
+                OxcDiagnostic::error(
+                    "Compiling let/const in this loop would add a closure (throwIfClosureRequired).",
+                )
+                .with_label(body.span()),
+            );
+        }
+        let (span, reason) = analyzer.unsupported?;
+        Some(
+            OxcDiagnostic::error(format!(
+                "Cannot transform a loop which captures a `let` or `const` binding \
+                 in a closure and contains {reason}."
+            ))
+            .with_label(span),
+        )
+    }
+
+    /// `let` and `const` bindings declared in the loop head `head`.
+    fn head_symbol_ids(
+        &self,
+        head: Option<&VariableDeclaration<'a>>,
+        scoping: &Scoping,
+    ) -> Vec<SymbolId> {
+        head.into_iter()
+            .flat_map(|decl| &decl.declarations)
+            .flat_map(|declarator| declarator.id.get_binding_identifiers())
+            .map(BindingIdentifier::symbol_id)
+            .filter(|&symbol_id| is_let_or_const(symbol_id, &self.converted, scoping))
+            .collect()
+    }
+
+    /// Move `let` and `const` bindings of a block scope to the enclosing function scope,
+    /// renaming any which would clash with another binding.
+    ///
+    /// Must be called before entering the scope, so that all references to renamed bindings
+    /// are visited afterwards.
+    fn hoist_bindings(&mut self, scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) {
+        if self.skipped_loop.is_some() {
+            return;
+        }
+        let var_scope_id = ctx.current_hoist_scope_id();
+        let symbol_ids = ctx
+            .scoping()
+            .get_bindings(scope_id)
+            .values()
+            .copied()
+            .filter(|&symbol_id| {
+                let flags = ctx.scoping().symbol_flags(symbol_id);
+                flags.intersects(SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable)
+                    && !flags.intersects(SymbolFlags::Function | SymbolFlags::Class)
+            })
+            .collect::<Vec<_>>();
+
+        for symbol_id in symbol_ids {
+            let name = ctx.ast.atom(ctx.scoping().symbol_name(symbol_id));
+            let name = if Self::is_name_taken(&name, scope_id, ctx.scoping()) {
+                let new_name = ctx.generate_uid_name(&name);
+                ctx.scoping_mut().rename_symbol(symbol_id, scope_id, &new_name);
+                self.renamed.insert(symbol_id, new_name);
+                new_name
+            } else {
+                name
+            };
+            ctx.scoping_mut().move_binding(scope_id, var_scope_id, &name);
+            ctx.scoping_mut().set_symbol_scope_id(symbol_id, var_scope_id);
+        }
+    }
+
+    /// Whether a binding named `name` in `scope_id` would shadow, or be shadowed by, another
+    /// binding once it is moved to the function scope.
+    fn is_name_taken(name: &str, scope_id: ScopeId, scoping: &Scoping) -> bool {
+        scoping
+            .scope_ancestors(scope_id)
+            .skip(1)
+            .any(|ancestor_id| scoping.scope_has_binding(ancestor_id, name))
+            || scoping.root_unresolved_references().contains_key(name)
+    }
+
+    /// Whether the current variable declaration is inside a loop body, in the same function.
+    fn is_in_loop_body(ctx: &TraverseCtx<'a>) -> bool {
+        if matches!(ctx.parent(), Ancestor::ForInStatementLeft(_) | Ancestor::ForOfStatementLeft(_))
+        {
+            return false;
+        }
+        for ancestor in ctx.ancestors() {
+            match ancestor {
+                Ancestor::ForStatementBody(_)
+                | Ancestor::ForInStatementBody(_)
+                | Ancestor::ForOfStatementBody(_)
+                | Ancestor::WhileStatementBody(_)
+                | Ancestor::DoWhileStatementBody(_) => return true,
+                Ancestor::FunctionBody(_)
+                | Ancestor::ArrowFunctionExpressionBody(_)
+                | Ancestor::StaticBlockBody(_) => return false,
+                _ => {}
+            }
+        }
+        false
+    }
+
+    /// Transform a loop whose body captures a binding declared in the loop in a closure:
+    ///
+    /// ```js
+    /// for (var i = 0; i < 3; i++) {
+    ///   fns.push(() => i);
+    /// }
+    /// ```
+    ///
+    /// to:
+    ///
+    /// ```js
+    /// for (var i = 0; i < 3; i++) {
+    ///   _loop(i);
+    /// }
+    /// ```
+    ///
+    /// and return the declaration of the function holding the loop body:
+    ///
+    /// ```js
+    /// var _loop = function (i) {
+    ///   fns.push(() => i);
+    /// };
+    /// ```
+    fn move_loop_body_into_function(
+        &mut self,
+        loop_stmt: &mut Statement<'a>,
+        label: Option<Atom<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Statement<'a>> {
+        let (head, body, loop_scope_id) = match loop_stmt {
+            Statement::ForStatement(stmt) => {
+                let scope_id = stmt.scope_id();
+                let ForStatement { init, body, .. } = stmt.as_mut();
+                let head = match init {
+                    Some(ForStatementInit::VariableDeclaration(decl)) => Some(&**decl),
+                    _ => None,
+                };
+                (head, body, Some(scope_id))
+            }
+            Statement::ForInStatement(stmt) => {
+                let scope_id = stmt.scope_id();
+                let ForInStatement { left, body, .. } = stmt.as_mut();
+                let head = match left {
+                    ForStatementLeft::VariableDeclaration(decl) => Some(&**decl),
+                    _ => None,
+                };
+                (head, body, Some(scope_id))
+            }
+            Statement::ForOfStatement(stmt) if !stmt.r#await => {
+                let scope_id = stmt.scope_id();
+                let ForOfStatement { left, body, .. } = stmt.as_mut();
+                let head = match left {
+                    ForStatementLeft::VariableDeclaration(decl) => Some(&**decl),
+                    _ => None,
+                };
+                (head, body, Some(scope_id))
+            }
+            Statement::WhileStatement(stmt) => (None, &mut stmt.body, None),
+            Statement::DoWhileStatement(stmt) => (None, &mut stmt.body, None),
+            _ => return None,
+        };
+        let head_symbol_ids = self.head_symbol_ids(head, ctx.scoping());
+
+        let mut analyzer =
+            LoopBodyAnalyzer::new(&head_symbol_ids, &self.converted, label, ctx.scoping());
+        analyzer.visit_statement(body);
+        // Loops which cannot be moved into a function were reported and skipped on entering
+        if !analyzer.requires_closure() || analyzer.unsupported.is_some() {
+            return None;
+        }
+        let LoopBodyAnalyzer { head_references, body_symbol_ids, has_continue, .. } = analyzer;
+
+        let current_scope_id = ctx.current_scope_id();
+        let body_parent_scope_id = loop_scope_id.unwrap_or(current_scope_id);
+
+        // The function reuses the scope of a block body. Otherwise a new scope is inserted
+        // between the loop and the scopes inside the body.
+        let (function_scope_id, mut statements) = match body.take_in(ctx.ast) {
+            Statement::BlockStatement(block) => {
+                let block = block.unbox();
+                let scope_id = block.scope_id();
+                let flags =
+                    ctx.scoping().get_new_scope_flags(ScopeFlags::Function, current_scope_id);
+                *ctx.scoping_mut().scope_flags_mut(scope_id) = flags;
+                ctx.scoping_mut().change_scope_parent_id(scope_id, Some(current_scope_id));
+                (scope_id, block.body)
+            }
+            stmt => {
+                let scope_id = ctx.insert_scope_below_statement_from_scope_id(
+                    &stmt,
+                    body_parent_scope_id,
+                    ScopeFlags::Function,
+                );
+                ctx.scoping_mut().change_scope_parent_id(scope_id, Some(current_scope_id));
+                (scope_id, ctx.ast.vec1(stmt))
+            }
+        };
+
+        // Bindings declared in the body are local to each call of the function
+        for symbol_id in body_symbol_ids {
+            let from_scope_id = ctx.scoping().symbol_scope_id(symbol_id);
+            let name = ctx.ast.atom(ctx.scoping().symbol_name(symbol_id));
+            ctx.scoping_mut().move_binding(from_scope_id, function_scope_id, &name);
+            ctx.scoping_mut().set_symbol_scope_id(symbol_id, function_scope_id);
+        }
+
+        // Bindings declared in the loop head are passed to the function as parameters
+        let mut params = ctx.ast.vec_with_capacity(head_symbol_ids.len());
+        let mut arguments = ctx.ast.vec_with_capacity(head_symbol_ids.len());
+        for &symbol_id in &head_symbol_ids {
+            let name = ctx.ast.atom(ctx.scoping().symbol_name(symbol_id));
+            let param =
+                ctx.generate_binding(name, function_scope_id, SymbolFlags::FunctionScopedVariable);
+            for &(reference_symbol_id, reference_id) in &head_references {
+                if reference_symbol_id == symbol_id {
+                    let scoping = ctx.scoping_mut();
+                    scoping.delete_resolved_reference(symbol_id, reference_id);
+                    scoping.get_reference_mut(reference_id).set_symbol_id(param.symbol_id);
+                    scoping.add_resolved_reference(param.symbol_id, reference_id);
+                }
+            }
+            params.push(ctx.ast.formal_parameter(
+                SPAN,
+                ctx.ast.vec(),
+                param.create_binding_pattern(ctx),
+                None,
+                false,
+                false,
+            ));
+            arguments.push(Argument::from(
+                BoundIdentifier::new(name, symbol_id).create_read_expression(ctx),
+            ));
+        }
+
+        if has_continue {
+            ContinueToReturn::new(label, ctx.ast).visit_statements(&mut statements);
+        }
+
+        let function = ctx.ast.alloc_function_with_scope_id(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            ctx.ast.alloc_formal_parameters(
+                SPAN,
+                FormalParameterKind::FormalParameter,
+                params,
+                NONE,
+            ),
+            NONE,
+            Some(ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), statements)),
+            function_scope_id,
+        );
+
+        // `var _loop` may end up inside the body of another loop which is moved into a function
+        let loop_function = ctx.generate_uid(
+            "loop",
+            ctx.current_hoist_scope_id(),
+            SymbolFlags::FunctionScopedVariable,
+        );
+        self.converted.insert(loop_function.symbol_id);
+
+        // `{ _loop(i); }`
+        let call = ctx.ast.expression_call(
+            SPAN,
+            loop_function.create_read_expression(ctx),
+            NONE,
+            arguments,
+            false,
+        );
+        let body_scope_id = ctx.create_child_scope(body_parent_scope_id, ScopeFlags::empty());
+        *body = ctx.ast.statement_block_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call)),
+            body_scope_id,
+        );
+
+        // `var _loop = function (i) { ... };`
+        Some(Statement::from(ctx.ast.declaration_variable(
+            SPAN,
+            VariableDeclarationKind::Var,
+            ctx.ast.vec1(ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                loop_function.create_binding_pattern(ctx),
+                Some(Expression::FunctionExpression(function)),
+                false,
+            )),
+            false,
+        )))
+    }
+}
+
+/// Finds out whether a loop body captures a binding declared in the loop in a closure,
+/// and whether the body can be moved into a function.
+struct LoopBodyAnalyzer<'a, 'b> {
+    head_symbol_ids: &'b [SymbolId],
+    converted: &'b FxHashSet<SymbolId>,
+    label: Option<Atom<'a>>,
+    scoping: &'b Scoping,
+    function_depth: u32,
+    /// Depth of functions which have their own `this`, which excludes arrow functions
+    this_depth: u32,
+    loop_depth: u32,
+    switch_depth: u32,
+    labels: Vec<Atom<'a>>,
+    /// Bindings referenced inside a closure
+    captured: FxHashSet<SymbolId>,
+    /// References to bindings declared in the loop head
+    head_references: Vec<(SymbolId, ReferenceId)>,
+    /// `let` and `const` bindings declared in the body, outside of any closure
+    body_symbol_ids: Vec<SymbolId>,
+    /// Bindings of `body_symbol_ids` declared in a nested loop, which moves them into a function
+    /// of its own if they are captured
+    nested_loop_symbol_ids: FxHashSet<SymbolId>,
+    has_continue: bool,
+    /// Span and description of the first construct which prevents moving the body into a function
+    unsupported: Option<(Span, &'static str)>,
+}
+
+impl<'a, 'b> LoopBodyAnalyzer<'a, 'b> {
+    fn new(
+        head_symbol_ids: &'b [SymbolId],
+        converted: &'b FxHashSet<SymbolId>,
+        label: Option<Atom<'a>>,
+        scoping: &'b Scoping,
+    ) -> Self {
+        Self {
+            head_symbol_ids,
+            converted,
+            label,
+            scoping,
+            function_depth: 0,
+            this_depth: 0,
+            loop_depth: 0,
+            switch_depth: 0,
+            labels: vec![],
+            captured: FxHashSet::default(),
+            head_references: vec![],
+            body_symbol_ids: vec![],
+            nested_loop_symbol_ids: FxHashSet::default(),
+            has_continue: false,
+            unsupported: None,
+        }
+    }
+
+    fn requires_closure(&self) -> bool {
+        self.head_symbol_ids
+            .iter()
+            .chain(
+                self.body_symbol_ids
+                    .iter()
+                    .filter(|symbol_id| !self.nested_loop_symbol_ids.contains(symbol_id)),
+            )
+            .any(|symbol_id| self.captured.contains(symbol_id))
+    }
+
+    fn unsupported(&mut self, span: Span, reason: &'static str) {
+        self.unsupported.get_or_insert((span, reason));
+    }
+}
+
+impl<'a> Visit<'a> for LoopBodyAnalyzer<'a, '_> {
+    fn visit_statement(&mut self, stmt: &Statement<'a>) {
+        if self.function_depth > 0 {
+            walk::walk_statement(self, stmt);
+            return;
+        }
+        match stmt {
+            Statement::ForOfStatement(for_of) if for_of.r#await => {
+                self.unsupported(for_of.span, "`for await`");
+            }
+            Statement::ForStatement(_)
+            | Statement::ForInStatement(_)
+            | Statement::ForOfStatement(_)
+            | Statement::WhileStatement(_)
+            | Statement::DoWhileStatement(_) => {
+                self.loop_depth += 1;
+                walk::walk_statement(self, stmt);
+                self.loop_depth -= 1;
+            }
+            Statement::SwitchStatement(_) => {
+                self.switch_depth += 1;
+                walk::walk_statement(self, stmt);
+                self.switch_depth -= 1;
+            }
+            Statement::LabeledStatement(labeled) => {
+                self.labels.push(labeled.label.name);
+                walk::walk_statement(self, stmt);
+                self.labels.pop();
+            }
+            Statement::BreakStatement(brk) => match &brk.label {
+                Some(label) if self.labels.contains(&label.name) => {}
+                Some(_) => self.unsupported(brk.span, "`break` to an outer label"),
+                None if self.loop_depth == 0 && self.switch_depth == 0 => {
+                    self.unsupported(brk.span, "`break`");
+                }
+                None => {}
+            },
+            Statement::ContinueStatement(cont) => match &cont.label {
+                Some(label) if self.labels.contains(&label.name) => {}
+                Some(label) if Some(label.name) == self.label => self.has_continue = true,
+                Some(_) => self.unsupported(cont.span, "`continue` to an outer label"),
+                None if self.loop_depth == 0 => self.has_continue = true,
+                None => {}
+            },
+            Statement::ReturnStatement(ret) => self.unsupported(ret.span, "`return`"),
+            _ => walk::walk_statement(self, stmt),
+        }
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        self.function_depth += 1;
+        self.this_depth += 1;
+        walk::walk_function(self, func, flags);
+        self.function_depth -= 1;
+        self.this_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        self.function_depth += 1;
+        walk::walk_arrow_function_expression(self, arrow);
+        self.function_depth -= 1;
+    }
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        self.function_depth += 1;
+        self.this_depth += 1;
+        walk::walk_class(self, class);
+        self.function_depth -= 1;
+        self.this_depth -= 1;
+    }
+
+    fn visit_this_expression(&mut self, this: &ThisExpression) {
+        if self.this_depth == 0 {
+            self.unsupported(this.span, "`this`");
+        }
+    }
+
+    fn visit_super(&mut self, sup: &Super) {
+        if self.this_depth == 0 {
+            self.unsupported(sup.span, "`super`");
+        }
+    }
+
+    fn visit_meta_property(&mut self, meta: &MetaProperty<'a>) {
+        if self.this_depth == 0 && meta.meta.name == "new" {
+            self.unsupported(meta.span, "`new.target`");
+        }
+    }
+
+    fn visit_yield_expression(&mut self, expr: &YieldExpression<'a>) {
+        if self.function_depth == 0 {
+            self.unsupported(expr.span, "`yield`");
+        }
+        walk::walk_yield_expression(self, expr);
+    }
+
+    fn visit_await_expression(&mut self, expr: &AwaitExpression<'a>) {
+        if self.function_depth == 0 {
+            self.unsupported(expr.span, "`await`");
+        }
+        walk::walk_await_expression(self, expr);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let reference = self.scoping.get_reference(ident.reference_id());
+        let Some(symbol_id) = reference.symbol_id() else {
+            if self.this_depth == 0 && ident.name == "arguments" {
+                self.unsupported(ident.span, "`arguments`");
+            }
+            return;
+        };
+        if self.head_symbol_ids.contains(&symbol_id) {
+            if reference.is_write() {
+                self.unsupported(
+                    ident.span,
+                    "an assignment to a binding declared in the loop head",
+                );
+            }
+            self.head_references.push((symbol_id, ident.reference_id()));
+        }
+        if self.function_depth > 0 {
+            self.captured.insert(symbol_id);
+        }
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        if self.function_depth == 0 {
+            for declarator in &decl.declarations {
+                for ident in declarator.id.get_binding_identifiers() {
+                    let symbol_id = ident.symbol_id();
+                    if is_let_or_const(symbol_id, self.converted, self.scoping) {
+                        self.body_symbol_ids.push(symbol_id);
+                        if self.loop_depth > 0 {
+                            self.nested_loop_symbol_ids.insert(symbol_id);
+                        }
+                    } else {
+                        self.unsupported(decl.span, "a `var` declaration");
+                    }
+                }
+            }
+        }
+        walk::walk_variable_declaration(self, decl);
+    }
+}
+
+/// Whether `symbol_id` was declared with `let` or `const`, whether or not its declaration has been
+/// converted to `var` yet.
+fn is_let_or_const(
+    symbol_id: SymbolId,
+    converted: &FxHashSet<SymbolId>,
+    scoping: &Scoping,
+) -> bool {
+    if converted.contains(&symbol_id) {
+        return true;
+    }
+    let flags = scoping.symbol_flags(symbol_id);
+    flags.intersects(SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable)
+        && !flags.intersects(SymbolFlags::Function | SymbolFlags::Class)
+}
+
+/// Replaces `continue` statements which target the loop with `return`,
+/// once the loop body has been moved into a function.
+struct ContinueToReturn<'a> {
+    label: Option<Atom<'a>>,
+    loop_depth: u32,
+    ast: AstBuilder<'a>,
+}
+
+impl<'a> ContinueToReturn<'a> {
+    fn new(label: Option<Atom<'a>>, ast: AstBuilder<'a>) -> Self {
+        Self { label, loop_depth: 0, ast }
+    }
+}
+
+impl<'a> VisitMut<'a> for ContinueToReturn<'a> {
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        match stmt {
+            Statement::ContinueStatement(cont) => {
+                let targets_loop = match &cont.label {
+                    Some(label) => Some(label.name) == self.label,
+                    None => self.loop_depth == 0,
+                };
+                if targets_loop {
+                    *stmt = self.ast.statement_return(cont.span, None);
+                }
+            }
+            Statement::ForStatement(_)
+            | Statement::ForInStatement(_)
+            | Statement::ForOfStatement(_)
+            | Statement::WhileStatement(_)
+            | Statement::DoWhileStatement(_) => {
+                self.loop_depth += 1;
+                walk_mut::walk_statement(self, stmt);
+                self.loop_depth -= 1;
+            }
+            _ => walk_mut::walk_statement(self, stmt),
+        }
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &mut ArrowFunctionExpression<'a>) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
@@ -0,0 +1,438 @@
+//! ES2015 For Of
+//!
+//! This plugin transforms `for...of` loops to `for` loops.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! for (const [key, value] of [[1, 2], [3, 4]]) {
+//!   console.log(key, value);
+//! }
+//!
+//! for (const item of items) {
+//!   console.log(item);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! for (var _i = 0, _arr = [[1, 2], [3, 4]]; _i < _arr.length; _i++) {
+//!   const [key, value] = _arr[_i];
+//!   {
+//!     console.log(key, value);
+//!   }
+//! }
+//!
+//! var _iterator = babelHelpers.createForOfIteratorHelper(items), _step;
+//! try {
+//!   for (_iterator.s(); !(_step = _iterator.n()).done;) {
+//!     const item = _step.value;
+//!     {
+//!       console.log(item);
+//!     }
+//!   }
+//! } catch (err) {
+//!   _iterator.e(err);
+//! } finally {
+//!   _iterator.f();
+//! }
+//! ```
+//!
+//! Array literals are always iterated by index, the same as Babel does.
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Iterate over every iterable by index, as if it was an array. The output is smaller and faster,
+//! but only works for arrays and array-like objects, not for other iterables such as `Map`, `Set`
+//! or generators.
+//!
+//! ## Missing features
+//!
+//! * `assumeArray` and `allowArrayLike` options.
+//! * A loop with more than one label (`a: b: for (x of y) {}`) is only correct with `loose`,
+//!   as the outer label ends up on the `try` statement instead of the loop.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-for-of>
+//! * `for...of` specification: <https://tc39.es/ecma262/#sec-for-in-and-for-of-statements>
+
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::is_multiple_statements_allowed,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ForOfOptions {
+    /// Iterate over every iterable by index, as if it was an array.
+    pub loose: bool,
+}
+
+pub struct ForOf<'a, 'ctx> {
+    options: ForOfOptions,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> ForOf<'a, 'ctx> {
+    pub fn new(options: ForOfOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { options, ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ForOf<'a, '_> {
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        // A labelled loop is transformed from its label, as the label has to stay on the `for` loop
+        // when the loop is wrapped in a `try` statement.
+        let (for_of, label) = match stmt {
+            Statement::LabeledStatement(labeled) => {
+                let LabeledStatement { label, body, .. } = labeled.as_mut();
+                let Statement::ForOfStatement(for_of) = body else { return };
+                (for_of, Some(label.clone()))
+            }
+            Statement::ForOfStatement(for_of) => (for_of, None),
+            _ => return,
+        };
+
+        if for_of.r#await {
+            return;
+        }
+
+        *stmt = if self.options.loose || matches!(for_of.right, Expression::ArrayExpression(_)) {
+            let for_statement = Self::transform_to_indexed_loop(for_of, ctx);
+            Self::wrap_with_label(for_statement, label, ctx)
+        } else {
+            self.transform_to_iterator_loop(for_of, label, ctx)
+        };
+    }
+}
+
+impl<'a> ForOf<'a, '_> {
+    /// Transform `for (const x of arr) {}` to:
+    ///
+    /// ```js
+    /// for (var _i = 0, _arr = arr; _i < _arr.length; _i++) {
+    ///   const x = _arr[_i];
+    /// }
+    /// ```
+    fn transform_to_indexed_loop(
+        for_of: &mut ForOfStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let index = ctx.generate_uid_in_current_scope("i", SymbolFlags::FunctionScopedVariable);
+        let array = ctx.generate_uid_in_current_scope("arr", SymbolFlags::FunctionScopedVariable);
+        let for_scope_id = ctx.create_child_scope_of_current(ScopeFlags::empty());
+
+        // `var _i = 0, _arr = arr`
+        let init = ctx.ast.for_statement_init_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            ctx.ast.vec_from_array([
+                ctx.ast.variable_declarator(
+                    SPAN,
+                    VariableDeclarationKind::Var,
+                    index.create_binding_pattern(ctx),
+                    Some(ctx.ast.expression_numeric_literal(SPAN, 0.0, None, NumberBase::Decimal)),
+                    false,
+                ),
+                ctx.ast.variable_declarator(
+                    SPAN,
+                    VariableDeclarationKind::Var,
+                    array.create_binding_pattern(ctx),
+                    Some(for_of.right.take_in(ctx.ast)),
+                    false,
+                ),
+            ]),
+            false,
+        );
+        // `_i < _arr.length`
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            index.create_read_expression(ctx),
+            BinaryOperator::LessThan,
+            Expression::from(ctx.ast.member_expression_static(
+                SPAN,
+                array.create_read_expression(ctx),
+                ctx.ast.identifier_name(SPAN, "length"),
+                false,
+            )),
+        );
+        // `_i++`
+        let update = ctx.ast.expression_update(
+            SPAN,
+            UpdateOperator::Increment,
+            false,
+            index.create_read_write_simple_target(ctx),
+        );
+        // `_arr[_i]`
+        let value = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            array.create_read_expression(ctx),
+            index.create_read_expression(ctx),
+            false,
+        ));
+        let body = Self::create_loop_body(for_of, value, for_scope_id, ctx);
+
+        ctx.ast.statement_for_with_scope_id(
+            for_of.span,
+            Some(init),
+            Some(test),
+            Some(update),
+            body,
+            for_scope_id,
+        )
+    }
+
+    /// Transform `for (const x of iterable) {}` to:
+    ///
+    /// ```js
+    /// var _iterator = _createForOfIteratorHelper(iterable), _step;
+    /// try {
+    ///   for (_iterator.s(); !(_step = _iterator.n()).done;) {
+    ///     const x = _step.value;
+    ///   }
+    /// } catch (err) {
+    ///   _iterator.e(err);
+    /// } finally {
+    ///   _iterator.f();
+    /// }
+    /// ```
+    ///
+    /// If the parent only allows a single statement (`if (x) for (y of z) {}`), the output is
+    /// wrapped in a block statement.
+    fn transform_to_iterator_loop(
+        &self,
+        for_of: &mut ForOfStatement<'a>,
+        label: Option<LabelIdentifier<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let allow_multiple_statements = is_multiple_statements_allowed(ctx);
+        let parent_scope_id = if allow_multiple_statements {
+            ctx.current_scope_id()
+        } else {
+            ctx.create_child_scope_of_current(ScopeFlags::empty())
+        };
+
+        let iterator =
+            ctx.generate_uid_in_current_scope("iterator", SymbolFlags::FunctionScopedVariable);
+        let step = ctx.generate_uid_in_current_scope("step", SymbolFlags::FunctionScopedVariable);
+
+        // `var _iterator = _createForOfIteratorHelper(iterable), _step;`
+        let iterable = for_of.right.take_in(ctx.ast);
+        let create_iterator = self.ctx.helper_call_expr(
+            Helper::CreateForOfIterator,
+            SPAN,
+            ctx.ast.vec1(Argument::from(iterable)),
+            ctx,
+        );
+        let iterator_declaration = Statement::from(ctx.ast.declaration_variable(
+            SPAN,
+            VariableDeclarationKind::Var,
+            ctx.ast.vec_from_array([
+                ctx.ast.variable_declarator(
+                    SPAN,
+                    VariableDeclarationKind::Var,
+                    iterator.create_binding_pattern(ctx),
+                    Some(create_iterator),
+                    false,
+                ),
+                ctx.ast.variable_declarator(
+                    SPAN,
+                    VariableDeclarationKind::Var,
+                    step.create_binding_pattern(ctx),
+                    None,
+                    false,
+                ),
+            ]),
+            false,
+        ));
+
+        let block = {
+            let block_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+            let for_scope_id = ctx.create_child_scope(block_scope_id, ScopeFlags::empty());
+
+            // `_iterator.s()`
+            let init = ForStatementInit::from(Self::create_iterator_call(
+                &iterator,
+                "s",
+                ctx.ast.vec(),
+                ctx,
+            ));
+            // `!(_step = _iterator.n()).done`
+            let next = Self::create_iterator_call(&iterator, "n", ctx.ast.vec(), ctx);
+            let test = ctx.ast.expression_unary(
+                SPAN,
+                UnaryOperator::LogicalNot,
+                Expression::from(ctx.ast.member_expression_static(
+                    SPAN,
+                    ctx.ast.expression_parenthesized(
+                        SPAN,
+                        ctx.ast.expression_assignment(
+                            SPAN,
+                            AssignmentOperator::Assign,
+                            step.create_write_target(ctx),
+                            next,
+                        ),
+                    ),
+                    ctx.ast.identifier_name(SPAN, "done"),
+                    false,
+                )),
+            );
+            // `_step.value`
+            let value = Expression::from(ctx.ast.member_expression_static(
+                SPAN,
+                step.create_read_expression(ctx),
+                ctx.ast.identifier_name(SPAN, "value"),
+                false,
+            ));
+            let body = Self::create_loop_body(for_of, value, for_scope_id, ctx);
+
+            let for_statement = ctx.ast.statement_for_with_scope_id(
+                for_of.span,
+                Some(init),
+                Some(test),
+                None,
+                body,
+                for_scope_id,
+            );
+            let for_statement = Self::wrap_with_label(for_statement, label, ctx);
+            ctx.ast.block_statement_with_scope_id(SPAN, ctx.ast.vec1(for_statement), block_scope_id)
+        };
+
+        // `catch (err) { _iterator.e(err); }`
+        let handler = {
+            let catch_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::CatchClause);
+            let block_scope_id = ctx.create_child_scope(catch_scope_id, ScopeFlags::empty());
+            let err = ctx.generate_binding(
+                Atom::from("err"),
+                catch_scope_id,
+                SymbolFlags::CatchVariable | SymbolFlags::FunctionScopedVariable,
+            );
+            let arguments = ctx.ast.vec1(Argument::from(err.create_read_expression(ctx)));
+            let body = ctx.ast.vec1(ctx.ast.statement_expression(
+                SPAN,
+                Self::create_iterator_call(&iterator, "e", arguments, ctx),
+            ));
+            ctx.ast.catch_clause_with_scope_id(
+                SPAN,
+                Some(ctx.ast.catch_parameter(SPAN, err.create_binding_pattern(ctx))),
+                ctx.ast.block_statement_with_scope_id(SPAN, body, block_scope_id),
+                catch_scope_id,
+            )
+        };
+
+        // `finally { _iterator.f(); }`
+        let finalizer = {
+            let finally_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+            let body = ctx.ast.vec1(ctx.ast.statement_expression(
+                SPAN,
+                Self::create_iterator_call(&iterator, "f", ctx.ast.vec(), ctx),
+            ));
+            ctx.ast.block_statement_with_scope_id(SPAN, body, finally_scope_id)
+        };
+
+        let try_statement = ctx.ast.statement_try(SPAN, block, Some(handler), Some(finalizer));
+
+        if allow_multiple_statements {
+            self.ctx.statement_injector.insert_before(&try_statement, iterator_declaration);
+            try_statement
+        } else {
+            ctx.ast.statement_block_with_scope_id(
+                SPAN,
+                ctx.ast.vec_from_array([iterator_declaration, try_statement]),
+                parent_scope_id,
+            )
+        }
+    }
+
+    /// Create the body of the new `for` loop, which assigns `value` to the left side of the
+    /// `for...of` loop, followed by the original body.
+    ///
+    /// The `for...of` loop's scope, which holds any `let` or `const` bindings on the left side,
+    /// becomes the scope of the new body.
+    fn create_loop_body(
+        for_of: &mut ForOfStatement<'a>,
+        value: Expression<'a>,
+        for_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let assignment = match &mut for_of.left {
+            ForStatementLeft::VariableDeclaration(decl) => {
+                // `for (const x of y)` -> `const x = value;`
+                let mut declarator = decl.declarations.pop().unwrap();
+                declarator.init = Some(value);
+                Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                    SPAN,
+                    declarator.kind,
+                    ctx.ast.vec1(declarator),
+                    false,
+                ))
+            }
+            left @ match_assignment_target!(ForStatementLeft) => {
+                // `for (x of y)`, `for ({ x } of y)` -> `x = value;`, `({ x } = value);`
+                let target = left.to_assignment_target_mut().take_in(ctx.ast);
+                let expression =
+                    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+                ctx.ast.statement_expression(SPAN, expression)
+            }
+        };
+
+        let mut body = ctx.ast.vec_with_capacity(2);
+        body.push(assignment);
+        match &for_of.body {
+            Statement::BlockStatement(block) if block.body.is_empty() => {
+                ctx.scoping_mut().delete_scope(block.scope_id());
+            }
+            Statement::EmptyStatement(_) => {}
+            _ => body.push(for_of.body.take_in(ctx.ast)),
+        }
+
+        let body_scope_id = for_of.scope_id();
+        ctx.scoping_mut().change_scope_parent_id(body_scope_id, Some(for_scope_id));
+        ctx.ast.statement_block_with_scope_id(SPAN, body, body_scope_id)
+    }
+
+    /// `_iterator.<method>(<arguments>)`
+    fn create_iterator_call(
+        iterator: &BoundIdentifier<'a>,
+        method: &'static str,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            iterator.create_read_expression(ctx),
+            ctx.ast.identifier_name(SPAN, method),
+            false,
+        ));
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    fn wrap_with_label(
+        stmt: Statement<'a>,
+        label: Option<LabelIdentifier<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        match label {
+            Some(label) => ctx.ast.statement_labeled(SPAN, label, stmt),
+            None => stmt,
+        }
+    }
+}
@@ -1,10 +1,19 @@
 use serde::Deserialize;
 
-use super::ArrowFunctionsOptions;
+use super::{ArrowFunctionsOptions, BlockScopingOptions, ForOfOptions, TemplateLiteralsOptions};
 
 #[derive(Debug, Default, Clone, Copy, Deserialize)]
 #[serde(default, rename_all = "camelCase", deny_unknown_fields)]
 pub struct ES2015Options {
     #[serde(skip)]
     pub arrow_function: Option<ArrowFunctionsOptions>,
+
+    #[serde(skip)]
+    pub block_scoping: Option<BlockScopingOptions>,
+
+    #[serde(skip)]
+    pub for_of: Option<ForOfOptions>,
+
+    #[serde(skip)]
+    pub template_literals: Option<TemplateLiteralsOptions>,
 }
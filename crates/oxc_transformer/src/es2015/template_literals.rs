@@ -0,0 +1,171 @@
+//! ES2015 Template Literals
+//!
+//! This plugin transforms template literals to string concatenation.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! `Hello, ${name}!`;
+//! `${a}${b}`;
+//! tag`Hello, ${name}!`;
+//! ```
+//!
+//! Output:
+//! ```js
+//! "Hello, " + name + "!";
+//! "" + a + b;
+//! tag`Hello, ${name}!`;
+//! ```
+//!
+//! Empty strings are omitted, except that one of the first two operands is always a string,
+//! so that `+` is a string concatenation rather than a numeric addition.
+//!
+//! Tagged templates are not transformed, unless `loose` option is enabled.
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Also transform tagged templates, to a call with an array of the cooked strings as the first argument.
+//!
+//! ```js
+//! tag`Hello, ${name}!`;
+//! // =>
+//! tag(["Hello, ", "!"], name);
+//! ```
+//!
+//! The strings array has no `raw` property, and a new array is created on every evaluation,
+//! so this is only correct for tags which do not depend on either.
+//!
+//! ## Missing features
+//!
+//! * Babel converts expressions with `String.prototype.concat` by default, which calls `toString`
+//!   on objects as a template literal does. This plugin always uses `+` (Babel's `loose` output),
+//!   which calls `valueOf` first.
+//! * Tagged templates are not transformed with `taggedTemplateLiteral` helper.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-template-literals>
+//! * Template literals specification: <https://tc39.es/ecma262/#sec-template-literals>
+
+use serde::Deserialize;
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_span::SPAN;
+use oxc_syntax::operator::BinaryOperator;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TemplateLiteralsOptions {
+    /// Also transform tagged templates.
+    pub loose: bool,
+}
+
+pub struct TemplateLiterals {
+    options: TemplateLiteralsOptions,
+}
+
+impl TemplateLiterals {
+    pub fn new(options: TemplateLiteralsOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for TemplateLiterals {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::TemplateLiteral(template) => {
+                *expr = Self::transform_template_literal(template, ctx);
+            }
+            Expression::TaggedTemplateExpression(tagged) if self.options.loose => {
+                *expr = Self::transform_tagged_template(tagged, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> TemplateLiterals {
+    /// Transform `` `a${b}c${d}` `` to `"a" + b + "c" + d`.
+    fn transform_template_literal(
+        template: &mut TemplateLiteral<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut expressions = template.expressions.take_in(ctx.ast).into_iter();
+        let mut operands = Vec::with_capacity(template.quasis.len() * 2);
+        for quasi in &template.quasis {
+            if quasi.value.cooked.is_some_and(|cooked| !cooked.is_empty()) {
+                operands.push(Self::cooked_string(quasi, ctx));
+            }
+            if let Some(expression) = expressions.next() {
+                operands.push(expression);
+            }
+        }
+
+        // `+` is only a string concatenation if one of the first two operands is a string.
+        // Nested template literals have already been transformed at this point.
+        if !operands.iter().take(2).any(|operand| matches!(operand, Expression::StringLiteral(_))) {
+            operands.insert(0, ctx.ast.expression_string_literal(SPAN, "", None));
+        }
+
+        let mut operands = operands.into_iter();
+        let first = operands.next().unwrap();
+        let result = operands.fold(first, |left, right| {
+            ctx.ast.expression_binary(SPAN, left, BinaryOperator::Addition, right)
+        });
+        Self::with_span(result, template.span)
+    }
+
+    /// Transform `` tag`a${b}c` `` to `tag(["a", "c"], b)`.
+    fn transform_tagged_template(
+        tagged: &mut TaggedTemplateExpression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let quasi = &mut tagged.quasi;
+        let strings = ctx.ast.vec_from_iter(quasi.quasis.iter().map(|quasi| {
+            let string = if quasi.value.cooked.is_some() {
+                Self::cooked_string(quasi, ctx)
+            } else {
+                // Invalid escape sequence, e.g. `` tag`\unicode` ``
+                ctx.ast.void_0(SPAN)
+            };
+            ArrayExpressionElement::from(string)
+        }));
+
+        let mut arguments = ctx.ast.vec_with_capacity(quasi.expressions.len() + 1);
+        arguments.push(Argument::from(ctx.ast.expression_array(SPAN, strings)));
+        arguments.extend(quasi.expressions.take_in(ctx.ast).into_iter().map(Argument::from));
+
+        let callee = tagged.tag.take_in(ctx.ast);
+        ctx.ast.expression_call(tagged.span, callee, NONE, arguments, false)
+    }
+
+    /// Create a string literal from cooked value of a template element.
+    fn cooked_string(quasi: &TemplateElement<'a>, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        ctx.ast.expression_string_literal_with_lone_surrogates(
+            SPAN,
+            quasi.value.cooked.unwrap(),
+            None,
+            quasi.lone_surrogates,
+        )
+    }
+
+    fn with_span(mut expr: Expression<'a>, span: Span) -> Expression<'a> {
+        match &mut expr {
+            Expression::BinaryExpression(binary) => binary.span = span,
+            Expression::StringLiteral(string) => string.span = span,
+            _ => {}
+        }
+        expr
+    }
+}
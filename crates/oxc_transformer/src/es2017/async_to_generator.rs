@@ -42,6 +42,10 @@
 //! });
 //! ```
 //!
+//! With the `esbuild` [helper flavor](super::ES2017Options::helper), functions are kept, and
+//! their bodies are replaced with `return __async(this, null, function* () { ... })` instead.
+//! `void 0` is passed in place of `this` when the generator function does not use `this`.
+//!
 //! ## Implementation
 //!
 //! Implementation based on [@babel/plugin-transform-async-to-generator](https://babel.dev/docs/babel-plugin-transform-async-to-generator).
@@ -57,7 +61,7 @@ use oxc_allocator::{Box as ArenaBox, StringBuilder as ArenaStringBuilder, TakeIn
 use oxc_ast::{NONE, ast::*};
 use oxc_ast_visit::Visit;
 use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
-use oxc_span::{Atom, GetSpan, SPAN};
+use oxc_span::{Atom, GetSpan, SPAN, Span};
 use oxc_syntax::{
     identifier::{is_identifier_name, is_identifier_part, is_identifier_start},
     keyword::is_reserved_keyword,
@@ -70,14 +74,52 @@ use crate::{
     state::TransformState,
 };
 
+use super::{
+    AsyncFunctionKind, AsyncStrategy, GeneratorNaming, HelperFlavor,
+    decision_log::ThisArgumentsFinder,
+};
+
+/// Options for [`AsyncToGenerator`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AsyncToGeneratorOptions<'a> {
+    /// See [`ES2017Options::generator_naming`](super::ES2017Options::generator_naming).
+    pub generator_naming: GeneratorNaming,
+    /// Helper to call instead of `asyncToGenerator`, as `(object, property)`.
+    ///
+    /// See [`ES2017Options::helper_name`](super::ES2017Options::helper_name).
+    pub helper_name: Option<(Atom<'a>, Atom<'a>)>,
+    /// See [`ES2017Options::helper`](super::ES2017Options::helper).
+    pub helper: HelperFlavor,
+}
+
 pub struct AsyncToGenerator<'a, 'ctx> {
     ctx: &'ctx TransformCtx<'a>,
     executor: AsyncGeneratorExecutor<'a, 'ctx>,
+    span_filter: Option<Span>,
 }
 
 impl<'a, 'ctx> AsyncToGenerator<'a, 'ctx> {
-    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
-        Self { ctx, executor: AsyncGeneratorExecutor::new(Helper::AsyncToGenerator, ctx) }
+    pub fn new(options: AsyncToGeneratorOptions<'a>, ctx: &'ctx TransformCtx<'a>) -> Self {
+        let mut executor =
+            AsyncGeneratorExecutor::new(Helper::AsyncToGenerator, options.generator_naming, ctx);
+        if let Some((object, property)) = options.helper_name {
+            executor = executor.with_helper_name(object, property);
+        }
+        executor = executor.with_helper_flavor(options.helper);
+        Self { ctx, executor, span_filter: None }
+    }
+
+    /// Only transform async functions whose span is contained in `span`.
+    ///
+    /// Async functions outside of `span` (and the `await` expressions inside them) are left as is.
+    #[must_use]
+    pub fn with_span_filter(mut self, span: Span) -> Self {
+        self.span_filter = Some(span);
+        self
+    }
+
+    fn is_in_span_filter(&self, span: Span) -> bool {
+        self.span_filter.is_none_or(|filter| filter.contains_inclusive(span))
     }
 }
 
@@ -85,18 +127,36 @@ impl<'a> Traverse<'a, TransformState<'a>> for AsyncToGenerator<'a, '_> {
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         let new_expr = match expr {
             Expression::AwaitExpression(await_expr) => {
-                Self::transform_await_expression(await_expr, ctx)
+                self.transform_await_expression(await_expr, ctx)
             }
             Expression::FunctionExpression(func) => {
-                if func.r#async && !func.generator && !func.is_typescript_syntax() {
-                    Some(self.executor.transform_function_expression(func, ctx))
+                if func.r#async
+                    && !func.generator
+                    && !func.is_typescript_syntax()
+                    && self.is_in_span_filter(func.span)
+                {
+                    if self.executor.is_esbuild_flavor() {
+                        self.executor.transform_function_with_esbuild_helper(
+                            func,
+                            AsyncFunctionKind::Expression,
+                            ctx,
+                        );
+                        None
+                    } else {
+                        Some(self.executor.transform_function_expression(func, ctx))
+                    }
                 } else {
                     None
                 }
             }
             Expression::ArrowFunctionExpression(arrow) => {
-                if arrow.r#async {
-                    Some(self.executor.transform_arrow_function(arrow, ctx))
+                if arrow.r#async && self.is_in_span_filter(arrow.span) {
+                    if self.executor.is_esbuild_flavor() {
+                        self.executor.transform_arrow_function_with_esbuild_helper(arrow, ctx);
+                        None
+                    } else {
+                        Some(self.executor.transform_arrow_function(arrow, ctx))
+                    }
                 } else {
                     None
                 }
@@ -132,9 +192,21 @@ impl<'a> Traverse<'a, TransformState<'a>> for AsyncToGenerator<'a, '_> {
         };
 
         if let Some(function) = function {
-            if function.r#async && !function.generator && !function.is_typescript_syntax() {
-                let new_statement = self.executor.transform_function_declaration(function, ctx);
-                self.ctx.statement_injector.insert_after(stmt, new_statement);
+            if function.r#async
+                && !function.generator
+                && !function.is_typescript_syntax()
+                && self.is_in_span_filter(function.span)
+            {
+                if self.executor.is_esbuild_flavor() {
+                    self.executor.transform_function_with_esbuild_helper(
+                        function,
+                        AsyncFunctionKind::Declaration,
+                        ctx,
+                    );
+                } else {
+                    let new_statement = self.executor.transform_function_declaration(function, ctx);
+                    self.ctx.statement_injector.insert_after(stmt, new_statement);
+                }
             }
         }
     }
@@ -142,41 +214,42 @@ impl<'a> Traverse<'a, TransformState<'a>> for AsyncToGenerator<'a, '_> {
     fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
         if func.r#async
             && !func.is_typescript_syntax()
+            && self.is_in_span_filter(func.span)
             && AsyncGeneratorExecutor::is_class_method_like_ancestor(ctx.parent())
         {
-            self.executor.transform_function_for_method_definition(func, ctx);
+            if self.executor.is_esbuild_flavor() {
+                self.executor.transform_function_with_esbuild_helper(
+                    func,
+                    AsyncFunctionKind::Method,
+                    ctx,
+                );
+            } else {
+                self.executor.transform_function_for_method_definition(func, ctx);
+            }
         }
     }
 }
 
 impl<'a> AsyncToGenerator<'a, '_> {
-    /// Check whether the current node is inside an async function.
-    fn is_inside_async_function(ctx: &TraverseCtx<'a>) -> bool {
+    /// Check whether the current node is inside an async function which will be transformed.
+    fn is_inside_async_function(&self, ctx: &TraverseCtx<'a>) -> bool {
         // Early return if current scope is top because we don't need to transform top-level await expression.
-        if ctx.current_scope_flags().is_top() {
+        if ctx.is_top_level() {
             return false;
         }
 
-        for ancestor in ctx.ancestors() {
-            match ancestor {
-                Ancestor::FunctionBody(func) => return *func.r#async(),
-                Ancestor::ArrowFunctionExpressionBody(func) => {
-                    return *func.r#async();
-                }
-                _ => {}
-            }
-        }
-        false
+        ctx.enclosing_async_function().is_some_and(|func| self.is_in_span_filter(func.span()))
     }
 
     /// Transforms `await` expressions to `yield` expressions.
     /// Ignores top-level await expressions.
     fn transform_await_expression(
+        &self,
         expr: &mut AwaitExpression<'a>,
         ctx: &TraverseCtx<'a>,
     ) -> Option<Expression<'a>> {
         // We don't need to handle top-level await.
-        if Self::is_inside_async_function(ctx) {
+        if self.is_inside_async_function(ctx) {
             Some(ctx.ast.expression_yield(SPAN, false, Some(expr.argument.take_in(ctx.ast))))
         } else {
             None
@@ -186,12 +259,35 @@ impl<'a> AsyncToGenerator<'a, '_> {
 
 pub struct AsyncGeneratorExecutor<'a, 'ctx> {
     helper: Helper,
+    /// Custom helper to call instead of [`AsyncGeneratorExecutor::helper`], as `(object, property)`.
+    helper_name: Option<(Atom<'a>, Atom<'a>)>,
+    flavor: HelperFlavor,
+    naming: GeneratorNaming,
     ctx: &'ctx TransformCtx<'a>,
 }
 
 impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
-    pub fn new(helper: Helper, ctx: &'ctx TransformCtx<'a>) -> Self {
-        Self { helper, ctx }
+    pub fn new(helper: Helper, naming: GeneratorNaming, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { helper, helper_name: None, flavor: HelperFlavor::Babel, naming, ctx }
+    }
+
+    /// Call `object.property` instead of loading [`AsyncGeneratorExecutor::helper`].
+    #[must_use]
+    pub fn with_helper_name(mut self, object: Atom<'a>, property: Atom<'a>) -> Self {
+        self.helper_name = Some((object, property));
+        self
+    }
+
+    /// Call the helper with the calling convention of `flavor`.
+    #[must_use]
+    pub fn with_helper_flavor(mut self, flavor: HelperFlavor) -> Self {
+        self.flavor = flavor;
+        self
+    }
+
+    #[inline]
+    pub fn is_esbuild_flavor(&self) -> bool {
+        self.flavor == HelperFlavor::Esbuild
     }
 
     /// Transforms async method definitions to generator functions wrapped in asyncToGenerator.
@@ -221,6 +317,14 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
             return;
         };
 
+        // `async` precedes the method's key, e.g. `static async [foo]() {}`
+        let (method_span, key) = match ctx.parent() {
+            Ancestor::MethodDefinitionValue(method) => (*method.span(), method.key()),
+            Ancestor::ObjectPropertyValue(property) => (*property.span(), property.key()),
+            _ => unreachable!(),
+        };
+        self.record_async_keyword(func.scope_id(), Span::new(method_span.start, key.span().start));
+
         // If parameters could throw errors, we need to move them to the inner function,
         // because it is an async function, which should return a rejecting promise if
         // there is an error.
@@ -264,6 +368,13 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
             Self::create_empty_params(ctx)
         };
 
+        let strategy = if needs_move_parameters_to_inner_function {
+            AsyncStrategy::MethodApply
+        } else {
+            AsyncStrategy::MethodCall
+        };
+        self.record_decision(generator_scope_id, AsyncFunctionKind::Method, strategy);
+
         let callee = self.create_async_to_generator_call(params, body, generator_scope_id, ctx);
         let (callee, arguments) = if needs_move_parameters_to_inner_function {
             // callee.apply(this, arguments)
@@ -301,18 +412,20 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
         wrapper_function: &mut Function<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
+        self.record_async_keyword(wrapper_function.scope_id(), wrapper_function.span);
         let body = wrapper_function.body.take().unwrap();
         let params = wrapper_function.params.take_in_box(ctx.ast);
         let id = wrapper_function.id.take();
         let has_function_id = id.is_some();
 
         if !has_function_id && !Self::is_function_length_affected(&params) {
-            return self.create_async_to_generator_call(
-                params,
-                body,
-                wrapper_function.scope_id.take().unwrap(),
-                ctx,
+            let scope_id = wrapper_function.scope_id.take().unwrap();
+            self.record_decision(
+                scope_id,
+                AsyncFunctionKind::Expression,
+                AsyncStrategy::DirectCall,
             );
+            return self.create_async_to_generator_call(params, body, scope_id, ctx);
         }
 
         let (generator_scope_id, wrapper_scope_id) = {
@@ -331,8 +444,13 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
             }
             (scope_id, wrapper_scope_id)
         };
+        self.record_decision(
+            generator_scope_id,
+            AsyncFunctionKind::Expression,
+            AsyncStrategy::Wrapper,
+        );
 
-        let bound_ident = Self::create_bound_identifier(
+        let bound_ident = self.create_bound_identifier(
             id.as_ref(),
             wrapper_scope_id,
             SymbolFlags::FunctionScopedVariable,
@@ -399,6 +517,7 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
         wrapper_function: &mut Function<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) -> Statement<'a> {
+        self.record_async_keyword(wrapper_function.scope_id(), wrapper_function.span);
         let (generator_scope_id, wrapper_scope_id) = {
             let wrapper_scope_id =
                 ctx.create_child_scope(ctx.current_scope_id(), ScopeFlags::Function);
@@ -407,12 +526,17 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
             ctx.scoping_mut().change_scope_parent_id(scope_id, Some(wrapper_scope_id));
             (scope_id, wrapper_scope_id)
         };
+        self.record_decision(
+            generator_scope_id,
+            AsyncFunctionKind::Declaration,
+            AsyncStrategy::HoistedDeclaration,
+        );
         let body = wrapper_function.body.take().unwrap();
         let params =
             Self::create_placeholder_params(&wrapper_function.params, wrapper_scope_id, ctx);
         let params = mem::replace(&mut wrapper_function.params, params);
 
-        let bound_ident = Self::create_bound_identifier(
+        let bound_ident = self.create_bound_identifier(
             wrapper_function.id.as_ref(),
             ctx.current_scope_id(),
             SymbolFlags::Function,
@@ -464,6 +588,7 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
         arrow: &mut ArrowFunctionExpression<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
+        self.record_async_keyword(arrow.scope_id(), arrow.span);
         let mut body = arrow.body.take_in_box(ctx.ast);
 
         // If the arrow's expression is true, we need to wrap the only one expression with return statement.
@@ -482,8 +607,18 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
         let function_name = Self::infer_function_name_from_parent_node(ctx);
 
         if function_name.is_none() && !Self::is_function_length_affected(&params) {
+            self.record_decision(
+                generator_function_id,
+                AsyncFunctionKind::Arrow,
+                AsyncStrategy::DirectCall,
+            );
             return self.create_async_to_generator_call(params, body, generator_function_id, ctx);
         }
+        self.record_decision(
+            generator_function_id,
+            AsyncFunctionKind::Arrow,
+            AsyncStrategy::Wrapper,
+        );
 
         let wrapper_scope_id = ctx.create_child_scope(ctx.current_scope_id(), ScopeFlags::Function);
 
@@ -491,7 +626,7 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
         // to change the parent scope of the generator function to the wrapper function.
         ctx.scoping_mut().change_scope_parent_id(generator_function_id, Some(wrapper_scope_id));
 
-        let bound_ident = Self::create_bound_identifier(
+        let bound_ident = self.create_bound_identifier(
             None,
             wrapper_scope_id,
             SymbolFlags::FunctionScopedVariable,
@@ -531,6 +666,201 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
         }
     }
 
+    /// Transforms an async function, method or function expression in place, replacing its body
+    /// with a call to the helper with esbuild's calling convention.
+    ///
+    /// ## Example
+    ///
+    /// Input:
+    /// ```js
+    /// async function foo(a) { await bar(a); }
+    /// async function baz(a = qux()) { await bar(a); }
+    /// ```
+    ///
+    /// Output:
+    /// ```js
+    /// function foo(a) {
+    ///   return __async(void 0, null, function* () {
+    ///     yield bar(a);
+    ///   });
+    /// }
+    /// function baz() {
+    ///   return __async(void 0, arguments, function* (a = qux()) {
+    ///     yield bar(a);
+    ///   });
+    /// }
+    /// ```
+    pub fn transform_function_with_esbuild_helper(
+        &self,
+        func: &mut Function<'a>,
+        kind: AsyncFunctionKind,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(body) = func.body.take() else {
+            return;
+        };
+
+        let search_span = match ctx.parent() {
+            // `async` precedes the method's key, e.g. `static async [foo]() {}`
+            Ancestor::MethodDefinitionValue(method) if kind == AsyncFunctionKind::Method => {
+                Span::new(method.span().start, method.key().span().start)
+            }
+            Ancestor::ObjectPropertyValue(property) if kind == AsyncFunctionKind::Method => {
+                Span::new(property.span().start, property.key().span().start)
+            }
+            _ => func.span,
+        };
+        self.record_async_keyword(func.scope_id(), search_span);
+
+        // Parameters which could throw are evaluated in the generator function, so the error
+        // rejects the returned promise rather than being thrown synchronously.
+        let needs_move_parameters_to_inner_function =
+            Self::could_throw_errors_parameters(&func.params);
+
+        let generator_scope_id = func.scope_id();
+        let wrapper_scope_id = ctx.create_child_scope(ctx.current_scope_id(), ScopeFlags::Function);
+        func.scope_id.set(Some(wrapper_scope_id));
+        ctx.scoping_mut().change_scope_parent_id(generator_scope_id, Some(wrapper_scope_id));
+        if kind == AsyncFunctionKind::Expression {
+            // The name of a function expression is bound in its own scope.
+            if let Some(id) = func.id.as_ref() {
+                Self::move_binding_identifier_to_target_scope(wrapper_scope_id, id, ctx);
+            }
+        }
+
+        let (params, arguments) = if needs_move_parameters_to_inner_function {
+            let new_params = Self::create_placeholder_params(&func.params, wrapper_scope_id, ctx);
+            let params = mem::replace(&mut func.params, new_params);
+            (params, Self::create_arguments_expression(ctx))
+        } else {
+            Self::move_formal_parameters_to_target_scope(wrapper_scope_id, &func.params, ctx);
+            // The generator function only needs the arguments if it uses `arguments`
+            let arguments = if ThisArgumentsFinder::uses_arguments(&body) {
+                Self::create_arguments_expression(ctx)
+            } else {
+                ctx.ast.expression_null_literal(SPAN)
+            };
+            (Self::create_empty_params(ctx), arguments)
+        };
+        self.record_decision(generator_scope_id, kind, AsyncStrategy::Inline);
+
+        let call =
+            self.create_esbuild_helper_call(arguments, params, body, generator_scope_id, ctx);
+        let statement = ctx.ast.statement_return(SPAN, Some(call));
+
+        func.r#async = false;
+        func.body = Some(ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), ctx.ast.vec1(statement)));
+    }
+
+    /// Transforms an async arrow function in place, replacing its body with a call to the helper
+    /// with esbuild's calling convention.
+    ///
+    /// The arrow function is kept, so `this` passed to the helper, if it is used, is the enclosing
+    /// `this`.
+    ///
+    /// ## Example
+    ///
+    /// Input:
+    /// ```js
+    /// const foo = async (a) => await bar(a);
+    /// const baz = async (a = qux()) => await bar(a);
+    /// ```
+    ///
+    /// Output:
+    /// ```js
+    /// const foo = (a) => __async(void 0, null, function* () {
+    ///   return yield bar(a);
+    /// });
+    /// const baz = (..._args) => __async(void 0, _args, function* (a = qux()) {
+    ///   return yield bar(a);
+    /// });
+    /// ```
+    pub(self) fn transform_arrow_function_with_esbuild_helper(
+        &self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.record_async_keyword(arrow.scope_id(), arrow.span);
+        let mut body = arrow.body.take_in_box(ctx.ast);
+
+        // If the arrow's expression is true, we need to wrap the only one expression with return statement.
+        if arrow.expression {
+            let statement = body.statements.first_mut().unwrap();
+            let expression = match statement {
+                Statement::ExpressionStatement(es) => es.expression.take_in(ctx.ast),
+                _ => unreachable!(),
+            };
+            *statement = ctx.ast.statement_return(expression.span(), Some(expression));
+        }
+
+        let generator_scope_id = arrow.scope_id();
+        ctx.scoping_mut().scope_flags_mut(generator_scope_id).remove(ScopeFlags::Arrow);
+        let wrapper_scope_id = ctx
+            .create_child_scope(ctx.current_scope_id(), ScopeFlags::Function | ScopeFlags::Arrow);
+        arrow.scope_id.set(Some(wrapper_scope_id));
+        ctx.scoping_mut().change_scope_parent_id(generator_scope_id, Some(wrapper_scope_id));
+
+        let (params, arguments) = if Self::could_throw_errors_parameters(&arrow.params) {
+            // Arrow functions have no `arguments`, so collect them in a rest parameter:
+            // `(..._args) => helper(this, _args, function* (PARAMS) {})`
+            let binding =
+                ctx.generate_uid("args", wrapper_scope_id, SymbolFlags::FunctionScopedVariable);
+            let rest =
+                ctx.ast.alloc_binding_rest_element(SPAN, binding.create_binding_pattern(ctx));
+            let new_params = ctx.ast.alloc_formal_parameters(
+                SPAN,
+                FormalParameterKind::ArrowFormalParameters,
+                ctx.ast.vec(),
+                Some(rest),
+            );
+            let params = mem::replace(&mut arrow.params, new_params);
+            (params, binding.create_read_expression(ctx))
+        } else {
+            Self::move_formal_parameters_to_target_scope(wrapper_scope_id, &arrow.params, ctx);
+            (Self::create_empty_params(ctx), ctx.ast.expression_null_literal(SPAN))
+        };
+        self.record_decision(generator_scope_id, AsyncFunctionKind::Arrow, AsyncStrategy::Inline);
+
+        let call =
+            self.create_esbuild_helper_call(arguments, params, body, generator_scope_id, ctx);
+        let statement = ctx.ast.statement_expression(SPAN, call);
+
+        arrow.r#async = false;
+        arrow.expression = true;
+        arrow.body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), ctx.ast.vec1(statement));
+    }
+
+    /// Record how the async function whose original scope is `scope_id` is lowered.
+    fn record_decision(&self, scope_id: ScopeId, kind: AsyncFunctionKind, strategy: AsyncStrategy) {
+        self.ctx.decision_log.record(scope_id, kind, strategy, self.helper, self.ctx);
+    }
+
+    /// Record the span of the `async` keyword of the function being lowered to the generator function
+    /// whose scope is `scope_id`, so diagnostics about the generated function can point back at it.
+    ///
+    /// The keyword is the last `async` word in `search_span`, which starts at the keyword
+    /// for function declarations, expressions, and arrow functions.
+    fn record_async_keyword(&self, scope_id: ScopeId, search_span: Span) {
+        if search_span.is_unspanned() {
+            return;
+        }
+        let Some(text) =
+            self.ctx.source_text.get(search_span.start as usize..search_span.end as usize)
+        else {
+            return;
+        };
+        let is_word_boundary = |c: Option<char>| c.is_none_or(|c| !is_identifier_part(c));
+        let keyword_start = text.rmatch_indices("async").map(|(index, _)| index).find(|&index| {
+            is_word_boundary(text[..index].chars().next_back())
+                && is_word_boundary(text[index + "async".len()..].chars().next())
+        });
+        if let Some(start) = keyword_start {
+            #[expect(clippy::cast_possible_truncation)]
+            let span = Span::sized(search_span.start + start as u32, "async".len() as u32);
+            self.ctx.async_keyword_spans.borrow_mut().insert(scope_id, span);
+        }
+    }
+
     /// Infers the function id from [`TraverseCtx::parent`].
     fn infer_function_id_from_parent_node(
         scope_id: ScopeId,
@@ -692,7 +1022,71 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
         let mut function = Self::create_function(None, params, body, scope_id, ctx);
         function.generator = true;
         let arguments = ctx.ast.vec1(Argument::FunctionExpression(function));
-        self.ctx.helper_call_expr(self.helper, SPAN, arguments, ctx)
+        let callee = self.get_helper_callee(ctx);
+        ctx.ast.expression_call_with_pure(SPAN, callee, NONE, arguments, false, self.helper.pure())
+    }
+
+    /// Creates an [`Expression`] that calls the helper with esbuild's calling convention.
+    ///
+    /// The generated code structure is:
+    /// ```js
+    /// __async(THIS, ARGUMENTS, function* (PARAMS) {
+    ///    BODY
+    /// });
+    /// ```
+    ///
+    /// `THIS` is `this` if `BODY` uses `this`, otherwise `void 0`.
+    fn create_esbuild_helper_call(
+        &self,
+        arguments: Expression<'a>,
+        params: ArenaBox<'a, FormalParameters<'a>>,
+        body: ArenaBox<'a, FunctionBody<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        // Pass `this` only if the generator function uses it, so the call does not depend on
+        // the surrounding context, e.g. at the top level of a module.
+        let this = if ThisArgumentsFinder::uses_this(&body) {
+            ctx.ast.expression_this(SPAN)
+        } else {
+            ctx.ast.void_0(SPAN)
+        };
+        let mut function = Self::create_function(None, params, body, scope_id, ctx);
+        function.generator = true;
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(this),
+            Argument::from(arguments),
+            Argument::FunctionExpression(function),
+        ]);
+        let callee = self.get_helper_callee(ctx);
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    /// Creates an `arguments` [`Expression`].
+    fn create_arguments_expression(ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        ctx.create_unbound_ident_expr(SPAN, Atom::new_const("arguments"), ReferenceFlags::Read)
+    }
+
+    /// Creates the callee of the helper call.
+    ///
+    /// This is `object.property` if a custom helper name is set, otherwise the helper loaded by
+    /// the helper loader, e.g. `babelHelpers.asyncToGenerator`, or the global `__async` for the
+    /// esbuild helper flavor.
+    fn get_helper_callee(&self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let Some((object, property)) = self.helper_name else {
+            if self.is_esbuild_flavor() {
+                return ctx.create_unbound_ident_expr(
+                    SPAN,
+                    Atom::new_const("__async"),
+                    ReferenceFlags::Read,
+                );
+            }
+            return self.ctx.helper_load(self.helper, ctx);
+        };
+        let symbol_id = ctx.scoping().find_binding(ctx.current_scope_id(), &object);
+        let object = ctx.create_ident_expr(SPAN, object, symbol_id, ReferenceFlags::Read);
+        let property = ctx.ast.identifier_name(SPAN, property);
+        Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
     }
 
     /// Creates a helper declaration statement for async-to-generator transformation.
@@ -790,15 +1184,23 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
         )
     }
 
-    /// Creates a [`BoundIdentifier`] for the id of the function.
-    #[inline]
+    /// Creates a [`BoundIdentifier`] for the generator function, named according to
+    /// [`GeneratorNaming`].
     fn create_bound_identifier(
+        &self,
         id: Option<&BindingIdentifier<'a>>,
         scope_id: ScopeId,
         flags: SymbolFlags,
         ctx: &mut TraverseCtx<'a>,
     ) -> BoundIdentifier<'a> {
-        ctx.generate_uid(id.as_ref().map_or_else(|| "ref", |id| id.name.as_str()), scope_id, flags)
+        let name = match self.naming {
+            GeneratorNaming::Babel => id.map(|id| id.name),
+            GeneratorNaming::Ref => None,
+            GeneratorNaming::Name => {
+                id.map(|id| id.name).or_else(|| Self::infer_function_name_from_parent_node(ctx))
+            }
+        };
+        ctx.generate_uid(name.as_ref().map_or("ref", Atom::as_str), scope_id, flags)
     }
 
     /// Check whether the given [`Ancestor`] is a class method-like node.
@@ -0,0 +1,411 @@
+//! Decision log for async function lowering.
+//!
+//! When [`ES2017Options::decision_log`] is enabled, every async function converted to a generator
+//! function records an [`AsyncDecision`]: which strategy was chosen, which helper was used, and
+//! what the analysis found. The log for a file is returned in
+//! [`TransformerReturn::decision_log`], and can be written to a JSONL artifact with
+//! [`DecisionLog::to_jsonl`].
+//!
+//! Two artifacts produced by different versions of Oxc can be compared with
+//! [`compare_decision_logs`], to check that an upgrade did not change how functions are lowered.
+//!
+//! Functions are identified by a fingerprint of their original parameters and body. It is computed
+//! before any transform runs, from node types, names, literal values and operators only, so it does
+//! not depend on spans, formatting, comments, or the uids generated by transforms.
+//!
+//! [`ES2017Options::decision_log`]: crate::ES2017Options::decision_log
+//! [`TransformerReturn::decision_log`]: crate::TransformerReturn::decision_log
+
+use std::{cell::RefCell, fmt::Write};
+
+use indexmap::IndexMap;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+
+use oxc_ast::{AstKind, ast::*};
+use oxc_ast_visit::Visit;
+use oxc_semantic::{ScopeFlags, ScopeId};
+
+use crate::{common::helper_loader::Helper, context::TransformCtx};
+
+/// Kind of an async function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AsyncFunctionKind {
+    /// `async function foo() {}`
+    Declaration,
+    /// `(async function () {})`
+    Expression,
+    /// `async () => {}`
+    Arrow,
+    /// `class A { async foo() {} }` or `({ async foo() {} })`
+    Method,
+}
+
+/// How an async function was lowered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AsyncStrategy {
+    /// Replaced with a direct call to the helper: `helper(function* () {})`.
+    DirectCall,
+    /// Wrapped in an IIFE which preserves the function's name and `length`.
+    Wrapper,
+    /// Split into the original declaration, and a hoisted declaration calling the helper.
+    HoistedDeclaration,
+    /// Method body replaced with `return helper(function* () {})()`.
+    MethodCall,
+    /// Method body replaced with `return helper(function* (params) {}).apply(this, arguments)`,
+    /// because evaluating the parameters could throw.
+    MethodApply,
+    /// Body replaced with `return helper(this, arguments, function* () {})`, keeping the function,
+    /// for the `esbuild` helper flavor.
+    Inline,
+}
+
+/// Record of how a single async function was lowered.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AsyncDecision {
+    /// Path of the source file.
+    pub file: String,
+    /// Fingerprint of the function's original parameters and body.
+    pub fingerprint: String,
+    pub kind: AsyncFunctionKind,
+    pub strategy: AsyncStrategy,
+    /// Compiler assumptions enabled for the transform.
+    pub assumptions: Vec<String>,
+    /// Helpers the lowered function calls.
+    pub helpers: Vec<String>,
+    /// `this` is used in the function, not counting nested non-arrow functions.
+    pub uses_this: bool,
+    /// `arguments` is used in the function, not counting nested non-arrow functions.
+    pub uses_arguments: bool,
+}
+
+/// Decisions recorded while transforming a file, in the order functions were lowered.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DecisionLog {
+    pub decisions: Vec<AsyncDecision>,
+}
+
+impl DecisionLog {
+    /// Serialize to JSONL, one decision per line.
+    #[expect(clippy::missing_panics_doc, reason = "infallible")]
+    pub fn to_jsonl(&self) -> String {
+        let mut out = String::new();
+        for decision in &self.decisions {
+            out.push_str(&serde_json::to_string(decision).unwrap());
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Parse JSONL produced by [`DecisionLog::to_jsonl`]. Logs of several files can be
+    /// concatenated. Empty lines are ignored.
+    ///
+    /// # Errors
+    ///
+    /// If a line is not a valid [`AsyncDecision`].
+    pub fn from_jsonl(jsonl: &str) -> Result<Self, serde_json::Error> {
+        let decisions = jsonl
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<Result<_, _>>()?;
+        Ok(Self { decisions })
+    }
+}
+
+/// A decision which differs between two logs, for the same function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecisionChange {
+    pub old: AsyncDecision,
+    pub new: AsyncDecision,
+}
+
+/// Result of [`compare_decision_logs`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DecisionLogReport {
+    /// Functions only in the new log.
+    pub added: Vec<AsyncDecision>,
+    /// Functions only in the old log.
+    pub removed: Vec<AsyncDecision>,
+    /// Functions in both logs, with different decisions.
+    pub changed: Vec<DecisionChange>,
+    /// Number of functions in both logs, with identical decisions.
+    pub unchanged: usize,
+}
+
+impl DecisionLogReport {
+    /// Returns `true` if the logs contain the same decisions for the same functions.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Compare two decision logs.
+///
+/// Functions are matched by file, fingerprint, and the order in which functions with the same
+/// fingerprint appear in that file.
+pub fn compare_decision_logs(old: &DecisionLog, new: &DecisionLog) -> DecisionLogReport {
+    let mut old = keyed(old);
+    let mut report = DecisionLogReport::default();
+    for (key, new_decision) in keyed(new) {
+        match old.shift_remove(&key) {
+            Some(old_decision) if old_decision == new_decision => report.unchanged += 1,
+            Some(old_decision) => report
+                .changed
+                .push(DecisionChange { old: old_decision.clone(), new: new_decision.clone() }),
+            None => report.added.push(new_decision.clone()),
+        }
+    }
+    report.removed = old.into_values().cloned().collect();
+    report
+}
+
+fn keyed(log: &DecisionLog) -> IndexMap<(&str, &str, usize), &AsyncDecision> {
+    let mut occurrences = FxHashMap::<(&str, &str), usize>::default();
+    log.decisions
+        .iter()
+        .map(|decision| {
+            let key = (decision.file.as_str(), decision.fingerprint.as_str());
+            let occurrence = occurrences.entry(key).or_default();
+            *occurrence += 1;
+            ((key.0, key.1, *occurrence), decision)
+        })
+        .collect()
+}
+
+/// Result of analyzing an async function before it is transformed.
+struct FunctionAnalysis {
+    fingerprint: String,
+    uses_this: bool,
+    uses_arguments: bool,
+}
+
+/// Collects decisions while transforming a file.
+pub struct DecisionLogStore {
+    enabled: bool,
+    /// Analysis of async functions, keyed by their original scope
+    analyses: RefCell<FxHashMap<ScopeId, FunctionAnalysis>>,
+    decisions: RefCell<Vec<AsyncDecision>>,
+}
+
+impl DecisionLogStore {
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled, analyses: RefCell::default(), decisions: RefCell::default() }
+    }
+
+    /// Analyze an async function before it is transformed.
+    pub fn analyze_function(&self, func: &Function<'_>) {
+        if self.enabled && func.r#async {
+            if let Some(body) = &func.body {
+                self.analyze(func.scope_id(), &func.params, body, false);
+            }
+        }
+    }
+
+    /// Analyze an async arrow function before it is transformed.
+    pub fn analyze_arrow_function(&self, arrow: &ArrowFunctionExpression<'_>) {
+        if self.enabled && arrow.r#async {
+            self.analyze(arrow.scope_id(), &arrow.params, &arrow.body, true);
+        }
+    }
+
+    fn analyze(
+        &self,
+        scope_id: ScopeId,
+        params: &FormalParameters,
+        body: &FunctionBody,
+        arrow: bool,
+    ) {
+        let mut fingerprinter = Fingerprinter::default();
+        fingerprinter.write(if arrow { "arrow" } else { "function" });
+        fingerprinter.visit_formal_parameters(params);
+        fingerprinter.visit_function_body(body);
+
+        let mut finder = ThisArgumentsFinder::default();
+        finder.visit_formal_parameters(params);
+        finder.visit_function_body(body);
+
+        let analysis = FunctionAnalysis {
+            fingerprint: fingerprinter.finish(),
+            uses_this: finder.uses_this,
+            uses_arguments: finder.uses_arguments,
+        };
+        self.analyses.borrow_mut().insert(scope_id, analysis);
+    }
+
+    /// Record the decision for the async function whose original scope is `scope_id`.
+    pub fn record(
+        &self,
+        scope_id: ScopeId,
+        kind: AsyncFunctionKind,
+        strategy: AsyncStrategy,
+        helper: Helper,
+        ctx: &TransformCtx<'_>,
+    ) {
+        if !self.enabled {
+            return;
+        }
+        let Some(analysis) = self.analyses.borrow_mut().remove(&scope_id) else {
+            return;
+        };
+        self.decisions.borrow_mut().push(AsyncDecision {
+            file: ctx.source_path.to_string_lossy().into_owned(),
+            fingerprint: analysis.fingerprint,
+            kind,
+            strategy,
+            assumptions: ctx.assumptions.enabled_names().into_iter().map(String::from).collect(),
+            helpers: vec![helper.name().to_string()],
+            uses_this: analysis.uses_this,
+            uses_arguments: analysis.uses_arguments,
+        });
+    }
+
+    pub fn take(&self) -> DecisionLog {
+        self.analyses.borrow_mut().clear();
+        DecisionLog { decisions: self.decisions.take() }
+    }
+}
+
+/// Hashes the token-level content of the visited nodes: node types, names, literal values,
+/// and operators.
+#[derive(Default)]
+struct Fingerprinter {
+    hasher: Sha1,
+    buffer: String,
+}
+
+impl Fingerprinter {
+    fn write(&mut self, token: &str) {
+        self.hasher.update(token.as_bytes());
+        // Separate tokens, so that e.g. `ab` + `c` differs from `a` + `bc`.
+        self.hasher.update([0]);
+    }
+
+    fn finish(self) -> String {
+        let digest = self.hasher.finalize();
+        digest[..8].iter().fold(String::with_capacity(16), |mut out, byte| {
+            write!(out, "{byte:02x}").unwrap();
+            out
+        })
+    }
+}
+
+impl<'a> Visit<'a> for Fingerprinter {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        // Redundant parentheses are formatting.
+        if matches!(kind, AstKind::ParenthesizedExpression(_)) {
+            return;
+        }
+
+        let mut buffer = std::mem::take(&mut self.buffer);
+        buffer.clear();
+        write!(buffer, "{:?}", kind.ty()).unwrap();
+        self.write(&buffer);
+        self.buffer = buffer;
+
+        match kind {
+            AstKind::IdentifierName(ident) => self.write(&ident.name),
+            AstKind::IdentifierReference(ident) => self.write(&ident.name),
+            AstKind::BindingIdentifier(ident) => self.write(&ident.name),
+            AstKind::LabelIdentifier(ident) => self.write(&ident.name),
+            AstKind::PrivateIdentifier(ident) => self.write(&ident.name),
+            AstKind::JSXIdentifier(ident) => self.write(&ident.name),
+            AstKind::StringLiteral(lit) => self.write(&lit.value),
+            AstKind::NumericLiteral(lit) => self.write(&lit.value.to_string()),
+            AstKind::BigIntLiteral(lit) => self.write(&lit.value),
+            AstKind::BooleanLiteral(lit) => self.write(if lit.value { "true" } else { "false" }),
+            AstKind::RegExpLiteral(lit) => {
+                self.write(&lit.regex.pattern.text);
+                self.write(&lit.regex.flags.to_string());
+            }
+            AstKind::TemplateElement(elem) => self.write(&elem.value.raw),
+            AstKind::JSXText(text) => self.write(&text.value),
+            AstKind::BinaryExpression(expr) => self.write(expr.operator.as_str()),
+            AstKind::LogicalExpression(expr) => self.write(expr.operator.as_str()),
+            AstKind::UnaryExpression(expr) => self.write(expr.operator.as_str()),
+            AstKind::UpdateExpression(expr) => {
+                self.write(expr.operator.as_str());
+                self.write(if expr.prefix { "prefix" } else { "postfix" });
+            }
+            AstKind::AssignmentExpression(expr) => self.write(expr.operator.as_str()),
+            AstKind::VariableDeclaration(decl) => self.write(decl.kind.as_str()),
+            AstKind::Function(func) => {
+                self.write(if func.r#async { "async" } else { "sync" });
+                self.write(if func.generator { "generator" } else { "function" });
+            }
+            AstKind::ArrowFunctionExpression(arrow) => {
+                self.write(if arrow.r#async { "async" } else { "sync" });
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Finds uses of `this` and `arguments` which refer to the visited function.
+#[derive(Default)]
+pub(super) struct ThisArgumentsFinder {
+    uses_this: bool,
+    uses_arguments: bool,
+}
+
+impl ThisArgumentsFinder {
+    /// Whether the function whose body is `body` uses its own `arguments`.
+    pub(super) fn uses_arguments(body: &FunctionBody<'_>) -> bool {
+        let mut finder = Self::default();
+        finder.visit_function_body(body);
+        finder.uses_arguments
+    }
+
+    /// Whether the function whose body is `body` uses its own `this`.
+    pub(super) fn uses_this(body: &FunctionBody<'_>) -> bool {
+        let mut finder = Self::default();
+        finder.visit_function_body(body);
+        finder.uses_this
+    }
+}
+
+impl<'a> Visit<'a> for ThisArgumentsFinder {
+    fn visit_this_expression(&mut self, _it: &ThisExpression) {
+        self.uses_this = true;
+    }
+
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        if it.name == "arguments" {
+            self.uses_arguments = true;
+        }
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    /// Only visit the parts of a class which are evaluated in the enclosing scope: decorators,
+    /// the super class, and computed keys. Method bodies, field initializers and static blocks
+    /// have their own `this` and `arguments`.
+    fn visit_class(&mut self, it: &Class<'a>) {
+        self.visit_decorators(&it.decorators);
+        if let Some(super_class) = &it.super_class {
+            self.visit_expression(super_class);
+        }
+        for element in &it.body.body {
+            let (decorators, key, computed) = match element {
+                ClassElement::MethodDefinition(method) => {
+                    (&method.decorators, &method.key, method.computed)
+                }
+                ClassElement::PropertyDefinition(prop) => {
+                    (&prop.decorators, &prop.key, prop.computed)
+                }
+                ClassElement::AccessorProperty(prop) => {
+                    (&prop.decorators, &prop.key, prop.computed)
+                }
+                ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => continue,
+            };
+            self.visit_decorators(decorators);
+            if computed {
+                self.visit_property_key(key);
+            }
+        }
+    }
+}
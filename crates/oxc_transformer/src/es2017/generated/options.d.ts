@@ -0,0 +1,49 @@
+export interface ES2017Options {
+  /**
+   * Record how each async function is lowered, and return the records in
+   * `TransformerReturn::decision_log`.
+   *
+   * Useful for checking that upgrading Oxc does not change the output for a codebase.
+   *
+   * @default false
+   * @since 0.77.2
+   */
+  decisionLog?: boolean;
+  /**
+   * How to name the variable which holds the generator function wrapped in
+   * `asyncToGenerator`.
+   *
+   * `"babel"` matches Babel, using the function's own name (`_foo`), otherwise `_ref`.
+   * `"ref"` always uses `_ref`. `"name"` also uses the name inferred from the variable or
+   * property the function is assigned to (`_foo` for `const foo = async () => {}`).
+   *
+   * @default "babel"
+   * @since 0.77.2
+   */
+  generatorNaming?: "babel" | "ref" | "name";
+  /**
+   * Call this helper instead of `asyncToGenerator` from the helper runtime, given as
+   * `[object, property]`.
+   *
+   * For example, `["myRuntime", "asyncToGenerator"]` calls `myRuntime.asyncToGenerator`.
+   * The helper must take a generator function and return an async function, as
+   * `asyncToGenerator` does.
+   *
+   * @default null
+   * @since 0.77.2
+   */
+  helperName?: [string, string] | null;
+  /**
+   * Calling convention of the helper.
+   *
+   * `"babel"` matches Babel's `asyncToGenerator`, which takes a generator function and
+   * returns an async function: `asyncToGenerator(function* () {}).apply(this, arguments)`.
+   * `"esbuild"` matches esbuild's `__async`, which takes `this`, the arguments and a
+   * generator function, and returns a promise: `__async(this, null, function* () {})`.
+   * Unless `helperName` is set, `"esbuild"` calls a global `__async` function.
+   *
+   * @default "babel"
+   * @since 0.77.2
+   */
+  helper?: "babel" | "esbuild";
+}
@@ -1,4 +1,7 @@
-use oxc_ast::ast::{Expression, Function, Statement};
+use oxc_ast::{
+    AstBuilder,
+    ast::{ArrowFunctionExpression, Expression, Function, Statement},
+};
 use oxc_traverse::Traverse;
 
 use crate::{
@@ -7,11 +10,18 @@ use crate::{
 };
 
 mod async_to_generator;
+mod decision_log;
 mod options;
-pub use async_to_generator::{AsyncGeneratorExecutor, AsyncToGenerator};
-pub use options::ES2017Options;
+pub use async_to_generator::{AsyncGeneratorExecutor, AsyncToGenerator, AsyncToGeneratorOptions};
+pub use decision_log::DecisionLogStore;
+pub use decision_log::{
+    AsyncDecision, AsyncFunctionKind, AsyncStrategy, DecisionChange, DecisionLog,
+    DecisionLogReport, compare_decision_logs,
+};
+pub use options::{ES2017Options, GeneratorNaming, HelperFlavor};
 
 pub struct ES2017<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
     options: ES2017Options,
 
     // Plugins
@@ -19,12 +29,43 @@ pub struct ES2017<'a, 'ctx> {
 }
 
 impl<'a, 'ctx> ES2017<'a, 'ctx> {
-    pub fn new(options: ES2017Options, ctx: &'ctx TransformCtx<'a>) -> ES2017<'a, 'ctx> {
-        ES2017 { async_to_generator: AsyncToGenerator::new(ctx), options }
+    pub fn new(
+        options: ES2017Options,
+        ast: AstBuilder<'a>,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> ES2017<'a, 'ctx> {
+        let helper_name = options
+            .helper_name
+            .as_ref()
+            .map(|(object, property)| (ast.atom(object), ast.atom(property)));
+        let mut async_to_generator = AsyncToGenerator::new(
+            AsyncToGeneratorOptions {
+                generator_naming: options.generator_naming,
+                helper_name,
+                helper: options.helper,
+            },
+            ctx,
+        );
+        if let Some(span) = options.span_filter {
+            async_to_generator = async_to_generator.with_span_filter(span);
+        }
+        ES2017 { ctx, options, async_to_generator }
     }
 }
 
 impl<'a> Traverse<'a, TransformState<'a>> for ES2017<'a, '_> {
+    fn enter_function(&mut self, node: &mut Function<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.ctx.decision_log.analyze_function(node);
+    }
+
+    fn enter_arrow_function_expression(
+        &mut self,
+        node: &mut ArrowFunctionExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.ctx.decision_log.analyze_arrow_function(node);
+    }
+
     fn exit_expression(&mut self, node: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.async_to_generator {
             self.async_to_generator.exit_expression(node, ctx);
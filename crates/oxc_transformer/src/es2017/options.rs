@@ -1,8 +1,98 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default, Clone, Copy, Deserialize)]
-#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
-pub struct ES2017Options {
-    #[serde(skip)]
-    pub async_to_generator: bool,
+use oxc_span::Span;
+
+use crate::options::plugin_options::{OptionType, declare_plugin_options};
+
+declare_plugin_options! {
+    #[derive(Debug, Clone)]
+    pub struct ES2017Options {
+        #[internal]
+        pub async_to_generator: bool,
+
+        #[internal]
+        /// Only transform async functions contained in this span.
+        ///
+        /// Used by editor integrations to transform a selection rather than the whole program.
+        /// See [`AsyncToGenerator::with_span_filter`](super::AsyncToGenerator::with_span_filter).
+        pub span_filter: Option<Span>,
+
+        /// Record how each async function is lowered, and return the records in
+        /// `TransformerReturn::decision_log`.
+        ///
+        /// Useful for checking that upgrading Oxc does not change the output for a codebase.
+        #[option(default = false, since = "0.77.2")]
+        pub decision_log: bool,
+
+        /// How to name the variable which holds the generator function wrapped in
+        /// `asyncToGenerator`.
+        ///
+        /// `"babel"` matches Babel, using the function's own name (`_foo`), otherwise `_ref`.
+        /// `"ref"` always uses `_ref`. `"name"` also uses the name inferred from the variable or
+        /// property the function is assigned to (`_foo` for `const foo = async () => {}`).
+        #[option(default = GeneratorNaming::Babel, since = "0.77.2")]
+        pub generator_naming: GeneratorNaming,
+
+        /// Call this helper instead of `asyncToGenerator` from the helper runtime, given as
+        /// `[object, property]`.
+        ///
+        /// For example, `["myRuntime", "asyncToGenerator"]` calls `myRuntime.asyncToGenerator`.
+        /// The helper must take a generator function and return an async function, as
+        /// `asyncToGenerator` does.
+        #[option(default = None, since = "0.77.2")]
+        pub helper_name: Option<(String, String)>,
+
+        /// Calling convention of the helper.
+        ///
+        /// `"babel"` matches Babel's `asyncToGenerator`, which takes a generator function and
+        /// returns an async function: `asyncToGenerator(function* () {}).apply(this, arguments)`.
+        /// `"esbuild"` matches esbuild's `__async`, which takes `this`, the arguments and a
+        /// generator function, and returns a promise: `__async(this, null, function* () {})`.
+        /// Unless `helperName` is set, `"esbuild"` calls a global `__async` function.
+        #[option(default = HelperFlavor::Babel, since = "0.77.2")]
+        pub helper: HelperFlavor,
+    }
+}
+
+/// Naming convention for the variable holding the generator function in async-to-generator output.
+///
+/// See [`ES2017Options::generator_naming`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GeneratorNaming {
+    #[default]
+    Babel,
+    Ref,
+    Name,
+}
+
+impl OptionType for GeneratorNaming {
+    fn ts_type() -> String {
+        r#""babel" | "ref" | "name""#.to_string()
+    }
+
+    fn json_schema() -> serde_json::Value {
+        serde_json::json!({ "type": "string", "enum": ["babel", "ref", "name"] })
+    }
+}
+
+/// Calling convention of the helper used in async-to-generator output.
+///
+/// See [`ES2017Options::helper`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HelperFlavor {
+    #[default]
+    Babel,
+    Esbuild,
+}
+
+impl OptionType for HelperFlavor {
+    fn ts_type() -> String {
+        r#""babel" | "esbuild""#.to_string()
+    }
+
+    fn json_schema() -> serde_json::Value {
+        serde_json::json!({ "type": "string", "enum": ["babel", "esbuild"] })
+    }
 }
@@ -4,26 +4,15 @@ use oxc_allocator::{TakeIn, Vec as ArenaVec};
 use oxc_ast::{NONE, ast::*};
 use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
 use oxc_span::SPAN;
-use oxc_traverse::{Ancestor, BoundIdentifier};
+use oxc_traverse::BoundIdentifier;
 
-use crate::{common::helper_loader::Helper, context::TraverseCtx};
+use crate::{
+    common::helper_loader::Helper, context::TraverseCtx, utils::is_multiple_statements_allowed,
+};
 
 use super::AsyncGeneratorFunctions;
 
 impl<'a> AsyncGeneratorFunctions<'a, '_> {
-    /// Check the parent node to see if multiple statements are allowed.
-    fn is_multiple_statements_allowed(ctx: &TraverseCtx<'a>) -> bool {
-        matches!(
-            ctx.parent(),
-            Ancestor::ProgramBody(_)
-                | Ancestor::FunctionBodyStatements(_)
-                | Ancestor::BlockStatementBody(_)
-                | Ancestor::SwitchCaseConsequent(_)
-                | Ancestor::StaticBlockBody(_)
-                | Ancestor::TSModuleBlockBody(_)
-        )
-    }
-
     pub(crate) fn transform_statement(&self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         let (for_of, label) = match stmt {
             Statement::LabeledStatement(labeled) => {
@@ -42,7 +31,7 @@ impl<'a> AsyncGeneratorFunctions<'a, '_> {
             return;
         }
 
-        let allow_multiple_statements = Self::is_multiple_statements_allowed(ctx);
+        let allow_multiple_statements = is_multiple_statements_allowed(ctx);
         let parent_scope_id = if allow_multiple_statements {
             ctx.current_scope_id()
         } else {
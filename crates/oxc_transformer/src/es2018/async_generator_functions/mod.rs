@@ -74,7 +74,7 @@ use oxc_traverse::{Ancestor, Traverse};
 use crate::{
     common::helper_loader::Helper,
     context::{TransformCtx, TraverseCtx},
-    es2017::AsyncGeneratorExecutor,
+    es2017::{AsyncGeneratorExecutor, GeneratorNaming},
     state::TransformState,
 };
 
@@ -85,7 +85,14 @@ pub struct AsyncGeneratorFunctions<'a, 'ctx> {
 
 impl<'a, 'ctx> AsyncGeneratorFunctions<'a, 'ctx> {
     pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
-        Self { ctx, executor: AsyncGeneratorExecutor::new(Helper::WrapAsyncGenerator, ctx) }
+        Self {
+            ctx,
+            executor: AsyncGeneratorExecutor::new(
+                Helper::WrapAsyncGenerator,
+                GeneratorNaming::Babel,
+                ctx,
+            ),
+        }
     }
 }
 
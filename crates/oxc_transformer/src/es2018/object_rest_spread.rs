@@ -18,6 +18,31 @@
 //! var y = _objectSpread({}, x, { c: 3 });
 //! ```
 //!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Enables the `setSpreadProperties`, `pureGetters` and `objectRestNoSymbols` compiler assumptions.
+//! Spread properties are assigned with `_extends` (`Object.assign`) instead of being defined,
+//! nested spreads are flattened into a single call, and symbol properties are not copied to rest
+//! elements.
+//!
+//! ```js
+//! var y = _extends({}, x, { c: 3 });
+//! var { a, ...rest } = obj; // var rest = _objectWithoutPropertiesLoose(obj, ["a"]);
+//! ```
+//!
+//! `ignoreFunctionLength`, which Babel also enables with `loose`, is not implemented, so
+//! functions keep their `length` as without `loose`.
+//!
+//! ### `useBuiltIns`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Use `Object.assign` directly, instead of the `_extends` helper.
+//!
 //! ## Implementation
 //!
 //! Implementation based on [@babel/plugin-transform-object-rest-spread](https://babeljs.io/docs/babel-plugin-transform-object-rest-spread).
@@ -37,8 +62,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_ecmascript::{
     BoundNames, ToJsString, is_global_reference::WithoutGlobalReferenceInformation,
 };
-use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
-use oxc_span::{GetSpan, SPAN};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::{Atom, GetSpan, SPAN};
 use oxc_traverse::{Ancestor, MaybeBoundIdentifier, Traverse};
 
 use crate::{
@@ -55,6 +80,40 @@ pub struct ObjectRestSpreadOptions {
     pub use_built_ins: bool,
 }
 
+impl ObjectRestSpreadOptions {
+    fn set_spread_properties(self, transform_ctx: &TransformCtx) -> bool {
+        self.loose || transform_ctx.assumptions.set_spread_properties
+    }
+
+    fn pure_getters(self, transform_ctx: &TransformCtx) -> bool {
+        self.loose || transform_ctx.assumptions.pure_getters
+    }
+
+    fn object_rest_no_symbols(self, transform_ctx: &TransformCtx) -> bool {
+        self.loose || transform_ctx.assumptions.object_rest_no_symbols
+    }
+
+    /// `_extends`, or `Object.assign` with `useBuiltIns`.
+    fn extends_callee<'a>(
+        self,
+        transform_ctx: &TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if !self.use_built_ins {
+            return transform_ctx.helper_load(Helper::Extends, ctx);
+        }
+        let object_symbol_id = ctx.scoping().find_binding(ctx.current_scope_id(), "Object");
+        let object = ctx.create_ident_expr(
+            SPAN,
+            Atom::from("Object"),
+            object_symbol_id,
+            ReferenceFlags::Read,
+        );
+        let property = ctx.ast.identifier_name(SPAN, "assign");
+        Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+    }
+}
+
 pub struct ObjectRestSpread<'a, 'ctx> {
     ctx: &'ctx TransformCtx<'a>,
 
@@ -65,21 +124,6 @@ pub struct ObjectRestSpread<'a, 'ctx> {
 
 impl<'a, 'ctx> ObjectRestSpread<'a, 'ctx> {
     pub fn new(options: ObjectRestSpreadOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
-        if options.loose {
-            ctx.error(OxcDiagnostic::error(
-                "Option `loose` is not implemented for object-rest-spread.",
-            ));
-        }
-        if options.use_built_ins {
-            ctx.error(OxcDiagnostic::error(
-                "Option `useBuiltIns` is not implemented for object-rest-spread.",
-            ));
-        }
-        if ctx.assumptions.object_rest_no_symbols {
-            ctx.error(OxcDiagnostic::error(
-                "Compiler assumption `objectRestNoSymbols` is not implemented for object-rest-spread.",
-            ));
-        }
         if ctx.assumptions.ignore_function_length {
             ctx.error(OxcDiagnostic::error(
                 "Compiler assumption `ignoreFunctionLength` is not implemented for object-rest-spread.",
@@ -268,6 +312,7 @@ impl<'a> ObjectRestSpread<'a, '_> {
             let (lhs, rhs) = datum.get_lhs_rhs(
                 &mut reference_builder,
                 &mut self.excluded_variable_declarators,
+                self.options,
                 self.ctx,
                 ctx,
             );
@@ -480,7 +525,7 @@ impl<'a, 'ctx> ObjectRestSpread<'a, 'ctx> {
     // Transform `({ x, ..y })`.
     // `pub` for jsx spread.
     pub fn transform_object_expression(
-        _options: ObjectRestSpreadOptions,
+        options: ObjectRestSpreadOptions,
         expr: &mut Expression<'a>,
         transform_ctx: &'ctx TransformCtx<'a>,
         ctx: &mut TraverseCtx<'a>,
@@ -496,7 +541,7 @@ impl<'a, 'ctx> ObjectRestSpread<'a, 'ctx> {
 
         for prop in obj_expr.properties.drain(..) {
             if let ObjectPropertyKind::SpreadProperty(mut spread_prop) = prop {
-                Self::make_object_spread(&mut call_expr, &mut props, transform_ctx, ctx);
+                Self::make_object_spread(&mut call_expr, &mut props, options, transform_ctx, ctx);
                 let arg = spread_prop.argument.take_in(ctx.ast);
                 call_expr.as_mut().unwrap().arguments.push(Argument::from(arg));
             } else {
@@ -505,7 +550,7 @@ impl<'a, 'ctx> ObjectRestSpread<'a, 'ctx> {
         }
 
         if !props.is_empty() {
-            Self::make_object_spread(&mut call_expr, &mut props, transform_ctx, ctx);
+            Self::make_object_spread(&mut call_expr, &mut props, options, transform_ctx, ctx);
         }
 
         *expr = Expression::CallExpression(call_expr.unwrap());
@@ -514,6 +559,7 @@ impl<'a, 'ctx> ObjectRestSpread<'a, 'ctx> {
     fn make_object_spread(
         expr: &mut Option<ArenaBox<'a, CallExpression<'a>>>,
         props: &mut ArenaVec<'a, ObjectPropertyKind<'a>>,
+        options: ObjectRestSpreadOptions,
         transform_ctx: &'ctx TransformCtx<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
@@ -523,6 +569,16 @@ impl<'a, 'ctx> ObjectRestSpread<'a, 'ctx> {
             // Reserve maximize might be used space for new vec
             mem::replace(props, ctx.ast.vec_with_capacity(props.capacity() - props.len())),
         );
+        // With `pureGetters`, add to the existing call instead of nesting calls.
+        // `_extends({}, a, { b })` rather than `_extends(_extends({}, a), {}, { b })`.
+        if options.pure_getters(transform_ctx) {
+            if let Some(call_expr) = expr.as_mut() {
+                if had_props {
+                    call_expr.arguments.push(Argument::from(obj));
+                }
+                return;
+            }
+        }
         let arguments = if let Some(call_expr) = expr.take() {
             let arg = Expression::CallExpression(call_expr);
             let arg = Argument::from(arg);
@@ -535,7 +591,12 @@ impl<'a, 'ctx> ObjectRestSpread<'a, 'ctx> {
         } else {
             ctx.ast.vec1(Argument::from(obj))
         };
-        let new_expr = transform_ctx.helper_call(Helper::ObjectSpread2, SPAN, arguments, ctx);
+        let new_expr = if options.set_spread_properties(transform_ctx) {
+            let callee = options.extends_callee(transform_ctx, ctx);
+            ctx.ast.call_expression(SPAN, callee, NONE, arguments, false)
+        } else {
+            transform_ctx.helper_call(Helper::ObjectSpread2, SPAN, arguments, ctx)
+        };
         expr.replace(ctx.ast.alloc(new_expr));
     }
 }
@@ -894,6 +955,7 @@ impl<'a> ObjectRestSpread<'a, '_> {
                 let (lhs, rhs) = datum.get_lhs_rhs(
                     &mut reference_builder,
                     &mut self.excluded_variable_declarators,
+                    self.options,
                     self.ctx,
                     ctx,
                 );
@@ -1081,6 +1143,7 @@ impl<'a> SpreadPair<'a> {
         self,
         reference_builder: &mut ReferenceBuilder<'a>,
         excluded_variable_declarators: &mut Vec<VariableDeclarator<'a>>,
+        options: ObjectRestSpreadOptions,
         transform_ctx: &TransformCtx<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) -> (BindingPatternOrAssignmentTarget<'a>, Expression<'a>) {
@@ -1107,7 +1170,8 @@ impl<'a> SpreadPair<'a> {
                     sequence
                 },
             )));
-            transform_ctx.helper_call_expr(Helper::Extends, SPAN, arguments, ctx)
+            let callee = options.extends_callee(transform_ctx, ctx);
+            ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
         } else {
             // / `let { a, b, ...c } = z` -> _objectWithoutProperties(_z, ["a", "b"]);
             // / `_objectWithoutProperties(_z, ["a", "b"])`
@@ -1148,7 +1212,12 @@ impl<'a> SpreadPair<'a> {
                 key_expression
             };
             arguments.push(Argument::from(key_expression));
-            transform_ctx.helper_call_expr(Helper::ObjectWithoutProperties, SPAN, arguments, ctx)
+            let helper = if options.object_rest_no_symbols(transform_ctx) {
+                Helper::ObjectWithoutPropertiesLoose
+            } else {
+                Helper::ObjectWithoutProperties
+            };
+            transform_ctx.helper_call_expr(helper, SPAN, arguments, ctx)
         };
         (self.lhs, rhs)
     }
@@ -10,8 +10,10 @@ use crate::{
 mod nullish_coalescing_operator;
 mod optional_chaining;
 mod options;
-use nullish_coalescing_operator::NullishCoalescingOperator;
-pub use optional_chaining::OptionalChaining;
+pub use nullish_coalescing_operator::{
+    NullishCoalescingOperator, NullishCoalescingOperatorOptions,
+};
+pub use optional_chaining::{OptionalChaining, OptionalChainingOptions};
 pub use options::ES2020Options;
 
 pub struct ES2020<'a, 'ctx> {
@@ -28,19 +30,25 @@ impl<'a, 'ctx> ES2020<'a, 'ctx> {
         Self {
             ctx,
             options,
-            nullish_coalescing_operator: NullishCoalescingOperator::new(ctx),
-            optional_chaining: OptionalChaining::new(ctx),
+            nullish_coalescing_operator: NullishCoalescingOperator::new(
+                options.nullish_coalescing_operator.unwrap_or_default(),
+                ctx,
+            ),
+            optional_chaining: OptionalChaining::new(
+                options.optional_chaining.unwrap_or_default(),
+                ctx,
+            ),
         }
     }
 }
 
 impl<'a> Traverse<'a, TransformState<'a>> for ES2020<'a, '_> {
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
-        if self.options.nullish_coalescing_operator {
+        if self.options.nullish_coalescing_operator.is_some() {
             self.nullish_coalescing_operator.enter_expression(expr, ctx);
         }
 
-        if self.options.optional_chaining {
+        if self.options.optional_chaining.is_some() {
             self.optional_chaining.enter_expression(expr, ctx);
         }
     }
@@ -50,7 +58,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for ES2020<'a, '_> {
         node: &mut FormalParameters<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
-        if self.options.optional_chaining {
+        if self.options.optional_chaining.is_some() {
             self.optional_chaining.enter_formal_parameters(node, ctx);
         }
     }
@@ -60,7 +68,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for ES2020<'a, '_> {
         node: &mut FormalParameters<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
-        if self.options.optional_chaining {
+        if self.options.optional_chaining.is_some() {
             self.optional_chaining.exit_formal_parameters(node, ctx);
         }
     }
@@ -20,6 +20,22 @@
 //!   : "default";
 //! ```
 //!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Check for `null` and `undefined` with a single `!= null` comparison, as with the
+//! `noDocumentAll` assumption.
+//!
+//! ```js
+//! var foo = object.foo ?? "default";
+//! // =>
+//! var _object$foo;
+//! var foo = (_object$foo = object.foo) != null ? _object$foo : "default";
+//! ```
+//!
 //! ## Implementation
 //!
 //! Implementation based on [@babel/plugin-transform-nullish-coalescing-operator](https://babeljs.io/docs/babel-plugin-transform-nullish-coalescing-operator).
@@ -28,6 +44,8 @@
 //! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-nullish-coalescing-operator>
 //! * Nullish coalescing TC39 proposal: <https://github.com/tc39-transfer/proposal-nullish-coalescing>
 
+use serde::Deserialize;
+
 use oxc_allocator::{Box as ArenaBox, TakeIn};
 use oxc_ast::{NONE, ast::*};
 use oxc_semantic::{ScopeFlags, SymbolFlags};
@@ -40,13 +58,21 @@ use crate::{
     state::TransformState,
 };
 
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NullishCoalescingOperatorOptions {
+    /// Check for `null` and `undefined` with `!= null`, ignoring `document.all`.
+    pub loose: bool,
+}
+
 pub struct NullishCoalescingOperator<'a, 'ctx> {
     ctx: &'ctx TransformCtx<'a>,
+    options: NullishCoalescingOperatorOptions,
 }
 
 impl<'a, 'ctx> NullishCoalescingOperator<'a, 'ctx> {
-    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
-        Self { ctx }
+    pub fn new(options: NullishCoalescingOperatorOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx, options }
     }
 }
 
@@ -68,6 +94,11 @@ impl<'a> Traverse<'a, TransformState<'a>> for NullishCoalescingOperator<'a, '_>
 }
 
 impl<'a> NullishCoalescingOperator<'a, '_> {
+    /// Whether `document.all` can be ignored, so `!= null` checks for both `null` and `undefined`.
+    fn no_document_all(&self) -> bool {
+        self.options.loose || self.ctx.assumptions.no_document_all
+    }
+
     fn transform_logical_expression(
         &self,
         logical_expr: ArenaBox<'a, LogicalExpression<'a>>,
@@ -79,9 +110,11 @@ impl<'a> NullishCoalescingOperator<'a, '_> {
         match &logical_expr.left {
             Expression::ThisExpression(this) => {
                 let this_span = this.span;
-                return Self::create_conditional_expression(
+                let reference1 =
+                    (!self.no_document_all()).then(|| ctx.ast.expression_this(this_span));
+                return self.create_conditional_expression(
                     logical_expr.left,
-                    ctx.ast.expression_this(this_span),
+                    reference1,
                     ctx.ast.expression_this(this_span),
                     logical_expr.right,
                     logical_expr.span,
@@ -96,9 +129,11 @@ impl<'a> NullishCoalescingOperator<'a, '_> {
                     if ctx.scoping().get_resolved_references(symbol_id).all(|r| !r.is_write()) {
                         let binding = BoundIdentifier::new(ident.name, symbol_id);
                         let ident_span = ident.span;
-                        return Self::create_conditional_expression(
+                        let reference1 = (!self.no_document_all())
+                            .then(|| binding.create_spanned_read_expression(ident_span, ctx));
+                        return self.create_conditional_expression(
                             logical_expr.left,
-                            binding.create_spanned_read_expression(ident_span, ctx),
+                            reference1,
                             binding.create_spanned_read_expression(ident_span, ctx),
                             logical_expr.right,
                             logical_expr.span,
@@ -135,9 +170,10 @@ impl<'a> NullishCoalescingOperator<'a, '_> {
             binding.create_write_target(ctx),
             logical_expr.left,
         );
-        let mut new_expr = Self::create_conditional_expression(
+        let reference1 = (!self.no_document_all()).then(|| binding.create_read_expression(ctx));
+        let mut new_expr = self.create_conditional_expression(
             assignment,
-            binding.create_read_expression(ctx),
+            reference1,
             binding.create_read_expression(ctx),
             logical_expr.right,
             logical_expr.span,
@@ -201,19 +237,31 @@ impl<'a> NullishCoalescingOperator<'a, '_> {
     /// //    ^^^^^^^^^^^^^^^^ assignment  ^^^^^^ reference1            ^^^^^ default
     /// //                                                     ^^^^^^ reference2
     /// ```
+    ///
+    /// `reference1` is `None` when `document.all` can be ignored:
+    ///
+    /// ```js
+    /// foo = (_bar$x = bar.x) != null ? _bar$x : "qux"
+    /// ```
     fn create_conditional_expression(
+        &self,
         assignment: Expression<'a>,
-        reference1: Expression<'a>,
+        reference1: Option<Expression<'a>>,
         reference2: Expression<'a>,
         default: Expression<'a>,
         span: Span,
         ctx: &TraverseCtx<'a>,
     ) -> Expression<'a> {
-        let op = BinaryOperator::StrictInequality;
         let null = ctx.ast.expression_null_literal(SPAN);
-        let left = ctx.ast.expression_binary(SPAN, assignment, op, null);
-        let right = ctx.ast.expression_binary(SPAN, reference1, op, ctx.ast.void_0(SPAN));
-        let test = ctx.ast.expression_logical(SPAN, left, LogicalOperator::And, right);
+        let test = if let Some(reference1) = reference1 {
+            let op = BinaryOperator::StrictInequality;
+            let left = ctx.ast.expression_binary(SPAN, assignment, op, null);
+            let right = ctx.ast.expression_binary(SPAN, reference1, op, ctx.ast.void_0(SPAN));
+            ctx.ast.expression_logical(SPAN, left, LogicalOperator::And, right)
+        } else {
+            debug_assert!(self.no_document_all());
+            ctx.ast.expression_binary(SPAN, assignment, BinaryOperator::Inequality, null)
+        };
 
         ctx.ast.expression_conditional(span, test, reference2, default)
     }
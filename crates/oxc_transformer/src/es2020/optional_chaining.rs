@@ -34,6 +34,21 @@
 //!   _foo$bar3 === void 0 ? true : delete _foo$bar3.baz;
 //! ```
 //!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Check for `null` and `undefined` with a single `== null` comparison, as with the
+//! `noDocumentAll` assumption.
+//!
+//! ```js
+//! foo?.bar;
+//! // =>
+//! foo == null ? void 0 : foo.bar;
+//! ```
+//!
 //! ## Implementation
 //!
 //! Due to the different architecture, we found it hard to port the implementation from Babel directly;
@@ -49,6 +64,8 @@
 
 use std::mem;
 
+use serde::Deserialize;
+
 use oxc_allocator::{CloneIn, TakeIn};
 use oxc_ast::{NONE, ast::*};
 use oxc_span::SPAN;
@@ -70,8 +87,16 @@ enum CallContext<'a> {
     Binding(MaybeBoundIdentifier<'a>),
 }
 
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct OptionalChainingOptions {
+    /// Check for `null` and `undefined` with `== null`, ignoring `document.all`.
+    pub loose: bool,
+}
+
 pub struct OptionalChaining<'a, 'ctx> {
     ctx: &'ctx TransformCtx<'a>,
+    options: OptionalChainingOptions,
 
     // states
     is_inside_function_parameter: bool,
@@ -82,9 +107,10 @@ pub struct OptionalChaining<'a, 'ctx> {
 }
 
 impl<'a, 'ctx> OptionalChaining<'a, 'ctx> {
-    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+    pub fn new(options: OptionalChainingOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
         Self {
             ctx,
+            options,
             is_inside_function_parameter: false,
             temp_binding: None,
             call_context: CallContext::None,
@@ -126,6 +152,11 @@ impl<'a> Traverse<'a, TransformState<'a>> for OptionalChaining<'a, '_> {
 }
 
 impl<'a> OptionalChaining<'a, '_> {
+    /// Whether `document.all` can be ignored, so `== null` checks for both `null` and `undefined`.
+    fn no_document_all(&self) -> bool {
+        self.options.loose || self.ctx.assumptions.no_document_all
+    }
+
     fn set_temp_binding(&mut self, binding: BoundIdentifier<'a>) {
         self.temp_binding.replace(binding);
     }
@@ -193,7 +224,7 @@ impl<'a> OptionalChaining<'a, '_> {
 
     /// Return `left === null`
     fn wrap_null_check(&self, left: Expression<'a>, ctx: &TraverseCtx<'a>) -> Expression<'a> {
-        let operator = if self.ctx.assumptions.no_document_all {
+        let operator = if self.no_document_all() {
             BinaryOperator::Equality
         } else {
             BinaryOperator::StrictEquality
@@ -542,7 +573,7 @@ impl<'a> OptionalChaining<'a, '_> {
                 }
 
                 let left1 = binding.create_read_expression(ctx);
-                let replacement = if self.ctx.assumptions.no_document_all {
+                let replacement = if self.no_document_all() {
                     // `foo === null`
                     self.wrap_null_check(left1, ctx)
                 } else {
@@ -566,7 +597,7 @@ impl<'a> OptionalChaining<'a, '_> {
         // `(binding = expr)`
         let assignment_expression =
             Self::create_assignment_expression(temp_binding.create_write_target(ctx), expr, ctx);
-        let expr = if self.ctx.assumptions.no_document_all {
+        let expr = if self.no_document_all() {
             // `(binding = expr) === null`
             self.wrap_null_check(assignment_expression, ctx)
         } else {
@@ -627,7 +658,7 @@ impl<'a> OptionalChaining<'a, '_> {
             ctx,
         );
 
-        if self.ctx.assumptions.no_document_all {
+        if self.no_document_all() {
             left
         } else {
             let reference = temp_binding.create_read_expression(ctx);
@@ -1,14 +1,16 @@
 use serde::Deserialize;
 
+use super::{NullishCoalescingOperatorOptions, OptionalChainingOptions};
+
 #[derive(Debug, Default, Clone, Copy, Deserialize)]
 #[serde(default, rename_all = "camelCase", deny_unknown_fields)]
 pub struct ES2020Options {
     #[serde(skip)]
-    pub nullish_coalescing_operator: bool,
+    pub nullish_coalescing_operator: Option<NullishCoalescingOperatorOptions>,
 
     #[serde(skip)]
     pub big_int: bool,
 
     #[serde(skip)]
-    pub optional_chaining: bool,
+    pub optional_chaining: Option<OptionalChainingOptions>,
 }
@@ -1,5 +1,30 @@
 //! Transformer / Transpiler
 //!
+//! ## Usage
+//!
+//! [`Transformer::build_with_scoping`] transforms a parsed [`Program`] in place, and returns
+//! the updated [`Scoping`]. The program stays in the caller's arena, so custom
+//! [`Traverse`] passes can run before and after the transform on the same AST,
+//! without printing and re-parsing in between.
+//!
+//! ```ignore
+//! let allocator = Allocator::default();
+//! let mut program = Parser::new(&allocator, source_text, source_type).parse().program;
+//! let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+//!
+//! // Lower async functions to generators.
+//! let options = TransformOptions::from(ESTarget::ES2016);
+//! let ret = Transformer::new(&allocator, path, &options).build_with_scoping(scoping, &mut program);
+//! assert!(ret.errors.is_empty());
+//!
+//! // Continue with a custom pass, using the `Scoping` returned by the transformer.
+//! let scoping = oxc_traverse::traverse_mut(&mut MyPass, &allocator, &mut program, ret.scoping, ());
+//! ```
+//!
+//! The transformer does not require the input `Scoping` to come from a fresh `SemanticBuilder`
+//! run, but it must be accurate for `program`. A pass which adds or removes bindings or
+//! references must keep `Scoping` in sync, as [`TraverseCtx`](oxc_traverse::TraverseCtx) does.
+//!
 //! References:
 //! * <https://www.typescriptlang.org/tsconfig#target>
 //! * <https://babel.dev/docs/presets>
@@ -10,8 +35,8 @@ use std::path::Path;
 use oxc_allocator::{Allocator, TakeIn, Vec as ArenaVec};
 use oxc_ast::{AstBuilder, ast::*};
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_semantic::Scoping;
-use oxc_span::SPAN;
+use oxc_semantic::{ScopeId, Scoping};
+use oxc_span::{SPAN, Span};
 use oxc_traverse::{Traverse, traverse_mut};
 
 // Core
@@ -62,17 +87,24 @@ pub use crate::{
     common::helper_loader::{Helper, HelperLoaderMode, HelperLoaderOptions},
     compiler_assumptions::CompilerAssumptions,
     decorator::DecoratorOptions,
-    es2015::{ArrowFunctionsOptions, ES2015Options},
+    es2015::{
+        ArrowFunctionsOptions, BlockScopingOptions, ES2015Options, ForOfOptions,
+        TemplateLiteralsOptions,
+    },
     es2016::ES2016Options,
-    es2017::ES2017Options,
-    es2018::ES2018Options,
+    es2017::{
+        AsyncDecision, AsyncFunctionKind, AsyncStrategy, DecisionChange, DecisionLog,
+        DecisionLogReport, ES2017Options, GeneratorNaming, HelperFlavor, compare_decision_logs,
+    },
+    es2018::{ES2018Options, ObjectRestSpreadOptions},
     es2019::ES2019Options,
-    es2020::ES2020Options,
+    es2020::{ES2020Options, NullishCoalescingOperatorOptions, OptionalChainingOptions},
     es2021::ES2021Options,
     es2022::{ClassPropertiesOptions, ES2022Options},
     jsx::{JsxOptions, JsxRuntime, ReactRefreshOptions},
     options::{
-        ESTarget, Engine, EngineTargets, EnvOptions, Module, TransformOptions,
+        ESTarget, Engine, EngineTargets, EnvOptions, Module, OptionMeta, OptionType,
+        PluginOptionsMeta, TransformOptions,
         babel::{BabelEnvOptions, BabelOptions},
     },
     plugins::{PluginsOptions, StyledComponentsOptions},
@@ -87,6 +119,11 @@ pub struct TransformerReturn {
     /// Helpers used by this transform.
     #[deprecated = "Internal usage only"]
     pub helpers_used: FxHashMap<Helper, String>,
+    /// How async functions were lowered. Empty unless [`ES2017Options::decision_log`] is enabled.
+    pub decision_log: DecisionLog,
+    /// Spans of the original `async` keyword of async functions lowered to generator functions,
+    /// keyed by the scope of the generator function.
+    pub async_keyword_spans: FxHashMap<ScopeId, Span>,
 }
 
 pub struct Transformer<'a> {
@@ -111,11 +148,15 @@ impl<'a> Transformer<'a> {
             decorator: options.decorator,
             plugins: options.plugins.clone(),
             jsx: options.jsx.clone(),
-            env: options.env,
+            env: options.env.clone(),
             proposals: options.proposals,
         }
     }
 
+    /// Transform `program` in place.
+    ///
+    /// `scoping` must describe `program`. It is updated along with the AST, and returned in
+    /// [`TransformerReturn::scoping`], ready for further passes over the same `program`.
     pub fn build_with_scoping(
         mut self,
         scoping: Scoping,
@@ -126,6 +167,7 @@ impl<'a> Transformer<'a> {
 
         self.ctx.source_type = program.source_type;
         self.ctx.source_text = program.source_text;
+        self.ctx.helper_loader.collect_existing_helpers(program, &scoping);
 
         if program.source_type.is_jsx() {
             jsx::update_options_with_comments(
@@ -160,7 +202,7 @@ impl<'a> Transformer<'a> {
             x2_es2019: ES2019::new(self.env.es2019),
             x2_es2018: ES2018::new(self.env.es2018, &self.ctx),
             x2_es2016: ES2016::new(self.env.es2016, &self.ctx),
-            x2_es2017: ES2017::new(self.env.es2017, &self.ctx),
+            x2_es2017: ES2017::new(self.env.es2017, ast_builder, &self.ctx),
             x3_es2015: ES2015::new(self.env.es2015, &self.ctx),
             x4_regexp: RegExp::new(self.env.regexp, &self.ctx),
         };
@@ -168,8 +210,16 @@ impl<'a> Transformer<'a> {
         let state = TransformState::default();
         let scoping = traverse_mut(&mut transformer, allocator, program, scoping, state);
         let helpers_used = self.ctx.helper_loader.used_helpers.borrow_mut().drain().collect();
+        let decision_log = self.ctx.decision_log.take();
+        let async_keyword_spans = self.ctx.async_keyword_spans.take();
         #[expect(deprecated)]
-        TransformerReturn { errors: self.ctx.take_errors(), scoping, helpers_used }
+        TransformerReturn {
+            errors: self.ctx.take_errors(),
+            scoping,
+            helpers_used,
+            decision_log,
+            async_keyword_spans,
+        }
     }
 }
 
@@ -187,7 +237,6 @@ struct TransformerImpl<'a, 'ctx> {
     x2_es2018: ES2018<'a, 'ctx>,
     x2_es2017: ES2017<'a, 'ctx>,
     x2_es2016: ES2016<'a, 'ctx>,
-    #[expect(unused)]
     x3_es2015: ES2015<'a, 'ctx>,
     x4_regexp: RegExp<'a, 'ctx>,
     common: Common<'a, 'ctx>,
@@ -221,6 +270,8 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         arrow: &mut ArrowFunctionExpression<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        // Runs first, so the decision log fingerprints the function before other plugins change it.
+        self.x2_es2017.enter_arrow_function_expression(arrow, ctx);
         self.common.enter_arrow_function_expression(arrow, ctx);
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_arrow_function_expression(arrow, ctx);
@@ -234,6 +285,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.x2_es2018.enter_variable_declaration(decl, ctx);
+        self.x3_es2015.enter_variable_declaration(decl, ctx);
     }
 
     fn enter_variable_declarator(
@@ -257,6 +309,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.common.enter_binding_identifier(node, ctx);
+        self.x3_es2015.enter_binding_identifier(node, ctx);
     }
 
     fn enter_identifier_reference(
@@ -265,6 +318,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.common.enter_identifier_reference(node, ctx);
+        self.x3_es2015.enter_identifier_reference(node, ctx);
     }
 
     fn enter_binding_pattern(&mut self, pat: &mut BindingPattern<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -340,6 +394,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.x2_es2022.exit_expression(expr, ctx);
         self.x2_es2018.exit_expression(expr, ctx);
         self.x2_es2017.exit_expression(expr, ctx);
+        self.x3_es2015.exit_expression(expr, ctx);
     }
 
     fn enter_simple_assignment_target(
@@ -390,6 +445,8 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
     }
 
     fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Runs first, so the decision log fingerprints the function before other plugins change it.
+        self.x2_es2017.enter_function(func, ctx);
         self.common.enter_function(func, ctx);
         self.x2_es2018.enter_function(func, ctx);
     }
@@ -565,6 +622,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.decorator.exit_statement(stmt, ctx);
         self.x2_es2018.exit_statement(stmt, ctx);
         self.x2_es2017.exit_statement(stmt, ctx);
+        self.x3_es2015.exit_statement(stmt, ctx);
     }
 
     fn enter_tagged_template_expression(
@@ -585,6 +643,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         if let Some(explicit_resource_management) = self.explicit_resource_management.as_mut() {
             explicit_resource_management.enter_statement(stmt, ctx);
         }
+        self.x3_es2015.enter_statement(stmt, ctx);
     }
 
     fn enter_declaration(&mut self, decl: &mut Declaration<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -593,6 +652,18 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         }
     }
 
+    fn enter_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.enter_block_statement(block, ctx);
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.enter_switch_statement(stmt, ctx);
+    }
+
     fn enter_if_statement(&mut self, stmt: &mut IfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_if_statement(stmt, ctx);
@@ -619,6 +690,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_for_statement(stmt, ctx);
         }
+        self.x3_es2015.enter_for_statement(stmt, ctx);
     }
 
     fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -629,6 +701,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
             explicit_resource_management.enter_for_of_statement(stmt, ctx);
         }
         self.x2_es2018.enter_for_of_statement(stmt, ctx);
+        self.x3_es2015.enter_for_of_statement(stmt, ctx);
     }
 
     fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -636,6 +709,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
             typescript.enter_for_in_statement(stmt, ctx);
         }
         self.x2_es2018.enter_for_in_statement(stmt, ctx);
+        self.x3_es2015.enter_for_in_statement(stmt, ctx);
     }
 
     fn enter_try_statement(&mut self, stmt: &mut TryStatement<'a>, ctx: &mut TraverseCtx<'a>) {
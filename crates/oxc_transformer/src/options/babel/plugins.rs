@@ -1,8 +1,13 @@
 use serde::Deserialize;
 
 use crate::{
-    DecoratorOptions, TypeScriptOptions, es2015::ArrowFunctionsOptions,
-    es2018::ObjectRestSpreadOptions, es2022::ClassPropertiesOptions, jsx::JsxOptions,
+    DecoratorOptions, TypeScriptOptions,
+    es2015::{ArrowFunctionsOptions, BlockScopingOptions, ForOfOptions, TemplateLiteralsOptions},
+    es2017::ES2017Options,
+    es2018::ObjectRestSpreadOptions,
+    es2020::{NullishCoalescingOperatorOptions, OptionalChainingOptions},
+    es2022::ClassPropertiesOptions,
+    jsx::JsxOptions,
     plugins::StyledComponentsOptions,
 };
 
@@ -53,18 +58,22 @@ pub struct BabelPlugins {
     pub set_notation: bool,
     // ES2015
     pub arrow_function: Option<ArrowFunctionsOptions>,
+    pub block_scoping: Option<BlockScopingOptions>,
+    pub for_of: Option<ForOfOptions>,
+    pub template_literals: Option<TemplateLiteralsOptions>,
     // ES2016
     pub exponentiation_operator: bool,
     // ES2017
     pub async_to_generator: bool,
+    pub async_to_generator_options: Option<ES2017Options>,
     // ES2018
     pub object_rest_spread: Option<ObjectRestSpreadOptions>,
     pub async_generator_functions: bool,
     // ES2019
     pub optional_catch_binding: bool,
     // ES2020
-    pub optional_chaining: bool,
-    pub nullish_coalescing_operator: bool,
+    pub optional_chaining: Option<OptionalChainingOptions>,
+    pub nullish_coalescing_operator: Option<NullishCoalescingOperatorOptions>,
     // ES2021
     pub logical_assignment_operators: bool,
     // ES2022
@@ -138,8 +147,25 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                         .map_err(|err| p.errors.push(err))
                         .ok();
                 }
+                "transform-block-scoping" => {
+                    p.block_scoping =
+                        entry.value::<BlockScopingOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-for-of" => {
+                    p.for_of = entry.value::<ForOfOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-template-literals" => {
+                    p.template_literals = entry
+                        .value::<TemplateLiteralsOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
                 "transform-exponentiation-operator" => p.exponentiation_operator = true,
-                "transform-async-to-generator" => p.async_to_generator = true,
+                "transform-async-to-generator" => {
+                    p.async_to_generator = true;
+                    p.async_to_generator_options =
+                        entry.value::<ES2017Options>().map_err(|err| p.errors.push(err)).ok();
+                }
                 "transform-object-rest-spread" => {
                     p.object_rest_spread = entry
                         .value::<ObjectRestSpreadOptions>()
@@ -148,8 +174,18 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                 }
                 "transform-async-generator-functions" => p.async_generator_functions = true,
                 "transform-optional-catch-binding" => p.optional_catch_binding = true,
-                "transform-optional-chaining" => p.optional_chaining = true,
-                "transform-nullish-coalescing-operator" => p.nullish_coalescing_operator = true,
+                "transform-optional-chaining" => {
+                    p.optional_chaining = entry
+                        .value::<OptionalChainingOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "transform-nullish-coalescing-operator" => {
+                    p.nullish_coalescing_operator = entry
+                        .value::<NullishCoalescingOperatorOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
                 "transform-logical-assignment-operators" => p.logical_assignment_operators = true,
                 "transform-class-static-block" => p.class_static_block = true,
                 "transform-class-properties" => {
@@ -4,12 +4,15 @@ use serde::Deserialize;
 
 use crate::{
     EngineTargets,
-    es2015::{ArrowFunctionsOptions, ES2015Options},
+    es2015::{
+        ArrowFunctionsOptions, BlockScopingOptions, ES2015Options, ForOfOptions,
+        TemplateLiteralsOptions,
+    },
     es2016::ES2016Options,
     es2017::ES2017Options,
     es2018::{ES2018Options, ObjectRestSpreadOptions},
     es2019::ES2019Options,
-    es2020::ES2020Options,
+    es2020::{ES2020Options, NullishCoalescingOperatorOptions, OptionalChainingOptions},
     es2021::ES2021Options,
     es2022::{ClassPropertiesOptions, ES2022Options},
     regexp::RegExpOptions,
@@ -17,7 +20,7 @@ use crate::{
 
 use super::{ESFeature, ESTarget, Engine, Module, babel::BabelEnvOptions};
 
-#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[derive(Debug, Default, Clone, Deserialize)]
 #[serde(try_from = "BabelEnvOptions")]
 pub struct EnvOptions {
     /// Specify what module code is generated.
@@ -67,19 +70,34 @@ impl EnvOptions {
                 } else {
                     None
                 },
+                block_scoping: if include_unfinished_plugins {
+                    Some(BlockScopingOptions::default())
+                } else {
+                    None
+                },
+                for_of: if include_unfinished_plugins {
+                    Some(ForOfOptions::default())
+                } else {
+                    None
+                },
+                template_literals: if include_unfinished_plugins {
+                    Some(TemplateLiteralsOptions::default())
+                } else {
+                    None
+                },
             },
             es2016: ES2016Options { exponentiation_operator: true },
-            es2017: ES2017Options { async_to_generator: true },
+            es2017: ES2017Options { async_to_generator: true, ..Default::default() },
             es2018: ES2018Options {
                 object_rest_spread: Some(ObjectRestSpreadOptions::default()),
                 async_generator_functions: true,
             },
             es2019: ES2019Options { optional_catch_binding: true },
             es2020: ES2020Options {
-                nullish_coalescing_operator: true,
+                nullish_coalescing_operator: Some(NullishCoalescingOperatorOptions::default()),
                 // Turn this on would throw error for all bigints.
                 big_int: false,
-                optional_chaining: true,
+                optional_chaining: Some(OptionalChainingOptions::default()),
             },
             es2021: ES2021Options { logical_assignment_operators: true },
             es2022: ES2022Options {
@@ -164,11 +182,18 @@ impl From<EngineTargets> for EnvOptions {
             },
             es2015: ES2015Options {
                 arrow_function: o.has_feature(ES2015ArrowFunctions).then(Default::default),
+                // Turned off because loops with closures are not lowered yet.
+                block_scoping: None,
+                for_of: o.has_feature(ES2015ForOf).then(Default::default),
+                template_literals: o.has_feature(ES2015TemplateLiterals).then(Default::default),
             },
             es2016: ES2016Options {
                 exponentiation_operator: o.has_feature(ES2016ExponentiationOperator),
             },
-            es2017: ES2017Options { async_to_generator: o.has_feature(ES2017AsyncToGenerator) },
+            es2017: ES2017Options {
+                async_to_generator: o.has_feature(ES2017AsyncToGenerator),
+                ..Default::default()
+            },
             es2018: ES2018Options {
                 object_rest_spread: o.has_feature(ES2018ObjectRestSpread).then(Default::default),
                 async_generator_functions: o.has_feature(ES2018AsyncGeneratorFunctions),
@@ -177,9 +202,11 @@ impl From<EngineTargets> for EnvOptions {
                 optional_catch_binding: o.has_feature(ES2019OptionalCatchBinding),
             },
             es2020: ES2020Options {
-                nullish_coalescing_operator: o.has_feature(ES2020NullishCoalescingOperator),
+                nullish_coalescing_operator: o
+                    .has_feature(ES2020NullishCoalescingOperator)
+                    .then(Default::default),
                 big_int: o.has_feature(ES2020BigInt),
-                optional_chaining: o.has_feature(ES2020OptionalChaining),
+                optional_chaining: o.has_feature(ES2020OptionalChaining).then(Default::default),
             },
             es2021: ES2021Options {
                 logical_assignment_operators: o.has_feature(ES2021LogicalAssignmentOperators),
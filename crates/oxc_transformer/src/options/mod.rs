@@ -28,6 +28,7 @@ mod env;
 mod es_features;
 mod es_target;
 mod module;
+pub mod plugin_options;
 
 use babel::BabelOptions;
 pub use browserslist_query::BrowserslistQuery;
@@ -37,6 +38,7 @@ pub use env::EnvOptions;
 pub use es_features::ESFeature;
 pub use es_target::ESTarget;
 pub use module::Module;
+pub use plugin_options::{OptionMeta, OptionType, PluginOptionsMeta};
 
 /// <https://babel.dev/docs/options>
 #[derive(Debug, Default, Clone)]
@@ -186,7 +188,7 @@ impl TryFrom<&BabelOptions> for TransformOptions {
             jsx_options
         };
 
-        let env = options.presets.env.unwrap_or_default();
+        let env = options.presets.env.clone().unwrap_or_default();
 
         let module = Module::try_from(&options.plugins).unwrap_or_else(|_| {
             options.presets.env.as_ref().map(|env| env.module).unwrap_or_default()
@@ -208,6 +210,9 @@ impl TryFrom<&BabelOptions> for TransformOptions {
 
         let es2015 = ES2015Options {
             arrow_function: options.plugins.arrow_function.or(env.es2015.arrow_function),
+            block_scoping: options.plugins.block_scoping.or(env.es2015.block_scoping),
+            for_of: options.plugins.for_of.or(env.es2015.for_of),
+            template_literals: options.plugins.template_literals.or(env.es2015.template_literals),
         };
 
         let es2016 = ES2016Options {
@@ -217,6 +222,22 @@ impl TryFrom<&BabelOptions> for TransformOptions {
 
         let es2017 = ES2017Options {
             async_to_generator: options.plugins.async_to_generator || env.es2017.async_to_generator,
+            span_filter: env.es2017.span_filter,
+            decision_log: env.es2017.decision_log,
+            generator_naming: options
+                .plugins
+                .async_to_generator_options
+                .as_ref()
+                .map_or(env.es2017.generator_naming, |options| options.generator_naming),
+            helper_name: options.plugins.async_to_generator_options.as_ref().map_or_else(
+                || env.es2017.helper_name.clone(),
+                |options| options.helper_name.clone(),
+            ),
+            helper: options
+                .plugins
+                .async_to_generator_options
+                .as_ref()
+                .map_or(env.es2017.helper, |options| options.helper),
         };
 
         let es2018 = ES2018Options {
@@ -234,9 +255,11 @@ impl TryFrom<&BabelOptions> for TransformOptions {
         };
 
         let es2020 = ES2020Options {
-            optional_chaining: options.plugins.optional_chaining || env.es2020.optional_chaining,
-            nullish_coalescing_operator: options.plugins.nullish_coalescing_operator
-                || env.es2020.nullish_coalescing_operator,
+            optional_chaining: options.plugins.optional_chaining.or(env.es2020.optional_chaining),
+            nullish_coalescing_operator: options
+                .plugins
+                .nullish_coalescing_operator
+                .or(env.es2020.nullish_coalescing_operator),
             big_int: env.es2020.big_int,
         };
 
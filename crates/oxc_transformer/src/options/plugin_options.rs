@@ -0,0 +1,377 @@
+//! Declarative definition of plugin options.
+//!
+//! [`declare_plugin_options!`] defines a plugin's options struct together with metadata about
+//! each user-facing option (doc text, default value, and the version it was added in).
+//! That metadata is the single source of truth for:
+//!
+//! * The options struct's `Deserialize` impl and its defaults.
+//! * A JSON schema fragment for config files.
+//! * A TypeScript typings snippet for the NAPI package.
+//! * A markdown table for the docs.
+//!
+//! The last three are checked in to a `generated` directory next to the options struct.
+//! A test asserts they are up to date. To regenerate them, run:
+//!
+//! ```sh
+//! UPDATE_GENERATED=1 cargo test -p oxc_transformer generated_options
+//! ```
+
+/// Metadata about a single user-facing option of a plugin.
+#[derive(Debug, Clone, Copy)]
+pub struct OptionMeta {
+    /// Name of the field in the Rust struct (snake case).
+    pub field: &'static str,
+    /// Lines of the field's doc comment.
+    pub doc: &'static [&'static str],
+    /// Version of Oxc which the option was added in.
+    pub since: &'static str,
+    /// TypeScript type of the option.
+    pub ts_type: fn() -> String,
+    /// JSON schema of the option's value.
+    pub json_schema: fn() -> serde_json::Value,
+}
+
+/// Implemented by options structs defined with [`declare_plugin_options!`].
+pub trait PluginOptionsMeta: Default + serde::Serialize {
+    /// Name of the options struct.
+    const NAME: &'static str;
+    /// Metadata of user-facing options, in declaration order.
+    const OPTIONS: &'static [OptionMeta];
+}
+
+/// Types which can be used for user-facing plugin options.
+pub trait OptionType {
+    fn ts_type() -> String;
+
+    fn json_schema() -> serde_json::Value;
+}
+
+impl OptionType for bool {
+    fn ts_type() -> String {
+        "boolean".to_string()
+    }
+
+    fn json_schema() -> serde_json::Value {
+        serde_json::json!({ "type": "boolean" })
+    }
+}
+
+impl OptionType for String {
+    fn ts_type() -> String {
+        "string".to_string()
+    }
+
+    fn json_schema() -> serde_json::Value {
+        serde_json::json!({ "type": "string" })
+    }
+}
+
+impl<A: OptionType, B: OptionType> OptionType for (A, B) {
+    fn ts_type() -> String {
+        format!("[{}, {}]", A::ts_type(), B::ts_type())
+    }
+
+    fn json_schema() -> serde_json::Value {
+        serde_json::json!({
+            "type": "array",
+            "items": [A::json_schema(), B::json_schema()],
+            "minItems": 2,
+            "maxItems": 2,
+        })
+    }
+}
+
+impl<T: OptionType> OptionType for Option<T> {
+    fn ts_type() -> String {
+        format!("{} | null", T::ts_type())
+    }
+
+    fn json_schema() -> serde_json::Value {
+        serde_json::json!({ "anyOf": [T::json_schema(), { "type": "null" }] })
+    }
+}
+
+/// Define a plugin options struct.
+///
+/// Fields marked `#[internal]` are not user-facing. They are skipped by serde, default to
+/// [`Default::default`], and do not appear in generated files.
+///
+/// All other fields must have a doc comment and an `#[option(default = ..., since = "...")]`
+/// attribute.
+///
+/// ```ignore
+/// declare_plugin_options! {
+///     #[derive(Debug, Clone)]
+///     pub struct FooOptions {
+///         #[internal]
+///         pub enabled: bool,
+///
+///         /// Do the thing.
+///         #[option(default = false, since = "0.1.0")]
+///         pub do_the_thing: bool,
+///     }
+/// }
+/// ```
+macro_rules! declare_plugin_options {
+    (
+        $(#[$struct_attr:meta])*
+        pub struct $name:ident {
+            $(
+                #[internal]
+                $(#[doc = $internal_doc:literal])*
+                pub $internal_field:ident: $internal_ty:ty,
+            )*
+            $(
+                $(#[doc = $doc:literal])+
+                #[option(default = $default:expr, since = $since:literal)]
+                pub $field:ident: $ty:ty,
+            )*
+        }
+    ) => {
+        $(#[$struct_attr])*
+        #[derive(serde::Deserialize, serde::Serialize)]
+        #[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+        pub struct $name {
+            $(
+                $(#[doc = $internal_doc])*
+                #[serde(skip)]
+                pub $internal_field: $internal_ty,
+            )*
+            $(
+                $(#[doc = $doc])+
+                pub $field: $ty,
+            )*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self {
+                    $($internal_field: Default::default(),)*
+                    $($field: $default,)*
+                }
+            }
+        }
+
+        impl $crate::options::plugin_options::PluginOptionsMeta for $name {
+            const NAME: &'static str = stringify!($name);
+            const OPTIONS: &'static [$crate::options::plugin_options::OptionMeta] = &[
+                $(
+                    $crate::options::plugin_options::OptionMeta {
+                        field: stringify!($field),
+                        doc: &[$($doc),+],
+                        since: $since,
+                        ts_type: <$ty as $crate::options::plugin_options::OptionType>::ts_type,
+                        json_schema:
+                            <$ty as $crate::options::plugin_options::OptionType>::json_schema,
+                    },
+                )*
+            ];
+        }
+    };
+}
+
+pub(crate) use declare_plugin_options;
+
+#[cfg(test)]
+mod generated_options {
+    use std::{fmt::Write, fs, path::Path};
+
+    use cow_utils::CowUtils;
+    use serde_json::{Map, Value};
+
+    use super::{OptionMeta, PluginOptionsMeta};
+    use crate::es2017::ES2017Options;
+
+    fn camel_case(field: &str) -> String {
+        let mut out = String::with_capacity(field.len());
+        let mut upper = false;
+        for c in field.chars() {
+            if c == '_' {
+                upper = true;
+            } else if upper {
+                out.push(c.to_ascii_uppercase());
+                upper = false;
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    fn doc_text(option: &OptionMeta) -> String {
+        option.doc.iter().map(|line| line.trim()).collect::<Vec<_>>().join("\n")
+    }
+
+    /// Default values of all user-facing options, keyed by camel case name.
+    fn defaults<T: PluginOptionsMeta>() -> Map<String, Value> {
+        match serde_json::to_value(T::default()).unwrap() {
+            Value::Object(map) => map,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Rebuild all objects in `value` with their keys in sorted order.
+    ///
+    /// `serde_json::Map` preserves insertion order when another crate in the workspace enables
+    /// serde_json's `preserve_order` feature, so sort explicitly to produce the same output
+    /// however this crate is built.
+    fn sort_keys(value: Value) -> Value {
+        match value {
+            Value::Object(map) => {
+                let mut entries = map.into_iter().collect::<Vec<_>>();
+                entries.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+                Value::Object(entries.into_iter().map(|(k, v)| (k, sort_keys(v))).collect())
+            }
+            Value::Array(values) => Value::Array(values.into_iter().map(sort_keys).collect()),
+            value => value,
+        }
+    }
+
+    fn json_schema<T: PluginOptionsMeta>() -> String {
+        let defaults = defaults::<T>();
+        let properties = T::OPTIONS
+            .iter()
+            .map(|option| {
+                let name = camel_case(option.field);
+                let mut schema = (option.json_schema)();
+                let schema_object = schema.as_object_mut().unwrap();
+                schema_object.insert("description".into(), doc_text(option).into());
+                schema_object.insert("default".into(), defaults[&name].clone());
+                (name, schema)
+            })
+            .collect::<Map<_, _>>();
+        let schema = serde_json::json!({
+            "title": T::NAME,
+            "type": "object",
+            "properties": properties,
+            "additionalProperties": false,
+        });
+        let mut out = serde_json::to_string_pretty(&sort_keys(schema)).unwrap();
+        out.push('\n');
+        out
+    }
+
+    fn typescript<T: PluginOptionsMeta>() -> String {
+        let defaults = defaults::<T>();
+        let mut out = format!("export interface {} {{\n", T::NAME);
+        for option in T::OPTIONS {
+            let name = camel_case(option.field);
+            out.push_str("  /**\n");
+            for line in doc_text(option).lines() {
+                if line.is_empty() {
+                    out.push_str("   *\n");
+                } else {
+                    writeln!(out, "   * {line}").unwrap();
+                }
+            }
+            writeln!(
+                out,
+                "   *\n   * @default {}\n   * @since {}\n   */",
+                defaults[&name], option.since
+            )
+            .unwrap();
+            writeln!(out, "  {name}?: {};", (option.ts_type)()).unwrap();
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    fn markdown<T: PluginOptionsMeta>() -> String {
+        let defaults = defaults::<T>();
+        let mut out = format!("# {}\n\n", T::NAME);
+        if T::OPTIONS.is_empty() {
+            out.push_str("This plugin has no user-facing options.\n");
+            return out;
+        }
+        out.push_str("| Option | Type | Default | Since | Description |\n");
+        out.push_str("| --- | --- | --- | --- | --- |\n");
+        for option in T::OPTIONS {
+            let name = camel_case(option.field);
+            let description = doc_text(option);
+            writeln!(
+                out,
+                "| `{name}` | `{}` | `{}` | {} | {} |",
+                (option.ts_type)().cow_replace('|', "\\|"),
+                defaults[&name],
+                option.since,
+                description
+                    .lines()
+                    .filter(|line| !line.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+                    .cow_replace('|', "\\|"),
+            )
+            .unwrap();
+        }
+        out
+    }
+
+    fn check_generated<T: PluginOptionsMeta>(dir: &str) {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join(dir).join("generated");
+        let update = std::env::var_os("UPDATE_GENERATED").is_some();
+        let files = [
+            ("options.schema.json", json_schema::<T>()),
+            ("options.d.ts", typescript::<T>()),
+            ("options.md", markdown::<T>()),
+        ];
+        for (file_name, expected) in files {
+            let path = dir.join(file_name);
+            if update {
+                fs::create_dir_all(&dir).unwrap();
+                fs::write(&path, expected).unwrap();
+                continue;
+            }
+            let actual = fs::read_to_string(&path).unwrap_or_default();
+            assert!(
+                actual == expected,
+                "`{}` is out of date. Run `UPDATE_GENERATED=1 cargo test -p oxc_transformer generated_options` to update it.",
+                path.display()
+            );
+        }
+    }
+
+    #[test]
+    fn es2017() {
+        check_generated::<ES2017Options>("es2017");
+    }
+
+    #[test]
+    fn renders_user_facing_options() {
+        super::declare_plugin_options! {
+            #[derive(Debug)]
+            pub struct TestOptions {
+                #[internal]
+                pub enabled: bool,
+
+                /// Name of the helper.
+                #[option(default = Some("helper".to_string()), since = "0.1.0")]
+                pub helper_name: Option<String>,
+            }
+        }
+
+        let options: TestOptions = serde_json::from_str("{}").unwrap();
+        assert_eq!(options.helper_name.as_deref(), Some("helper"));
+        assert!(serde_json::from_str::<TestOptions>(r#"{ "enabled": true }"#).is_err());
+
+        assert_eq!(
+            markdown::<TestOptions>(),
+            "# TestOptions\n\n\
+             | Option | Type | Default | Since | Description |\n\
+             | --- | --- | --- | --- | --- |\n\
+             | `helperName` | `string \\| null` | `\"helper\"` | 0.1.0 | Name of the helper. |\n"
+        );
+        assert_eq!(
+            typescript::<TestOptions>(),
+            "export interface TestOptions {\n  /**\n   * Name of the helper.\n   *\n   * @default \"helper\"\n   * @since 0.1.0\n   */\n  helperName?: string | null;\n}\n"
+        );
+        let schema: Value = serde_json::from_str(&json_schema::<TestOptions>()).unwrap();
+        assert_eq!(
+            schema["properties"]["helperName"],
+            serde_json::json!({
+                "anyOf": [{ "type": "string" }, { "type": "null" }],
+                "description": "Name of the helper.",
+                "default": "helper",
+            })
+        );
+    }
+}
@@ -1 +1,18 @@
+use oxc_traverse::Ancestor;
+
+use crate::context::TraverseCtx;
+
 pub mod ast_builder;
+
+/// Check the parent node to see if multiple statements are allowed.
+pub fn is_multiple_statements_allowed(ctx: &TraverseCtx<'_>) -> bool {
+    matches!(
+        ctx.parent(),
+        Ancestor::ProgramBody(_)
+            | Ancestor::FunctionBodyStatements(_)
+            | Ancestor::BlockStatementBody(_)
+            | Ancestor::SwitchCaseConsequent(_)
+            | Ancestor::StaticBlockBody(_)
+            | Ancestor::TSModuleBlockBody(_)
+    )
+}
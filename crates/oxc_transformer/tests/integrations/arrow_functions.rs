@@ -0,0 +1,72 @@
+use oxc_span::SourceType;
+use oxc_transformer::{ArrowFunctionsOptions, ESTarget, TransformOptions};
+
+use crate::{codegen, test};
+
+fn options(spec: bool) -> TransformOptions {
+    let mut options = TransformOptions::from(ESTarget::ES2015);
+    options.env.es2015.arrow_function = Some(ArrowFunctionsOptions { spec });
+    options
+}
+
+fn assert_transform(source: &str, expected: &str, spec: bool) {
+    assert_eq!(test(source, &options(spec)), Ok(codegen(expected, SourceType::default())));
+}
+
+#[test]
+fn converts_to_function_expression() {
+    assert_transform(
+        "const double = (x) => x * 2; const log = () => { console.log(1); };",
+        "const double = function(x) { return x * 2; };
+        const log = function() { console.log(1); };",
+        false,
+    );
+}
+
+#[test]
+fn captures_this() {
+    assert_transform(
+        "function f() { return () => this.x; }",
+        "function f() {
+            var _this = this;
+            return function() { return _this.x; };
+        }",
+        false,
+    );
+}
+
+#[test]
+fn nested_arrows_share_one_this_capture() {
+    assert_transform(
+        "function f() { return () => [this, () => this]; }",
+        "function f() {
+            var _this = this;
+            return function() {
+                return [_this, function() { return _this; }];
+            };
+        }",
+        false,
+    );
+}
+
+#[test]
+fn spec_binds_this() {
+    assert_transform(
+        "function f() { return () => this.x; }",
+        "function f() {
+            return function() { return this.x; }.bind(this);
+        }",
+        true,
+    );
+}
+
+#[test]
+fn spec_binds_nested_arrows() {
+    assert_transform(
+        "const g = () => () => this;",
+        "const g = function() {
+            return function() { return this; }.bind(this);
+        }.bind(this);",
+        true,
+    );
+}
@@ -0,0 +1,335 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{Function, ThisExpression};
+use oxc_ast_visit::{Visit, walk::walk_function};
+use oxc_parser::Parser;
+use oxc_semantic::{ScopeFlags, ScopeId, SemanticBuilder};
+use oxc_span::SourceType;
+use oxc_transformer::{ESTarget, HelperFlavor, TransformOptions, Transformer};
+
+use crate::test;
+
+/// `this` and `super` inside an async method body must still refer to the method's own `this`
+/// and `super`, once the body is moved into the generator function.
+#[test]
+fn this_and_super_in_async_method() {
+    let source = "
+class Foo extends Bar {
+  async method(v) {
+    const value = super.value;
+    await super.update(v);
+    super.value = v;
+    const arrow = () => this.state;
+    return this.state + value + arrow();
+  }
+  static async staticMethod() {
+    return super.create(this.name);
+  }
+}
+const obj = {
+  __proto__: base,
+  async method() {
+    return super.method(this);
+  }
+};
+";
+    let options = TransformOptions::from(ESTarget::ES2016);
+    #[cfg_attr(miri, expect(unused_variables))]
+    let output = test(source, &options).unwrap();
+
+    #[cfg(not(miri))]
+    {
+        insta::with_settings!({ prepend_module_to_snapshot => false, snapshot_suffix => "", omit_expression => true }, {
+            insta::assert_snapshot!("async_to_generator_this_and_super", output);
+        });
+    }
+}
+
+/// An async arrow at module scope refers to module's `this`, which is captured at top level.
+#[test]
+fn module_scope_async_arrow() {
+    let source = "
+const main = async () => { await run(); return this; };
+const nested = async () => { const f = () => this; await f(); };
+export const exported = async () => this?.x;
+";
+    let options = TransformOptions::from(ESTarget::ES2016);
+    #[cfg_attr(miri, expect(unused_variables))]
+    let output = test(source, &options).unwrap();
+
+    #[cfg(not(miri))]
+    {
+        insta::with_settings!({ prepend_module_to_snapshot => false, snapshot_suffix => "", omit_expression => true }, {
+            insta::assert_snapshot!("async_to_generator_module_scope_async_arrow", output);
+        });
+    }
+}
+
+#[test]
+fn module_scope_async_arrow_without_this() {
+    let options = TransformOptions::from(ESTarget::ES2016);
+    let output = test("const main = async () => { await run(); };", &options).unwrap();
+    assert!(!output.contains("_this"));
+    assert!(output.contains("return _ref.apply(this, arguments);"));
+}
+
+/// `await` inside a decorator expression is converted to `yield` along with the rest of the body,
+/// and the decorators themselves are left intact.
+#[test]
+fn await_in_class_decorator() {
+    let source = "
+async function main() {
+  @(await getDecorator()) class A {}
+  const B = @(await getDecorator()) class {};
+  class C {
+    @(await getDecorator()) method() {}
+  }
+  return [A, B, C];
+}
+const arrow = async () => { @(await getDecorator()) class D {} };
+";
+    let options = TransformOptions::from(ESTarget::ES2016);
+    #[cfg_attr(miri, expect(unused_variables))]
+    let output = test(source, &options).unwrap();
+
+    #[cfg(not(miri))]
+    {
+        insta::with_settings!({ prepend_module_to_snapshot => false, snapshot_suffix => "", omit_expression => true }, {
+            insta::assert_snapshot!("async_to_generator_await_in_class_decorator", output);
+        });
+    }
+}
+
+/// A custom `helperName` is called instead of `babelHelpers.asyncToGenerator`, and does not
+/// affect the helpers of other plugins.
+#[test]
+fn custom_helper_name() {
+    let source = "
+async function foo() { await bar(); }
+const baz = async () => { await qux(); };
+class A { async method() { await this.run(); } }
+async function* gen() { yield await bar(); }
+";
+    let mut options = TransformOptions::from(ESTarget::ES2016);
+    options.env.es2017.helper_name = Some(("myRuntime".into(), "asyncToGenerator".into()));
+    #[cfg_attr(miri, expect(unused_variables))]
+    let output = test(source, &options).unwrap();
+
+    #[cfg(not(miri))]
+    {
+        insta::with_settings!({ prepend_module_to_snapshot => false, snapshot_suffix => "", omit_expression => true }, {
+            insta::assert_snapshot!("async_to_generator_custom_helper_name", output);
+        });
+    }
+}
+
+/// Async arrows are replaced by a call to `asyncToGenerator`, or a wrapper function when they
+/// are named or their `length` must be kept, as Babel does. An expression body becomes a single
+/// `return` statement in the generator function, and no arrow is left behind to print.
+#[test]
+fn arrow_expression_and_block_bodies() {
+    let source = "
+const expression = async () => x;
+const awaitExpression = async () => await x;
+const block = async () => { return await x; };
+const nestedInBlock = async () => { const inner = async (y) => await y; return inner(await z); };
+const nestedInExpression = async (a) => async () => await a;
+foo(async () => this.x);
+foo(async () => async () => { await this.x; });
+";
+    let options = TransformOptions::from(ESTarget::ES2016);
+    #[cfg_attr(miri, expect(unused_variables))]
+    let output = test(source, &options).unwrap();
+
+    #[cfg(not(miri))]
+    {
+        insta::with_settings!({ prepend_module_to_snapshot => false, snapshot_suffix => "", omit_expression => true }, {
+            insta::assert_snapshot!("async_to_generator_arrow_expression_and_block_bodies", output);
+        });
+    }
+}
+
+/// Each async arrow in a multi-declarator declaration is transformed on its own, with its own
+/// `_ref`, and the declarators keep their order and their place in the declaration.
+#[test]
+fn multiple_arrows_in_one_declaration() {
+    let source = "
+const a = async () => await x(), b = async () => await y();
+let c = 1, d = async (e) => await e, f = 2;
+function outer() {
+  const g = async () => this.g, h = async () => this.h;
+}
+for (let i = async () => await x(), j = async () => await y(); ; ) {}
+";
+    let options = TransformOptions::from(ESTarget::ES2016);
+    #[cfg_attr(miri, expect(unused_variables))]
+    let output = test(source, &options).unwrap();
+
+    #[cfg(not(miri))]
+    {
+        insta::with_settings!({ prepend_module_to_snapshot => false, snapshot_suffix => "", omit_expression => true }, {
+            insta::assert_snapshot!("async_to_generator_multiple_arrows_in_one_declaration", output);
+        });
+    }
+}
+
+/// Fixture transformed with each helper flavor, so the two outputs can be compared.
+const HELPER_FLAVOR_FIXTURE: &str = "
+async function declaration(a, b) { await a; return arguments.length + b; }
+async function throwingParams(a, b = c()) { return await a + b; }
+const expression = async function named() { return await named; };
+const arrow = async (x) => await this.load(x);
+const throwingArrow = async (x = y()) => { await x; };
+class A {
+  async method(v) { return await this.run(v); }
+  static async staticMethod() { const f = async () => this; return await f(); }
+}
+";
+
+/// The `babel` helper flavor wraps the generator function in `asyncToGenerator`, and calls the
+/// result with `.apply(this, arguments)`, as Babel does.
+#[test]
+fn babel_helper_flavor() {
+    let mut options = TransformOptions::from(ESTarget::ES2016);
+    options.env.es2017.helper = HelperFlavor::Babel;
+    #[cfg_attr(miri, expect(unused_variables))]
+    let output = test(HELPER_FLAVOR_FIXTURE, &options).unwrap();
+
+    #[cfg(not(miri))]
+    {
+        insta::with_settings!({ prepend_module_to_snapshot => false, snapshot_suffix => "", omit_expression => true }, {
+            insta::assert_snapshot!("async_to_generator_babel_helper_flavor", output);
+        });
+    }
+}
+
+/// The `esbuild` helper flavor keeps each function, and passes `this`, the arguments, and the
+/// generator function to `__async`, as esbuild does.
+#[test]
+fn esbuild_helper_flavor() {
+    let mut options = TransformOptions::from(ESTarget::ES2016);
+    options.env.es2017.helper = HelperFlavor::Esbuild;
+    #[cfg_attr(miri, expect(unused_variables))]
+    let output = test(HELPER_FLAVOR_FIXTURE, &options).unwrap();
+
+    #[cfg(not(miri))]
+    {
+        insta::with_settings!({ prepend_module_to_snapshot => false, snapshot_suffix => "", omit_expression => true }, {
+            insta::assert_snapshot!("async_to_generator_esbuild_helper_flavor", output);
+        });
+    }
+}
+
+/// With the `esbuild` helper flavor, `void 0` is passed to `__async` in place of `this` when the
+/// generator function does not use `this`, so the output does not depend on the surrounding `this`.
+#[test]
+fn esbuild_helper_flavor_without_this() {
+    #[derive(Default)]
+    struct ThisCounter(usize);
+
+    impl Visit<'_> for ThisCounter {
+        fn visit_this_expression(&mut self, _it: &ThisExpression) {
+            self.0 += 1;
+        }
+    }
+
+    let mut options = TransformOptions::from(ESTarget::ES2016);
+    options.env.es2017.helper = HelperFlavor::Esbuild;
+    let output = test(
+        "export async function load(url) { return await fetch(url); }
+        export const parse = async (res) => JSON.parse(await res.text());",
+        &options,
+    )
+    .unwrap();
+    assert_eq!(output.matches("__async(void 0, null, function* ()").count(), 2);
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, &output, SourceType::mjs()).parse();
+    let mut counter = ThisCounter::default();
+    counter.visit_program(&ret.program);
+    assert_eq!(counter.0, 0);
+}
+
+#[test]
+fn esbuild_helper_flavor_class_heritage_uses_this() {
+    let mut options = TransformOptions::from(ESTarget::ES2016);
+    options.env.es2017.helper = HelperFlavor::Esbuild;
+    let output =
+        test("async function f() { class A extends this.Base {} await 1; }", &options).unwrap();
+    assert!(output.contains("__async(this, null, function* ()"), "{output}");
+}
+
+#[test]
+fn esbuild_helper_flavor_computed_class_key_uses_arguments() {
+    let mut options = TransformOptions::from(ESTarget::ES2016);
+    options.env.es2017.helper = HelperFlavor::Esbuild;
+    let output =
+        test("async function f() { class A { [arguments[0]]() {} } await 1; }", &options).unwrap();
+    assert!(output.contains("__async(void 0, arguments, function* ()"), "{output}");
+
+    // `this` and `arguments` in method bodies and field initializers belong to the class.
+    let output = test(
+        "async function f() { class A { m() { return this.x(arguments); } x = this; } await 1; }",
+        &options,
+    )
+    .unwrap();
+    assert!(output.contains("__async(void 0, null, function* ()"), "{output}");
+}
+
+/// The span of each lowered function's `async` keyword is recorded, keyed by the scope of the
+/// generator function which replaces it.
+#[test]
+fn async_keyword_spans() {
+    #[derive(Default)]
+    struct GeneratorScopes(Vec<ScopeId>);
+
+    impl<'a> Visit<'a> for GeneratorScopes {
+        fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+            if func.generator {
+                self.0.push(func.scope_id());
+            }
+            walk_function(self, func, flags);
+        }
+    }
+
+    let source = "
+async function foo() { await x; }
+export default async function () {}
+const bar = async function named() {};
+const baz = async x => x;
+class A { static async [async]() {} async async() {} }
+const obj = { async method() {}, async: async () => {} };
+";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source, SourceType::mjs()).parse();
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let options = TransformOptions::from(ESTarget::ES2016);
+    let ret = Transformer::new(&allocator, Path::new("test.js"), &options)
+        .build_with_scoping(scoping, &mut program);
+    assert!(ret.errors.is_empty());
+
+    let mut spans = ret.async_keyword_spans.values().copied().collect::<Vec<_>>();
+    spans.sort_unstable();
+    assert!(spans.iter().all(|span| span.source_text(source) == "async"));
+    let expected = [
+        "async function foo",
+        "async function ()",
+        "async function named",
+        "async x",
+        "async [async]",
+        "async async()",
+        "async method",
+        "async () => {} }",
+    ]
+    .map(|text| source.find(text).unwrap());
+    assert_eq!(spans.iter().map(|span| span.start as usize).collect::<Vec<_>>(), expected);
+
+    let mut generator_scopes = GeneratorScopes::default();
+    generator_scopes.visit_program(&program);
+    let mut recorded_scopes = ret.async_keyword_spans.keys().copied().collect::<Vec<_>>();
+    recorded_scopes.sort_unstable();
+    generator_scopes.0.sort_unstable();
+    assert_eq!(recorded_scopes, generator_scopes.0);
+}
@@ -0,0 +1,179 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{BlockScopingOptions, ESTarget, TransformOptions, Transformer};
+
+use crate::{codegen, test};
+
+fn options(throw_if_closure_required: bool) -> TransformOptions {
+    let mut options = TransformOptions::from(ESTarget::ES2015);
+    options.env.es2015.block_scoping = Some(BlockScopingOptions { throw_if_closure_required });
+    options
+}
+
+/// Transform `source_text`, returning the output even if there are errors.
+fn transform_with_errors(source_text: &str) -> (String, Vec<OxcDiagnostic>) {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let ret = Transformer::new(&allocator, Path::new(""), &options(false))
+        .build_with_scoping(scoping, &mut program);
+    let code = Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code;
+    (code, ret.errors)
+}
+
+fn assert_transform(source: &str, expected: &str) {
+    assert_eq!(test(source, &options(false)), Ok(codegen(expected, SourceType::default())));
+}
+
+#[test]
+fn let_and_const_become_var() {
+    assert_transform(
+        "let a = 1; const b = 2; export let c;",
+        "var a = 1; var b = 2; export var c;",
+    );
+}
+
+#[test]
+fn shadowing_binding_is_renamed() {
+    assert_transform(
+        "let a = 1; { let a = 2; log({ a }); } log(a);",
+        "var a = 1; { var _a = 2; log({ a: _a }); } log(a);",
+    );
+}
+
+#[test]
+fn sibling_blocks_get_unique_names() {
+    assert_transform(
+        "function f(x) { if (x) { let y = x; log(y); } else { let y = 2; log(y); } }",
+        "function f(x) { if (x) { var y = x; log(y); } else { var _y = 2; log(_y); } }",
+    );
+}
+
+#[test]
+fn binding_shadowing_global_is_renamed() {
+    assert_transform(
+        "function f() { { let name = 1; log(name); } return name; }",
+        "function f() { { var _name = 1; log(_name); } return name; }",
+    );
+}
+
+#[test]
+fn let_without_init_in_loop_is_reset() {
+    assert_transform(
+        "while (c) { let x; const y = 1; log(x, y); }",
+        "while (c) { var x = void 0; var y = 1; log(x, y); }",
+    );
+}
+
+#[test]
+fn loop_without_closure_is_not_wrapped() {
+    assert_transform(
+        "for (let i = 0; i < 3; i++) { log(i); }",
+        "for (var i = 0; i < 3; i++) { log(i); }",
+    );
+}
+
+#[test]
+fn loop_head_captured_in_closure() {
+    assert_transform(
+        "for (let i = 0; i < 3; i++) { fns.push(() => i); if (i) continue; }",
+        "var _loop = function (i) { fns.push(() => i); if (i) return; };
+        for (var i = 0; i < 3; i++) { _loop(i); }",
+    );
+}
+
+#[test]
+fn loop_body_binding_captured_in_closure() {
+    assert_transform(
+        "do { const d = next(); fns.push(function () { return d; }); } while (c);",
+        "var _loop = function () { var d = next(); fns.push(function () { return d; }); };
+        do { _loop(); } while (c);",
+    );
+}
+
+#[test]
+fn continue_to_own_label_in_nested_loop() {
+    assert_transform(
+        "outer: for (const k of items) {
+            let v;
+            for (let j in k) { if (j) continue outer; fns.push(() => v); }
+        }",
+        "var _loop = function (k) {
+            var v = void 0;
+            for (var j in k) { if (j) return; fns.push(() => v); }
+        };
+        outer: for (var k of items) { _loop(k); }",
+    );
+}
+
+#[test]
+fn loop_in_single_statement_position() {
+    assert_transform(
+        "if (c) for (let m in o) fns.push(() => m);",
+        "if (c) {
+            var _loop = function (m) { fns.push(() => m); };
+            for (var m in o) { _loop(m); }
+        }",
+    );
+}
+
+#[test]
+fn unsupported_loop_is_reported() {
+    let source = "for (let i = 0; i < 3; i++) { fns.push(() => i); if (i) break; }";
+    let errors = test(source, &options(false)).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].to_string().contains("contains `break`"));
+}
+
+#[test]
+fn unsupported_loop_keeps_let() {
+    let (code, errors) = transform_with_errors(
+        "let a = 1; for (let i = 0; i < 3; i++) { const x = i; fns.push(() => x); if (i) break; }",
+    );
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].severity, Severity::Error);
+    assert_eq!(
+        code,
+        codegen(
+            "var a = 1; for (let i = 0; i < 3; i++) { const x = i; fns.push(() => x); if (i) break; }",
+            SourceType::default()
+        )
+    );
+}
+
+#[test]
+fn unsupported_nested_loop_keeps_let() {
+    let (code, errors) = transform_with_errors(
+        "for (let i of a) { log(i); while (c) { let y = i; fns.push(() => y); return; } }",
+    );
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        code,
+        codegen(
+            "for (var i of a) { log(i); while (c) { let y = i; fns.push(() => y); return; } }",
+            SourceType::default()
+        )
+    );
+}
+
+#[test]
+fn throw_if_closure_required() {
+    let errors = test("for (let i of a) fns.push(() => i);", &options(true)).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].to_string().contains("throwIfClosureRequired"));
+
+    assert_eq!(
+        test("for (let i of a) log(i);", &options(true)),
+        Ok(codegen("for (var i of a) log(i);", SourceType::default()))
+    );
+}
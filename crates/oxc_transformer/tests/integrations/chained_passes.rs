@@ -0,0 +1,108 @@
+//! Running custom `Traverse` passes before and after the transformer, over one arena,
+//! without printing and re-parsing in between.
+
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::*;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{SPAN, SourceType};
+use oxc_syntax::symbol::SymbolId;
+use oxc_transformer::{ESTarget, TransformOptions, Transformer};
+use oxc_traverse::{Traverse, TraverseCtx, traverse_mut};
+
+use crate::codegen;
+
+/// Replaces `__VERSION__` with `"1.0.0"`.
+struct DefineVersion;
+
+impl<'a> Traverse<'a, ()> for DefineVersion {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a, ()>) {
+        if matches!(expr, Expression::Identifier(ident) if ident.name == "__VERSION__") {
+            *expr = ctx.ast.expression_string_literal(SPAN, "1.0.0", None);
+        }
+    }
+}
+
+/// Renames the `_asyncToGenerator` helper binding inserted by the transformer, and all references
+/// to it, by their `SymbolId`s in the `Scoping` returned from the transformer.
+#[derive(Default)]
+struct RenameHelper {
+    symbol_id: Option<SymbolId>,
+}
+
+impl<'a> Traverse<'a, ()> for RenameHelper {
+    fn enter_program(&mut self, _program: &mut Program<'a>, ctx: &mut TraverseCtx<'a, ()>) {
+        let root_scope_id = ctx.scoping().root_scope_id();
+        let symbol_id = ctx.scoping().find_binding(root_scope_id, "_asyncToGenerator").unwrap();
+        ctx.scoping_mut().rename_symbol(symbol_id, root_scope_id, "asyncToGenerator");
+        self.symbol_id = Some(symbol_id);
+    }
+
+    fn enter_binding_identifier(
+        &mut self,
+        ident: &mut BindingIdentifier<'a>,
+        _ctx: &mut TraverseCtx<'a, ()>,
+    ) {
+        if ident.symbol_id.get() == self.symbol_id {
+            ident.name = Atom::from("asyncToGenerator");
+        }
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a, ()>,
+    ) {
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        if ctx.scoping().get_reference(reference_id).symbol_id() == self.symbol_id {
+            ident.name = Atom::from("asyncToGenerator");
+        }
+    }
+}
+
+#[test]
+fn custom_passes_around_async_lowering() {
+    let source = "
+async function check() {
+  const latest = await fetchVersion();
+  return latest === __VERSION__;
+}
+";
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source, SourceType::default()).parse().program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+
+    let scoping = traverse_mut(&mut DefineVersion, &allocator, &mut program, scoping, ());
+    let ret =
+        Transformer::new(&allocator, Path::new(""), &TransformOptions::from(ESTarget::ES2016))
+            .build_with_scoping(scoping, &mut program);
+    assert!(ret.errors.is_empty());
+    let mut rename_helper = RenameHelper::default();
+    let scoping = traverse_mut(&mut rename_helper, &allocator, &mut program, ret.scoping, ());
+
+    let symbol_id = rename_helper.symbol_id.unwrap();
+    assert_eq!(scoping.symbol_name(symbol_id), "asyncToGenerator");
+    assert_eq!(scoping.get_resolved_reference_ids(symbol_id).len(), 1);
+
+    let output = Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code;
+    let expected = "
+import asyncToGenerator from '@oxc-project/runtime/helpers/asyncToGenerator';
+function check() {
+  return _check.apply(this, arguments);
+}
+function _check() {
+  _check = asyncToGenerator(function* () {
+    const latest = yield fetchVersion();
+    return latest === '1.0.0';
+  });
+  return _check.apply(this, arguments);
+}
+";
+    assert_eq!(output, codegen(expected, SourceType::default()));
+}
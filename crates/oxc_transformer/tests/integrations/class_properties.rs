@@ -0,0 +1,62 @@
+use oxc_transformer::{ClassPropertiesOptions, ESTarget, TransformOptions};
+
+use crate::test;
+
+/// Instance fields are initialized after `super()` in derived classes, or at the start of the
+/// constructor otherwise. Private fields are stored in `WeakMap`s declared before the class.
+const SOURCE: &str = "
+class A extends B {
+  x = 1;
+  #y = 2;
+  constructor(a) {
+    foo();
+    super(a);
+    bar();
+  }
+  get() {
+    return this.#y;
+  }
+}
+class C {
+  x;
+  #y = this.x;
+}
+";
+
+fn options(loose: bool) -> TransformOptions {
+    let mut options = TransformOptions::from(ESTarget::ES2021);
+    options.env.es2022.class_properties = Some(ClassPropertiesOptions { loose });
+    options
+}
+
+#[cfg_attr(miri, expect(unused_variables))]
+fn assert_snapshot(name: &str, options: &TransformOptions) {
+    let output = test(SOURCE, options).unwrap();
+
+    #[cfg(not(miri))]
+    {
+        insta::with_settings!({ prepend_module_to_snapshot => false, snapshot_suffix => "", omit_expression => true }, {
+            insta::assert_snapshot!(name, output);
+        });
+    }
+}
+
+#[test]
+fn instance_fields() {
+    assert_snapshot("class_properties_instance_fields", &options(false));
+}
+
+/// With `loose`, public fields are assigned with `=`, and private fields are stored as
+/// properties with unique keys.
+#[test]
+fn loose() {
+    assert_snapshot("class_properties_loose", &options(true));
+}
+
+/// The `privateFieldsAsProperties` assumption only changes how private fields are stored.
+#[test]
+fn private_fields_as_properties() {
+    let mut options = options(false);
+    options.assumptions.private_fields_as_properties = true;
+    assert_snapshot("class_properties_private_fields_as_properties", &options);
+}
@@ -0,0 +1,127 @@
+use std::path::Path;
+
+use cow_utils::CowUtils;
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{
+    AsyncFunctionKind, AsyncStrategy, DecisionLog, ESTarget, TransformOptions, Transformer,
+    compare_decision_logs,
+};
+
+fn decision_log(source_text: &str, options: &TransformOptions) -> DecisionLog {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let ret = Transformer::new(&allocator, Path::new("test.js"), options)
+        .build_with_scoping(scoping, &mut program);
+    assert!(ret.errors.is_empty());
+    ret.decision_log
+}
+
+fn options() -> TransformOptions {
+    let mut options = TransformOptions::from(ESTarget::ES2016);
+    options.env.es2017.decision_log = true;
+    options
+}
+
+const SOURCE: &str = "
+async function foo(a) { await a; return this; }
+const bar = async (b) => { await b; };
+call(async () => arguments);
+class A { async method(c = d()) { await c; } }
+";
+
+#[test]
+fn records_decisions() {
+    let log = decision_log(SOURCE, &options());
+    let decisions = log
+        .decisions
+        .iter()
+        .map(|decision| {
+            (decision.kind, decision.strategy, decision.uses_this, decision.uses_arguments)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        decisions,
+        [
+            (AsyncFunctionKind::Declaration, AsyncStrategy::HoistedDeclaration, true, false),
+            (AsyncFunctionKind::Arrow, AsyncStrategy::Wrapper, false, false),
+            (AsyncFunctionKind::Arrow, AsyncStrategy::DirectCall, false, true),
+            (AsyncFunctionKind::Method, AsyncStrategy::MethodApply, false, false),
+        ]
+    );
+    assert!(log.decisions.iter().all(|decision| decision.helpers == ["asyncToGenerator"]));
+
+    let jsonl = log.to_jsonl();
+    assert_eq!(jsonl.lines().count(), 4);
+    assert_eq!(DecisionLog::from_jsonl(&jsonl).unwrap(), log);
+}
+
+#[test]
+fn disabled_by_default() {
+    let log = decision_log(SOURCE, &TransformOptions::from(ESTarget::ES2016));
+    assert!(log.decisions.is_empty());
+}
+
+#[test]
+fn identical_runs() {
+    let first = decision_log(SOURCE, &options());
+    let second = decision_log(SOURCE, &options());
+    assert_eq!(first.to_jsonl(), second.to_jsonl());
+
+    let report = compare_decision_logs(&first, &second);
+    assert!(report.is_empty());
+    assert_eq!(report.unchanged, 4);
+}
+
+#[test]
+fn formatting_does_not_change_fingerprints() {
+    let first = decision_log(SOURCE, &options());
+    let reformatted = decision_log(
+        "// comment\nasync function foo(a) {\n  await a;\n\n  return this;\n}\nconst bar = async (b) => { await b };\ncall(async () => (arguments));\nclass A { async method(c = d()) { await c } }",
+        &options(),
+    );
+    assert!(compare_decision_logs(&first, &reformatted).is_empty());
+}
+
+#[test]
+fn changed_option_is_classified_as_changed_decision() {
+    let old = decision_log(SOURCE, &options());
+    let mut new_options = options();
+    new_options.assumptions.no_document_all = true;
+    let new = decision_log(SOURCE, &new_options);
+
+    let report = compare_decision_logs(&old, &new);
+    assert!(report.added.is_empty());
+    assert!(report.removed.is_empty());
+    assert_eq!(report.changed.len(), 4);
+    for change in &report.changed {
+        assert_eq!(change.old.fingerprint, change.new.fingerprint);
+        assert!(change.old.assumptions.is_empty());
+        assert_eq!(change.new.assumptions, ["noDocumentAll"]);
+    }
+}
+
+#[test]
+fn renaming_variable_changes_only_touched_function() {
+    let old = decision_log(SOURCE, &options());
+    let new = decision_log(
+        &SOURCE.cow_replace("(b) => { await b; }", "(renamed) => { await renamed; }"),
+        &options(),
+    );
+
+    assert_eq!(old.decisions[0].fingerprint, new.decisions[0].fingerprint);
+    assert_ne!(old.decisions[1].fingerprint, new.decisions[1].fingerprint);
+    assert_eq!(old.decisions[2].fingerprint, new.decisions[2].fingerprint);
+    assert_eq!(old.decisions[3].fingerprint, new.decisions[3].fingerprint);
+
+    let report = compare_decision_logs(&old, &new);
+    assert_eq!(report.unchanged, 3);
+    assert!(report.changed.is_empty());
+    assert_eq!(report.added, [new.decisions[1].clone()]);
+    assert_eq!(report.removed, [old.decisions[1].clone()]);
+}
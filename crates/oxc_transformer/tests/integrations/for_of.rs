@@ -0,0 +1,161 @@
+use oxc_span::SourceType;
+use oxc_transformer::{ESTarget, ForOfOptions, TransformOptions};
+
+use crate::{codegen, test};
+
+fn options(loose: bool) -> TransformOptions {
+    let mut options = TransformOptions::from(ESTarget::ES2015);
+    options.env.es2015.for_of = Some(ForOfOptions { loose });
+    options
+}
+
+fn assert_transform(source: &str, expected: &str, loose: bool) {
+    assert_eq!(test(source, &options(loose)), Ok(codegen(expected, SourceType::default())));
+}
+
+#[test]
+fn array_literal_is_iterated_by_index() {
+    assert_transform(
+        "for (const x of [1, 2, 3]) { log(x); }",
+        "for (var _i = 0, _arr = [1, 2, 3]; _i < _arr.length; _i++) {
+            const x = _arr[_i];
+            { log(x); }
+        }",
+        false,
+    );
+}
+
+#[test]
+fn iterable_uses_iterator_protocol() {
+    assert_transform(
+        "for (const x of items) { log(x); }",
+        "import _createForOfIteratorHelper from '@oxc-project/runtime/helpers/createForOfIteratorHelper';
+        var _iterator = _createForOfIteratorHelper(items), _step;
+        try {
+            for (_iterator.s(); !(_step = _iterator.n()).done;) {
+                const x = _step.value;
+                { log(x); }
+            }
+        } catch (err) {
+            _iterator.e(err);
+        } finally {
+            _iterator.f();
+        }",
+        false,
+    );
+}
+
+#[test]
+fn loose_iterates_by_index() {
+    assert_transform(
+        "for (const x of items) log(x);",
+        "for (var _i = 0, _arr = items; _i < _arr.length; _i++) {
+            const x = _arr[_i];
+            log(x);
+        }",
+        true,
+    );
+}
+
+#[test]
+fn destructuring_declaration() {
+    assert_transform(
+        "for (const [key, { value = 1 }] of entries) {}",
+        "for (var _i = 0, _arr = entries; _i < _arr.length; _i++) {
+            const [key, { value = 1 }] = _arr[_i];
+        }",
+        true,
+    );
+}
+
+#[test]
+fn destructuring_assignment() {
+    assert_transform(
+        "let a, b; for ({ a, b: [b] } of [x, y]) {}",
+        "let a, b;
+        for (var _i = 0, _arr = [x, y]; _i < _arr.length; _i++) {
+            ({ a, b: [b] } = _arr[_i]);
+        }",
+        false,
+    );
+}
+
+#[test]
+fn assignment_to_existing_variable() {
+    assert_transform(
+        "var x; for (x of [1]) log(x);",
+        "var x;
+        for (var _i = 0, _arr = [1]; _i < _arr.length; _i++) {
+            x = _arr[_i];
+            log(x);
+        }",
+        false,
+    );
+}
+
+#[test]
+fn nested_loops_get_unique_names() {
+    assert_transform(
+        "for (const a of [1]) for (const b of [2]) log(a, b);",
+        "for (var _i = 0, _arr = [1]; _i < _arr.length; _i++) {
+            const a = _arr[_i];
+            for (var _i2 = 0, _arr2 = [2]; _i2 < _arr2.length; _i2++) {
+                const b = _arr2[_i2];
+                log(a, b);
+            }
+        }",
+        false,
+    );
+}
+
+#[test]
+fn label_stays_on_loop() {
+    assert_transform(
+        "outer: for (const x of items) { continue outer; }",
+        "import _createForOfIteratorHelper from '@oxc-project/runtime/helpers/createForOfIteratorHelper';
+        var _iterator = _createForOfIteratorHelper(items), _step;
+        try {
+            outer: for (_iterator.s(); !(_step = _iterator.n()).done;) {
+                const x = _step.value;
+                { continue outer; }
+            }
+        } catch (err) {
+            _iterator.e(err);
+        } finally {
+            _iterator.f();
+        }",
+        false,
+    );
+}
+
+#[test]
+fn single_statement_position_is_wrapped_in_block() {
+    assert_transform(
+        "if (cond) for (const x of items);",
+        "import _createForOfIteratorHelper from '@oxc-project/runtime/helpers/createForOfIteratorHelper';
+        if (cond) {
+            var _iterator = _createForOfIteratorHelper(items), _step;
+            try {
+                for (_iterator.s(); !(_step = _iterator.n()).done;) {
+                    const x = _step.value;
+                }
+            } catch (err) {
+                _iterator.e(err);
+            } finally {
+                _iterator.f();
+            }
+        }",
+        false,
+    );
+}
+
+#[test]
+fn enabled_by_es5_target() {
+    let options = TransformOptions::from(ESTarget::ES5);
+    let output = test("for (const x of [1]) log(x);", &options).unwrap();
+    assert!(output.contains("for (var _i = 0, _arr = [1]; _i < _arr.length; _i++)"));
+
+    let options = TransformOptions::from(ESTarget::ES2015);
+    let output = test("for (const x of [1]) log(x);", &options).unwrap();
+    assert!(output.contains("for (const x of [1])"));
+}
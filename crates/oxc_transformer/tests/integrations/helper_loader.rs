@@ -0,0 +1,164 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{ESTarget, HelperLoaderMode, TransformOptions, Transformer};
+
+fn transform(source_text: &str, source_type: SourceType, mode: HelperLoaderMode) -> String {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let mut options = TransformOptions::from(ESTarget::ES2016);
+    options.helper_loader.mode = mode;
+    let ret = Transformer::new(&allocator, Path::new("test.js"), &options)
+        .build_with_scoping(scoping, &mut program);
+    assert!(ret.errors.is_empty());
+    Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code
+}
+
+const ASYNC_FUNCTIONS: &str = "
+async function foo() { await a; }
+const bar = async () => { await b; };
+class C { async method() { await c; } }
+";
+
+#[test]
+fn runtime_helper_imported_once() {
+    let output = transform(ASYNC_FUNCTIONS, SourceType::mjs(), HelperLoaderMode::Runtime);
+    assert_eq!(
+        output
+            .matches(
+                "import _asyncToGenerator from '@oxc-project/runtime/helpers/asyncToGenerator';"
+            )
+            .count(),
+        1
+    );
+    assert_eq!(output.matches("_asyncToGenerator(").count(), 3);
+    assert!(!output.contains("babelHelpers"));
+}
+
+#[test]
+fn runtime_helper_does_not_clash_with_existing_binding() {
+    let source_text = format!(
+        "import _asyncToGenerator from 'somewhere-else';\n_asyncToGenerator();\n{ASYNC_FUNCTIONS}"
+    );
+    let output = transform(&source_text, SourceType::mjs(), HelperLoaderMode::Runtime);
+    assert!(output.contains(
+        "import _asyncToGenerator2 from '@oxc-project/runtime/helpers/asyncToGenerator';"
+    ));
+    assert!(output.contains("import _asyncToGenerator from 'somewhere-else';"));
+    assert_eq!(output.matches("_asyncToGenerator2(").count(), 3);
+    assert_eq!(output.matches("_asyncToGenerator(").count(), 1);
+}
+
+#[test]
+fn runtime_helper_required_in_script() {
+    let output = transform(ASYNC_FUNCTIONS, SourceType::cjs(), HelperLoaderMode::Runtime);
+    assert_eq!(
+        output
+            .matches(
+                "var _asyncToGenerator = require('@oxc-project/runtime/helpers/asyncToGenerator');"
+            )
+            .count(),
+        1
+    );
+    assert!(!output.contains("import "));
+    assert_eq!(output.matches("_asyncToGenerator(").count(), 3);
+}
+
+#[test]
+fn external_helper() {
+    let output = transform(ASYNC_FUNCTIONS, SourceType::mjs(), HelperLoaderMode::External);
+    assert!(!output.contains("import "));
+    assert_eq!(output.matches("babelHelpers.asyncToGenerator(").count(), 3);
+}
+
+/// Transform output of a previous transform again. Output should be unchanged.
+fn assert_idempotent(source_type: SourceType, mode: HelperLoaderMode) {
+    let first = transform(ASYNC_FUNCTIONS, source_type, mode);
+    let second = transform(&first, source_type, mode);
+    assert_eq!(first, second);
+}
+
+#[test]
+fn runtime_helper_idempotent() {
+    assert_idempotent(SourceType::mjs(), HelperLoaderMode::Runtime);
+    assert_idempotent(SourceType::cjs(), HelperLoaderMode::Runtime);
+}
+
+#[test]
+fn external_helper_idempotent() {
+    assert_idempotent(SourceType::mjs(), HelperLoaderMode::External);
+}
+
+#[test]
+fn runtime_helper_reuses_existing_import() {
+    let source_text = format!(
+        "import _asyncToGenerator from '@oxc-project/runtime/helpers/asyncToGenerator';\n{ASYNC_FUNCTIONS}"
+    );
+    let output = transform(&source_text, SourceType::mjs(), HelperLoaderMode::Runtime);
+    assert_eq!(output.matches("import ").count(), 1);
+    assert!(output.contains(
+        "import _asyncToGenerator from '@oxc-project/runtime/helpers/asyncToGenerator';"
+    ));
+    assert_eq!(output.matches("_asyncToGenerator(").count(), 3);
+
+    let source_text = format!(
+        "import {{ default as helper }} from '@oxc-project/runtime/helpers/asyncToGenerator';\n{ASYNC_FUNCTIONS}"
+    );
+    let output = transform(&source_text, SourceType::mjs(), HelperLoaderMode::Runtime);
+    assert_eq!(output.matches("import ").count(), 1);
+    assert_eq!(output.matches("helper(").count(), 3);
+}
+
+#[test]
+fn runtime_helper_reuses_existing_require() {
+    let source_text = format!(
+        "var _asyncToGenerator = require('@oxc-project/runtime/helpers/asyncToGenerator');\n{ASYNC_FUNCTIONS}"
+    );
+    let output = transform(&source_text, SourceType::cjs(), HelperLoaderMode::Runtime);
+    assert_eq!(output.matches("require(").count(), 1);
+    assert_eq!(output.matches("_asyncToGenerator(").count(), 3);
+}
+
+#[test]
+fn runtime_helper_does_not_reuse_other_bindings() {
+    // Reassigned binding
+    let source_text = format!(
+        "var _asyncToGenerator = require('@oxc-project/runtime/helpers/asyncToGenerator');\n_asyncToGenerator = null;\n{ASYNC_FUNCTIONS}"
+    );
+    let output = transform(&source_text, SourceType::cjs(), HelperLoaderMode::Runtime);
+    assert_eq!(output.matches("require(").count(), 2);
+    assert_eq!(output.matches("_asyncToGenerator2(").count(), 3);
+
+    // Local `require`
+    let source_text = format!(
+        "function require() {{}}\nvar _asyncToGenerator = require('@oxc-project/runtime/helpers/asyncToGenerator');\n{ASYNC_FUNCTIONS}"
+    );
+    let output = transform(&source_text, SourceType::cjs(), HelperLoaderMode::Runtime);
+    assert_eq!(output.matches("_asyncToGenerator2(").count(), 3);
+
+    // Named import
+    let source_text = format!(
+        "import {{ asyncToGenerator }} from '@oxc-project/runtime/helpers/asyncToGenerator';\n{ASYNC_FUNCTIONS}"
+    );
+    let output = transform(&source_text, SourceType::mjs(), HelperLoaderMode::Runtime);
+    assert_eq!(output.matches("import ").count(), 2);
+    assert_eq!(output.matches("_asyncToGenerator(").count(), 3);
+}
+
+#[test]
+fn external_helper_reuses_existing_alias() {
+    let source_text =
+        format!("var _asyncToGenerator = babelHelpers.asyncToGenerator;\n{ASYNC_FUNCTIONS}");
+    let output = transform(&source_text, SourceType::mjs(), HelperLoaderMode::External);
+    assert_eq!(output.matches("babelHelpers.asyncToGenerator").count(), 1);
+    assert_eq!(output.matches("_asyncToGenerator(").count(), 3);
+}
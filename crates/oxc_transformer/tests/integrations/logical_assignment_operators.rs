@@ -0,0 +1,52 @@
+use oxc_span::SourceType;
+use oxc_transformer::{ESTarget, TransformOptions};
+
+use crate::{codegen, test};
+
+fn assert_transform(source: &str, expected: &str) {
+    let options = TransformOptions::from(ESTarget::ES2020);
+    assert_eq!(test(source, &options), Ok(codegen(expected, SourceType::default())));
+}
+
+#[test]
+fn identifier() {
+    assert_transform(
+        "x = a &&= b; x = a ||= b; x = a ??= b;",
+        "x = a && (a = b); x = a || (a = b); x = a ?? (a = b);",
+    );
+}
+
+#[test]
+fn member_expression() {
+    assert_transform(
+        "x = a.b ||= c; x = a.b.c &&= 1; x = this.x ??= 2;",
+        "var _a, _a$b;
+        x = (_a = a).b || (_a.b = c);
+        x = (_a$b = a.b).c && (_a$b.c = 1);
+        x = this.x ?? (this.x = 2);",
+    );
+}
+
+#[test]
+fn computed_member_expression() {
+    assert_transform(
+        "x = a[f()] ??= d; x = a[0] &&= d;",
+        "var _a, _f, _a2;
+        x = (_a = a)[_f = f()] ?? (_a[_f] = d);
+        x = (_a2 = a)[0] && (_a2[0] = d);",
+    );
+}
+
+#[test]
+fn super_member_expression() {
+    assert_transform(
+        "class A extends B { m() { super.x ??= 1; super[k()] &&= 2; } }",
+        "class A extends B {
+            m() {
+                var _k;
+                super.x ?? (super.x = 1);
+                super[_k = k()] && (super[_k] = 2);
+            }
+        }",
+    );
+}
@@ -1,5 +1,21 @@
+mod arrow_functions;
+mod async_to_generator;
+mod block_scoping;
+mod chained_passes;
+mod class_properties;
+mod decision_log;
 mod es_target;
+mod for_of;
+mod helper_loader;
+mod logical_assignment_operators;
+mod many_async_functions;
+mod nullish_coalescing_operator;
+mod object_rest_spread;
+mod optional_chaining;
+mod pure_annotations;
+mod span_filter;
 mod targets;
+mod template_literals;
 
 use std::path::Path;
 
@@ -0,0 +1,42 @@
+use std::{
+    fmt::Write,
+    time::{Duration, Instant},
+};
+
+use oxc_transformer::{ESTarget, TransformOptions};
+
+use crate::test;
+
+/// Number of async functions in the generated file.
+const COUNT: usize = 5000;
+
+/// Lowering a file with thousands of async functions, each of which inserts a statement
+/// into the program body, should take time linear in the number of functions.
+///
+/// Statement insertions are batched and applied to each list of statements in a single pass,
+/// and UIDs are generated with a hash map lookup per name, so this completes quickly.
+/// The time limit is generous so the test is not flaky in slow debug builds.
+#[test]
+#[cfg_attr(miri, ignore)]
+fn many_async_functions() {
+    let source_text = (0..COUNT).fold(String::new(), |mut source_text, i| {
+        writeln!(source_text, "async function f{i}() {{ await g({i}); }}").unwrap();
+        source_text
+    });
+    let options = TransformOptions::from(ESTarget::ES2016);
+
+    let start = Instant::now();
+    let output = test(&source_text, &options).unwrap();
+    let elapsed = start.elapsed();
+    assert!(elapsed < Duration::from_secs(30), "Transform took {elapsed:?}");
+
+    // All functions share the base name `f`, so their UIDs are `_f`, `_f2`, `_f3` etc.
+    for i in [0, COUNT / 2, COUNT - 1] {
+        let uid = if i == 0 { "_f".to_string() } else { format!("_f{}", i + 1) };
+        let expected = format!(
+            "function f{i}() {{\n\treturn {uid}.apply(this, arguments);\n}}\nfunction {uid}() {{\n\t{uid} = _asyncToGenerator(function* () {{\n\t\tyield g({i});\n\t}});\n\treturn {uid}.apply(this, arguments);\n}}\n"
+        );
+        assert!(output.contains(&expected), "Unexpected output for `f{i}`");
+    }
+    assert_eq!(output.matches("_asyncToGenerator(").count(), COUNT);
+}
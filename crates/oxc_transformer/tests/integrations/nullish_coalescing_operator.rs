@@ -0,0 +1,58 @@
+use oxc_span::SourceType;
+use oxc_transformer::{ESTarget, NullishCoalescingOperatorOptions, TransformOptions};
+
+use crate::{codegen, test};
+
+fn options(loose: bool) -> TransformOptions {
+    let mut options = TransformOptions::from(ESTarget::ES2019);
+    options.env.es2020.nullish_coalescing_operator =
+        Some(NullishCoalescingOperatorOptions { loose });
+    options
+}
+
+fn assert_transform(source: &str, expected: &str, options: &TransformOptions) {
+    assert_eq!(test(source, options), Ok(codegen(expected, SourceType::default())));
+}
+
+#[test]
+fn chained() {
+    assert_transform(
+        "x = a.b ?? c ?? d;",
+        "var _ref, _a$b;
+        x = (_ref = (_a$b = a.b) !== null && _a$b !== void 0 ? _a$b : c) !== null && _ref !== void 0 ? _ref : d;",
+        &options(false),
+    );
+}
+
+#[test]
+fn logical_assignment() {
+    assert_transform(
+        "a ??= b; obj.x ??= b;",
+        "var _a, _obj, _obj$x;
+        (_a = a) !== null && _a !== void 0 ? _a : a = b;
+        (_obj$x = (_obj = obj).x) !== null && _obj$x !== void 0 ? _obj$x : _obj.x = b;",
+        &options(false),
+    );
+}
+
+#[test]
+fn with_optional_chaining() {
+    assert_transform(
+        "x = a?.b ?? c;",
+        "var _a$b, _a;
+        x = (_a$b = (_a = a) === null || _a === void 0 ? void 0 : _a.b) !== null && _a$b !== void 0 ? _a$b : c;",
+        &options(false),
+    );
+}
+
+#[test]
+fn loose() {
+    assert_transform(
+        "x = a.b ?? c ?? d; a ??= b; x = a?.b ?? c;",
+        "var _ref, _a$b, _a, _a$b2, _a2;
+        x = (_ref = (_a$b = a.b) != null ? _a$b : c) != null ? _ref : d;
+        (_a = a) != null ? _a : a = b;
+        x = (_a$b2 = (_a2 = a) === null || _a2 === void 0 ? void 0 : _a2.b) != null ? _a$b2 : c;",
+        &options(true),
+    );
+}
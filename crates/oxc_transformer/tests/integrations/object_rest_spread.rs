@@ -0,0 +1,104 @@
+use oxc_span::SourceType;
+use oxc_transformer::{ESTarget, ObjectRestSpreadOptions, TransformOptions};
+
+use crate::{codegen, test};
+
+fn options(loose: bool, use_built_ins: bool) -> TransformOptions {
+    let mut options = TransformOptions::from(ESTarget::ES2017);
+    options.env.es2018.object_rest_spread = Some(ObjectRestSpreadOptions { loose, use_built_ins });
+    options
+}
+
+fn assert_transform(source: &str, expected: &str, options: &TransformOptions) {
+    assert_eq!(test(source, options), Ok(codegen(expected, SourceType::default())));
+}
+
+#[test]
+fn spread() {
+    assert_transform(
+        "x = { a, ...b, c };",
+        "import _objectSpread from '@oxc-project/runtime/helpers/objectSpread2';
+        x = _objectSpread(_objectSpread({ a }, b), {}, { c });",
+        &options(false, false),
+    );
+}
+
+#[test]
+fn spread_loose() {
+    assert_transform(
+        "x = { a, ...b, c, ...d };",
+        "import _extends from '@oxc-project/runtime/helpers/extends';
+        x = _extends({ a }, b, { c }, d);",
+        &options(true, false),
+    );
+    assert_transform(
+        "x = { ...b };",
+        "import _extends from '@oxc-project/runtime/helpers/extends';
+        x = _extends({}, b);",
+        &options(true, false),
+    );
+}
+
+#[test]
+fn spread_use_built_ins() {
+    assert_transform("x = { a, ...b };", "x = Object.assign({ a }, b);", &options(true, true));
+    // A local `Object` is not the global one, but is still used, the same as Babel.
+    assert_transform(
+        "let Object = {}; x = { ...b };",
+        "let Object = {}; x = Object.assign({}, b);",
+        &options(true, true),
+    );
+}
+
+#[test]
+fn spread_with_assumptions() {
+    let mut options = options(false, false);
+    options.assumptions.set_spread_properties = true;
+    assert_transform(
+        "x = { a, ...b, c };",
+        "import _extends from '@oxc-project/runtime/helpers/extends';
+        x = _extends(_extends({ a }, b), {}, { c });",
+        &options,
+    );
+    options.assumptions.pure_getters = true;
+    assert_transform(
+        "x = { a, ...b, c };",
+        "import _extends from '@oxc-project/runtime/helpers/extends';
+        x = _extends({ a }, b, { c });",
+        &options,
+    );
+}
+
+#[test]
+fn rest_loose() {
+    assert_transform(
+        "const { a, ...rest } = obj;",
+        "import _objectWithoutPropertiesLoose from '@oxc-project/runtime/helpers/objectWithoutPropertiesLoose';
+        const { a } = obj, rest = _objectWithoutPropertiesLoose(obj, ['a']);",
+        &options(true, false),
+    );
+    assert_transform(
+        "const { [key]: a, b: { c, ...inner } = def, ...rest } = obj;",
+        "import _objectWithoutPropertiesLoose from '@oxc-project/runtime/helpers/objectWithoutPropertiesLoose';
+        import _toPropertyKey from '@oxc-project/runtime/helpers/toPropertyKey';
+        const _key = key, { [_key]: a, b: _ref = def } = obj, { c } = _ref, inner = _objectWithoutPropertiesLoose(_ref, ['c']), rest = _objectWithoutPropertiesLoose(obj, [_key, 'b'].map(_toPropertyKey));",
+        &options(true, false),
+    );
+}
+
+#[test]
+fn empty_rest_loose() {
+    assert_transform(
+        "const { ...rest } = obj;",
+        "import _objectDestructuringEmpty from '@oxc-project/runtime/helpers/objectDestructuringEmpty';
+        import _extends from '@oxc-project/runtime/helpers/extends';
+        const rest = _extends({}, (_objectDestructuringEmpty(obj), obj));",
+        &options(true, false),
+    );
+    assert_transform(
+        "const { ...rest } = obj;",
+        "import _objectDestructuringEmpty from '@oxc-project/runtime/helpers/objectDestructuringEmpty';
+        const rest = Object.assign({}, (_objectDestructuringEmpty(obj), obj));",
+        &options(true, true),
+    );
+}
@@ -0,0 +1,51 @@
+use oxc_span::SourceType;
+use oxc_transformer::{ESTarget, OptionalChainingOptions, TransformOptions};
+
+use crate::{codegen, test};
+
+fn options(loose: bool) -> TransformOptions {
+    let mut options = TransformOptions::from(ESTarget::ES2019);
+    options.env.es2020.optional_chaining = Some(OptionalChainingOptions { loose });
+    options
+}
+
+fn assert_transform(source: &str, expected: &str, options: &TransformOptions) {
+    assert_eq!(test(source, options), Ok(codegen(expected, SourceType::default())));
+}
+
+#[test]
+fn member_and_call() {
+    assert_transform(
+        "a?.b?.c; a?.[b]; a.b?.c();",
+        "var _a, _a2, _a$b;
+        (_a = a) === null || _a === void 0 || (_a = _a.b) === null || _a === void 0 ? void 0 : _a.c;
+        (_a2 = a) === null || _a2 === void 0 ? void 0 : _a2[b];
+        (_a$b = a.b) === null || _a$b === void 0 ? void 0 : _a$b.c();",
+        &options(false),
+    );
+}
+
+#[test]
+fn delete_and_parenthesized() {
+    assert_transform(
+        "delete a?.b; (a?.b)(); (a?.b).c = 1;",
+        "var _a, _a2, _a3;
+        (_a = a) === null || _a === void 0 ? true : delete _a.b;
+        ((_a2 = a) === null || _a2 === void 0 ? void 0 : _a2.b.bind(_a2))();
+        ((_a3 = a) === null || _a3 === void 0 ? void 0 : _a3.b).c = 1;",
+        &options(false),
+    );
+}
+
+#[test]
+fn loose() {
+    assert_transform(
+        "a?.b?.c; delete a?.b; (a?.b)(); foo.bar?.baz();",
+        "var _a, _a2, _a3, _foo$bar;
+        (_a = a) == null || (_a = _a.b) == null ? void 0 : _a.c;
+        (_a2 = a) == null ? true : delete _a2.b;
+        ((_a3 = a) == null ? void 0 : _a3.b.bind(_a3))();
+        (_foo$bar = foo.bar) == null ? void 0 : _foo$bar.baz();",
+        &options(true),
+    );
+}
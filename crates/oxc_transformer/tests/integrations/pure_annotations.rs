@@ -0,0 +1,22 @@
+use oxc_transformer::{ESTarget, TransformOptions};
+
+use crate::test;
+
+/// Pure annotations on awaited calls must survive the `await` to `yield` rewrite,
+/// as the awaited expression is moved rather than recreated.
+#[test]
+fn awaited_pure_calls_keep_annotation() {
+    let source = "
+async function f() {
+  const a = await /* @__PURE__ */ makeThing();
+  const b = await /* @__PURE__ */ new Thing();
+  return g(await /* @__PURE__ */ makeThing());
+}
+const h = async () => await /* @__PURE__ */ makeThing();
+";
+    let output = test(source, &TransformOptions::from(ESTarget::ES2016)).unwrap();
+    assert!(output.contains("const a = yield /* @__PURE__ */ makeThing();"));
+    assert!(output.contains("const b = yield /* @__PURE__ */ new Thing();"));
+    assert!(output.contains("return g(yield /* @__PURE__ */ makeThing());"));
+    assert!(output.contains("return yield /* @__PURE__ */ makeThing();"));
+}
@@ -0,0 +1,45 @@
+use oxc_span::{SourceType, Span};
+use oxc_transformer::{ESTarget, TransformOptions};
+
+use crate::{codegen, test};
+
+fn options(span: Span) -> TransformOptions {
+    let mut options = TransformOptions::from(ESTarget::ES2016);
+    options.env.es2017.span_filter = Some(span);
+    options
+}
+
+fn span_of(source_text: &str, needle: &str) -> Span {
+    let start = source_text.find(needle).unwrap();
+    Span::sized(u32::try_from(start).unwrap(), u32::try_from(needle.len()).unwrap())
+}
+
+#[test]
+fn only_transforms_functions_in_span() {
+    let source_text = "
+async function foo() { await a(); }
+async function bar() { await b(); }
+";
+    let span = span_of(source_text, "async function bar() { await b(); }");
+    let output = test(source_text, &options(span)).unwrap();
+
+    assert!(output.contains(&codegen("async function foo() { await a(); }", SourceType::mjs())));
+    assert!(!output.contains("async function bar"));
+    assert!(output.contains("yield b()"));
+    assert_eq!(output.matches("_asyncToGenerator(").count(), 1);
+}
+
+#[test]
+fn await_outside_span_is_untouched() {
+    let source_text = "
+const foo = async () => { await a(); };
+const bar = async () => { await b(); };
+";
+    let span = span_of(source_text, "async () => { await a(); }");
+    let output = test(source_text, &options(span)).unwrap();
+
+    assert!(output.contains("yield a()"));
+    assert!(
+        output.contains(&codegen("const bar = async () => { await b(); };", SourceType::mjs()))
+    );
+}
@@ -0,0 +1,62 @@
+use oxc_span::SourceType;
+use oxc_transformer::{ESTarget, TemplateLiteralsOptions, TransformOptions};
+
+use crate::{codegen, test};
+
+fn options(loose: bool) -> TransformOptions {
+    let mut options = TransformOptions::from(ESTarget::ES2015);
+    options.env.es2015.template_literals = Some(TemplateLiteralsOptions { loose });
+    options
+}
+
+fn assert_transform(source: &str, expected: &str, loose: bool) {
+    assert_eq!(test(source, &options(loose)), Ok(codegen(expected, SourceType::default())));
+}
+
+#[test]
+fn untagged_template() {
+    assert_transform("`Hello, ${name}!`", "'Hello, ' + name + '!'", false);
+    assert_transform("`${a} and ${b}`", "a + ' and ' + b", false);
+    assert_transform("x = `plain`", "x = 'plain'", false);
+    assert_transform("x = ``", "x = ''", false);
+}
+
+#[test]
+fn leading_string_is_kept_for_concatenation() {
+    assert_transform("`${a}`", "'' + a", false);
+    assert_transform("`${a}${b}`", "'' + a + b", false);
+    assert_transform("`${a}${b}c`", "'' + a + b + 'c'", false);
+}
+
+#[test]
+fn nested_template() {
+    assert_transform("`a${`b${c}`}d`", "'a' + ('b' + c) + 'd'", false);
+    assert_transform("`${`inner`}${x}`", "'inner' + x", false);
+}
+
+#[test]
+fn multiline_and_escapes() {
+    assert_transform("`line 1\nline 2 ${x}`", "'line 1\\nline 2 ' + x", false);
+    assert_transform(r"`tab\t${x}A`", "'tab\\t' + x + 'A'", false);
+    assert_transform(r"`\`${x}\``", "'`' + x + '`'", false);
+}
+
+#[test]
+fn side_effects_evaluated_in_order() {
+    assert_transform("`${f()}-${g()}`", "f() + '-' + g()", false);
+    assert_transform("`${a = 1}${b, c}${d ? e : f}`", "'' + (a = 1) + (b, c) + (d ? e : f)", false);
+}
+
+#[test]
+fn tagged_template_is_not_transformed() {
+    assert_transform("tag`a${b}c`", "tag`a${b}c`", false);
+    assert_transform("tag`a${`b${c}`}`", "tag`a${'b' + c}`", false);
+}
+
+#[test]
+fn tagged_template_loose() {
+    assert_transform("tag`a${b}c${d}`", "tag(['a', 'c', ''], b, d)", true);
+    assert_transform("obj.tag`x`", "obj.tag(['x'])", true);
+    assert_transform(r"tag`\unicode${x}`", "tag([void 0, ''], x)", true);
+    assert_transform("`${tag`a${b}`}`", "'' + tag(['a', ''], b)", true);
+}
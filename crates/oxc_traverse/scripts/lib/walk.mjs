@@ -63,9 +63,12 @@ export default function generateWalkFunctionsCode(types) {
       ctx: &mut TraverseCtx<'a, State>
     ) {
       traverser.enter_statements(&mut *stmts, ctx);
-      for stmt in &mut *stmts {
+      ctx.enter_statement_list();
+      for (index, stmt) in (*stmts).iter_mut().enumerate() {
+        ctx.set_current_statement_index(index);
         walk_statement(traverser, stmt, ctx);
       }
+      ctx.exit_statement_list(&mut *stmts);
       traverser.exit_statements(&mut *stmts, ctx);
     }
   `;
@@ -22,8 +22,9 @@ pub fn get_var_name_from_node<'a, N: GatherNodeParts<'a>>(node: &N) -> String {
 
     if name.is_empty() {
         name = "ref".to_string();
-    } else {
-        name.truncate(20);
+    } else if let Some((index, _)) = name.char_indices().nth(20) {
+        // Truncate to 20 characters. Not bytes, which could split a multi-byte character.
+        name.truncate(index);
     }
 
     to_identifier(name)
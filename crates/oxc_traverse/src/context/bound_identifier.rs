@@ -1,12 +1,19 @@
+use oxc_allocator::{Box as ArenaBox, IntoIn};
 use oxc_ast::{
     NONE,
     ast::{
         AssignmentTarget, BindingIdentifier, BindingPattern, BindingPatternKind, Expression,
-        IdentifierReference, SimpleAssignmentTarget,
+        IdentifierReference, SimpleAssignmentTarget, TSType, TSTypeAnnotation, TSTypeName,
+        TSTypeQueryExprName,
     },
 };
 use oxc_span::{Atom, SPAN, Span};
-use oxc_syntax::{reference::ReferenceFlags, symbol::SymbolId};
+use oxc_syntax::{
+    operator::AssignmentOperator,
+    reference::{ReferenceFlags, ReferenceId},
+    scope::ScopeId,
+    symbol::{SymbolFlags, SymbolId},
+};
 
 use crate::TraverseCtx;
 
@@ -56,6 +63,48 @@ impl<'a> BoundIdentifier<'a> {
         Self { name: ident.name, symbol_id: ident.symbol_id() }
     }
 
+    /// Create `BoundIdentifier` for the symbol which reference `reference_id` resolves to.
+    ///
+    /// Returns `None` if the reference is unresolved (e.g. a reference to a global).
+    ///
+    /// Use this when starting from a usage site and creating more references to the same binding.
+    pub fn from_reference<State>(
+        reference_id: ReferenceId,
+        ctx: &TraverseCtx<'a, State>,
+    ) -> Option<Self> {
+        let symbol_id = ctx.scoping().get_reference(reference_id).symbol_id()?;
+        let name = ctx.ast.atom(ctx.scoping().symbol_name(symbol_id));
+        Some(Self { name, symbol_id })
+    }
+
+    /// Returns `true` if `other` refers to the same binding.
+    ///
+    /// Compares only `SymbolId`s. The names of two `BoundIdentifier`s for the same symbol are
+    /// always the same.
+    #[inline]
+    pub fn is_same(&self, other: &BoundIdentifier<'a>) -> bool {
+        self.symbol_id == other.symbol_id
+    }
+
+    /// Generate a UID in `scope_id`, with name derived from this binding's name.
+    ///
+    /// e.g. `_foo` for `foo`. Leading underscores and trailing digits are removed before
+    /// the UID is generated, so `_foo2` for `_foo` if `_foo` is already taken.
+    ///
+    /// See also comments on [`TraverseScoping::generate_uid_name`] for important information
+    /// on how UIDs are generated.
+    ///
+    /// [`TraverseScoping::generate_uid_name`]: crate::TraverseScoping::generate_uid_name
+    #[must_use]
+    pub fn generate_derived_uid<State>(
+        &self,
+        scope_id: ScopeId,
+        flags: SymbolFlags,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) -> BoundIdentifier<'a> {
+        ctx.generate_uid(self.name.as_str(), scope_id, flags)
+    }
+
     /// Convert `BoundIdentifier` to `MaybeBoundIdentifier`
     pub fn to_maybe_bound_identifier(&self) -> MaybeBoundIdentifier<'a> {
         MaybeBoundIdentifier::new(self.name, Some(self.symbol_id))
@@ -74,9 +123,24 @@ impl<'a> BoundIdentifier<'a> {
         &self,
         ctx: &TraverseCtx<'a, State>,
     ) -> BindingPattern<'a> {
+        self.create_binding_pattern_with_type(NONE, false, ctx)
+    }
+
+    /// Create `BindingPattern` for this binding, with a type annotation and optionality.
+    ///
+    /// e.g. `_x: Foo` or `_x?: Foo`. Pass `NONE` for `type_annotation` to omit the type annotation.
+    pub fn create_binding_pattern_with_type<T, State>(
+        &self,
+        type_annotation: T,
+        optional: bool,
+        ctx: &TraverseCtx<'a, State>,
+    ) -> BindingPattern<'a>
+    where
+        T: IntoIn<'a, Option<ArenaBox<'a, TSTypeAnnotation<'a>>>>,
+    {
         let ident = self.create_binding_identifier(ctx);
         let binding_pattern_kind = BindingPatternKind::BindingIdentifier(ctx.alloc(ident));
-        ctx.ast.binding_pattern(binding_pattern_kind, NONE, false)
+        ctx.ast.binding_pattern(binding_pattern_kind, type_annotation, optional)
     }
 
     // --- Read only ---
@@ -301,6 +365,21 @@ impl<'a> BoundIdentifier<'a> {
         self.create_spanned_simple_target(SPAN, flags, ctx)
     }
 
+    /// Create `count` `IdentifierReference`s referencing this binding, with specified `ReferenceFlags`
+    /// and dummy `Span`s.
+    ///
+    /// Equivalent to calling [`BoundIdentifier::create_reference`] `count` times, but reserves
+    /// space for all the references up front.
+    pub fn create_references<State>(
+        &self,
+        count: usize,
+        flags: ReferenceFlags,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) -> Vec<IdentifierReference<'a>> {
+        ctx.reserve_bound_references(self.symbol_id, count);
+        std::iter::repeat_with(|| self.create_reference(flags, ctx)).take(count).collect()
+    }
+
     /// Create `IdentifierReference` referencing this binding, with specified `Span` and `ReferenceFlags`
     pub fn create_spanned_reference<State>(
         &self,
@@ -311,6 +390,23 @@ impl<'a> BoundIdentifier<'a> {
         ctx.create_bound_ident_reference(span, self.name, self.symbol_id, flags)
     }
 
+    /// Create an `IdentifierReference` referencing this binding for each `(Span, ReferenceFlags)`
+    /// pair in `spans_and_flags`, in order.
+    ///
+    /// Equivalent to calling [`BoundIdentifier::create_spanned_reference`] for each pair, but
+    /// reserves space for all the references up front.
+    pub fn create_spanned_references<State>(
+        &self,
+        spans_and_flags: &[(Span, ReferenceFlags)],
+        ctx: &mut TraverseCtx<'a, State>,
+    ) -> Vec<IdentifierReference<'a>> {
+        ctx.reserve_bound_references(self.symbol_id, spans_and_flags.len());
+        spans_and_flags
+            .iter()
+            .map(|&(span, flags)| self.create_spanned_reference(span, flags, ctx))
+            .collect()
+    }
+
     /// Create `Expression::Identifier` referencing this binding, with specified `Span` and `ReferenceFlags`
     pub fn create_spanned_expression<State>(
         &self,
@@ -345,4 +441,459 @@ impl<'a> BoundIdentifier<'a> {
         let ident = self.create_spanned_reference(span, flags, ctx);
         SimpleAssignmentTarget::AssignmentTargetIdentifier(ctx.alloc(ident))
     }
+
+    // --- Assignment ---
+
+    /// Create `AssignmentExpression` assigning `right` to this binding, with dummy `Span`.
+    ///
+    /// e.g. `_x = right` or `_x += right`.
+    ///
+    /// The reference is written to for `=`, and read from + written to for all other operators.
+    pub fn create_assignment_expression<State>(
+        &self,
+        operator: AssignmentOperator,
+        right: Expression<'a>,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) -> Expression<'a> {
+        let flags = if operator.is_assign() {
+            ReferenceFlags::Write
+        } else {
+            ReferenceFlags::Read | ReferenceFlags::Write
+        };
+        let left = self.create_target(flags, ctx);
+        ctx.ast.expression_assignment(SPAN, operator, left, right)
+    }
+
+    // --- TypeScript types ---
+
+    /// Create `TSTypeName::IdentifierReference` referencing this binding, with dummy `Span`.
+    ///
+    /// The reference has `ReferenceFlags::Type`, as references in type positions do after semantic
+    /// analysis.
+    pub fn create_ts_type_name<State>(&self, ctx: &mut TraverseCtx<'a, State>) -> TSTypeName<'a> {
+        let ident = self.create_reference(ReferenceFlags::Type, ctx);
+        TSTypeName::IdentifierReference(ctx.alloc(ident))
+    }
+
+    /// Create `TSType::TSTypeReference` referencing this binding, with dummy `Span`.
+    ///
+    /// e.g. `Foo` in `let x: Foo`.
+    pub fn create_ts_type_reference<State>(&self, ctx: &mut TraverseCtx<'a, State>) -> TSType<'a> {
+        let type_name = self.create_ts_type_name(ctx);
+        ctx.ast.ts_type_type_reference(SPAN, type_name, NONE)
+    }
+
+    /// Create `TSType::TSTypeQuery` referencing this binding, with dummy `Span`.
+    ///
+    /// e.g. `typeof foo` in `let x: typeof foo`.
+    pub fn create_ts_type_query<State>(&self, ctx: &mut TraverseCtx<'a, State>) -> TSType<'a> {
+        let ident = self.create_reference(ReferenceFlags::Type, ctx);
+        let expr_name = TSTypeQueryExprName::IdentifierReference(ctx.alloc(ident));
+        ctx.ast.ts_type_type_query(SPAN, expr_name, NONE)
+    }
+
+    /// Create `TSTypeName::QualifiedName` for a member of this binding, with dummy `Span`.
+    ///
+    /// e.g. `Namespace.Member`, where this binding is `Namespace` and `property` is `Member`.
+    pub fn create_namespace_qualified_name<State>(
+        &self,
+        property: Atom<'a>,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) -> TSTypeName<'a> {
+        let left = self.create_ts_type_name(ctx);
+        let right = ctx.ast.identifier_name(SPAN, property);
+        ctx.ast.ts_type_name_qualified_name(SPAN, left, right)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_ast::ast::*;
+    use oxc_span::{Atom, SPAN, SourceType, Span};
+    use oxc_syntax::{
+        number::NumberBase, operator::AssignmentOperator, reference::ReferenceFlags,
+        scope::ScopeFlags, symbol::SymbolFlags,
+    };
+
+    use crate::{BoundIdentifier, MaybeBoundIdentifier, TraverseCtx, test_utils::enter_program};
+
+    fn type_name_reference<'a, 'b>(type_name: &'b TSTypeName<'a>) -> &'b IdentifierReference<'a> {
+        match type_name {
+            TSTypeName::IdentifierReference(ident) => ident,
+            _ => panic!("expected `TSTypeName::IdentifierReference`"),
+        }
+    }
+
+    fn expression_reference<'a, 'b>(expr: &'b Expression<'a>) -> &'b IdentifierReference<'a> {
+        match expr {
+            Expression::Identifier(ident) => ident,
+            _ => panic!("expected `Expression::Identifier`"),
+        }
+    }
+
+    fn target_reference<'a, 'b>(target: &'b AssignmentTarget<'a>) -> &'b IdentifierReference<'a> {
+        match target {
+            AssignmentTarget::AssignmentTargetIdentifier(ident) => ident,
+            _ => panic!("expected `AssignmentTarget::AssignmentTargetIdentifier`"),
+        }
+    }
+
+    fn simple_target_reference<'a, 'b>(
+        target: &'b SimpleAssignmentTarget<'a>,
+    ) -> &'b IdentifierReference<'a> {
+        match target {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => ident,
+            _ => panic!("expected `SimpleAssignmentTarget::AssignmentTargetIdentifier`"),
+        }
+    }
+
+    #[test]
+    fn create_ts_types() {
+        enter_program("", SourceType::ts(), |ctx| {
+            let binding = ctx.generate_uid_in_current_scope("Foo", SymbolFlags::Class);
+
+            let TSType::TSTypeReference(type_reference) = binding.create_ts_type_reference(ctx)
+            else {
+                panic!("expected `TSType::TSTypeReference`");
+            };
+            let reference_ident = type_name_reference(&type_reference.type_name);
+
+            let TSType::TSTypeQuery(type_query) = binding.create_ts_type_query(ctx) else {
+                panic!("expected `TSType::TSTypeQuery`");
+            };
+            let TSTypeQueryExprName::IdentifierReference(query_ident) = &type_query.expr_name
+            else {
+                panic!("expected `TSTypeQueryExprName::IdentifierReference`");
+            };
+
+            let qualified_name = binding.create_namespace_qualified_name(Atom::from("Member"), ctx);
+            let TSTypeName::QualifiedName(qualified_name) = &qualified_name else {
+                panic!("expected `TSTypeName::QualifiedName`");
+            };
+            assert_eq!(qualified_name.right.name, "Member");
+            let qualified_ident = type_name_reference(&qualified_name.left);
+
+            for ident in [reference_ident, query_ident, qualified_ident] {
+                assert_eq!(ident.name, binding.name);
+                let reference = ctx.scoping().get_reference(ident.reference_id());
+                assert_eq!(reference.symbol_id(), Some(binding.symbol_id));
+                assert_eq!(reference.flags(), ReferenceFlags::Type);
+                assert!(
+                    ctx.scoping()
+                        .get_resolved_reference_ids(binding.symbol_id)
+                        .contains(&ident.reference_id())
+                );
+            }
+
+            // Unbound `MaybeBoundIdentifier` creates unresolved references
+            let unbound = MaybeBoundIdentifier::new(Atom::from("Global"), None);
+            let type_name = unbound.create_namespace_qualified_name(Atom::from("Member"), ctx);
+            let TSTypeName::QualifiedName(qualified_name) = &type_name else {
+                panic!("expected `TSTypeName::QualifiedName`");
+            };
+            let ident = type_name_reference(&qualified_name.left);
+            let reference = ctx.scoping().get_reference(ident.reference_id());
+            assert_eq!(reference.symbol_id(), None);
+            assert_eq!(reference.flags(), ReferenceFlags::Type);
+            assert_eq!(
+                ctx.scoping().root_unresolved_references().get("Global").map(|ids| ids.as_slice()),
+                Some([ident.reference_id()].as_slice())
+            );
+
+            // Bound `MaybeBoundIdentifier` creates resolved references
+            let bound = binding.to_maybe_bound_identifier();
+            let TSType::TSTypeReference(type_reference) = bound.create_ts_type_reference(ctx)
+            else {
+                panic!("expected `TSType::TSTypeReference`");
+            };
+            let ident = type_name_reference(&type_reference.type_name);
+            let reference = ctx.scoping().get_reference(ident.reference_id());
+            assert_eq!(reference.symbol_id(), Some(binding.symbol_id));
+            assert_eq!(ctx.scoping().get_resolved_reference_ids(binding.symbol_id).len(), 4);
+        });
+    }
+
+    #[test]
+    fn is_same() {
+        enter_program("", SourceType::ts(), |ctx| {
+            let foo = ctx.generate_uid_in_current_scope("foo", SymbolFlags::FunctionScopedVariable);
+            let foo2 =
+                ctx.generate_uid_in_current_scope("foo", SymbolFlags::FunctionScopedVariable);
+            assert!(foo.is_same(&foo));
+            assert!(foo.is_same(&foo.clone()));
+            assert!(foo.is_same(&BoundIdentifier::new(foo.name, foo.symbol_id)));
+            assert!(!foo.is_same(&foo2));
+            assert!(!foo2.is_same(&foo));
+        });
+    }
+
+    /// Derives UIDs from `foo`, `_foo` and `ünï`.
+    #[test]
+    fn generate_derived_uid() {
+        enter_program("", SourceType::ts(), |ctx| {
+            let root_scope_id = ctx.current_scope_id();
+            let flags = SymbolFlags::FunctionScopedVariable;
+            let foo = ctx.generate_binding(Atom::from("foo"), root_scope_id, flags);
+            let unicode = ctx.generate_binding(Atom::from("ünï"), root_scope_id, flags);
+
+            let derived = foo.generate_derived_uid(root_scope_id, flags, ctx);
+            assert_eq!(derived.name, "_foo");
+            // Leading underscores are not repeated, a numeric postfix is added instead
+            let derived_again = derived.generate_derived_uid(root_scope_id, flags, ctx);
+            assert_eq!(derived_again.name, "_foo2");
+            assert_eq!(foo.generate_derived_uid(root_scope_id, flags, ctx).name, "_foo3");
+            assert_eq!(unicode.generate_derived_uid(root_scope_id, flags, ctx).name, "_ünï");
+
+            // Binding is created in the target scope
+            let block_scope_id = ctx.create_child_scope(root_scope_id, ScopeFlags::empty());
+            let in_block =
+                foo.generate_derived_uid(block_scope_id, SymbolFlags::BlockScopedVariable, ctx);
+            assert_eq!(in_block.name, "_foo4");
+            assert_eq!(ctx.scoping().symbol_scope_id(in_block.symbol_id), block_scope_id);
+            assert_eq!(
+                ctx.scoping().symbol_flags(in_block.symbol_id),
+                SymbolFlags::BlockScopedVariable
+            );
+            assert_eq!(
+                ctx.scoping().get_binding(block_scope_id, "_foo4"),
+                Some(in_block.symbol_id)
+            );
+        });
+    }
+
+    #[test]
+    fn create_binding_pattern_with_type() {
+        enter_program("", SourceType::ts(), |ctx| {
+            let binding =
+                ctx.generate_uid_in_current_scope("x", SymbolFlags::FunctionScopedVariable);
+            let class_binding = ctx.generate_uid_in_current_scope("Foo", SymbolFlags::Class);
+
+            let pattern = binding.create_binding_pattern(ctx);
+            assert!(pattern.type_annotation.is_none());
+            assert!(!pattern.optional);
+
+            let type_annotation =
+                ctx.ast.alloc_ts_type_annotation(SPAN, class_binding.create_ts_type_reference(ctx));
+            let pattern =
+                binding.create_binding_pattern_with_type(Some(type_annotation), true, ctx);
+            assert_eq!(pattern.get_binding_identifier().unwrap().symbol_id(), binding.symbol_id);
+            assert!(pattern.optional);
+            let Some(TSType::TSTypeReference(type_reference)) =
+                pattern.type_annotation.as_ref().map(|annotation| &annotation.type_annotation)
+            else {
+                panic!("expected `TSType::TSTypeReference`");
+            };
+            assert_eq!(type_name_reference(&type_reference.type_name).name, class_binding.name);
+        });
+    }
+
+    /// Simulates moving references to `x` and `y` out of the block in `var y; { let x, y; x; y; }`.
+    #[test]
+    fn rebind_in_scope() {
+        enter_program("", SourceType::ts(), |ctx| {
+            let root_scope_id = ctx.current_scope_id();
+            let outer_y = ctx.generate_binding(
+                Atom::from("y"),
+                root_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            let block_scope_id = ctx.create_child_scope(root_scope_id, ScopeFlags::empty());
+            let inner_x = ctx.generate_binding(
+                Atom::from("x"),
+                block_scope_id,
+                SymbolFlags::BlockScopedVariable,
+            );
+            let inner_y = ctx.generate_binding(
+                Atom::from("y"),
+                block_scope_id,
+                SymbolFlags::BlockScopedVariable,
+            );
+
+            // `x` is not bound outside the block
+            let x_ref = inner_x.create_read_reference(ctx);
+            let x = MaybeBoundIdentifier::from_identifier_reference(&x_ref, ctx);
+            assert!(x.to_bound_identifier().unwrap().is_same(&inner_x));
+            assert_eq!(x.rebind_in_scope(block_scope_id, ctx).symbol_id, Some(inner_x.symbol_id));
+            let moved_x = x.rebind_in_scope(root_scope_id, ctx);
+            assert_eq!(moved_x.name, "x");
+            assert_eq!(moved_x.symbol_id, None);
+            assert!(moved_x.to_bound_identifier().is_none());
+
+            // `y` is bound to the outer `var y` outside the block
+            let y_ref = inner_y.create_read_reference(ctx);
+            let y = MaybeBoundIdentifier::from_identifier_reference(&y_ref, ctx);
+            assert!(y.to_bound_identifier().unwrap().is_same(&inner_y));
+            let moved_y = y.rebind_in_scope(root_scope_id, ctx);
+            assert!(moved_y.to_bound_identifier().unwrap().is_same(&outer_y));
+            let moved_y_ref = moved_y.create_read_reference(ctx);
+            assert_eq!(
+                ctx.scoping().get_reference(moved_y_ref.reference_id()).symbol_id(),
+                Some(outer_y.symbol_id)
+            );
+        });
+    }
+
+    /// Starts from references to a bound `x` and an unbound `Global`.
+    #[test]
+    fn from_reference() {
+        enter_program("", SourceType::ts(), |ctx| {
+            let binding =
+                ctx.generate_uid_in_current_scope("x", SymbolFlags::FunctionScopedVariable);
+
+            // Resolved reference
+            let x_ref = binding.create_read_reference(ctx);
+            let x = BoundIdentifier::from_reference(x_ref.reference_id(), ctx).unwrap();
+            assert!(x.is_same(&binding));
+            assert_eq!(x.name, binding.name);
+            let new_ref = x.create_read_reference(ctx);
+            assert_eq!(
+                ctx.scoping().get_reference(new_ref.reference_id()).symbol_id(),
+                Some(binding.symbol_id)
+            );
+            assert_eq!(ctx.scoping().get_resolved_reference_ids(binding.symbol_id).len(), 2);
+
+            // Unresolved reference
+            let global_ref =
+                MaybeBoundIdentifier::new(Atom::from("Global"), None).create_read_reference(ctx);
+            assert!(BoundIdentifier::from_reference(global_ref.reference_id(), ctx).is_none());
+        });
+    }
+
+    /// Creates `_x = 1` and `_x += 1`.
+    #[test]
+    fn create_assignment_expression() {
+        enter_program("", SourceType::ts(), |ctx| {
+            let binding =
+                ctx.generate_uid_in_current_scope("x", SymbolFlags::FunctionScopedVariable);
+
+            for (operator, flags) in [
+                (AssignmentOperator::Assign, ReferenceFlags::Write),
+                (AssignmentOperator::Addition, ReferenceFlags::Read | ReferenceFlags::Write),
+            ] {
+                let right =
+                    ctx.ast.expression_numeric_literal(SPAN, 1.0, None, NumberBase::Decimal);
+                let expr = binding.create_assignment_expression(operator, right, ctx);
+                let Expression::AssignmentExpression(assignment) = &expr else {
+                    panic!("expected `Expression::AssignmentExpression`");
+                };
+                assert_eq!(assignment.operator, operator);
+                assert!(matches!(assignment.right, Expression::NumericLiteral(_)));
+                let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assignment.left else {
+                    panic!("expected `AssignmentTarget::AssignmentTargetIdentifier`");
+                };
+                assert_eq!(ident.name, binding.name);
+                let reference = ctx.scoping().get_reference(ident.reference_id());
+                assert_eq!(reference.symbol_id(), Some(binding.symbol_id));
+                assert_eq!(reference.flags(), flags);
+            }
+            assert_eq!(ctx.scoping().get_resolved_reference_ids(binding.symbol_id).len(), 2);
+        });
+    }
+
+    /// Creates references to `_x` with `create_references` and with `create_reference` in a loop.
+    #[test]
+    fn create_references() {
+        enter_program("", SourceType::ts(), |ctx| {
+            let binding =
+                ctx.generate_uid_in_current_scope("x", SymbolFlags::FunctionScopedVariable);
+            let flags = ReferenceFlags::Read | ReferenceFlags::Write;
+
+            let batch = binding.create_references(3, flags, ctx);
+            let single = std::iter::repeat_with(|| binding.create_reference(flags, ctx))
+                .take(3)
+                .collect::<Vec<_>>();
+            for (batch, single) in batch.iter().zip(&single) {
+                assert_eq!(batch.name, single.name);
+                assert_eq!(batch.span, single.span);
+                let batch_reference = ctx.scoping().get_reference(batch.reference_id());
+                let single_reference = ctx.scoping().get_reference(single.reference_id());
+                assert_eq!(batch_reference.symbol_id(), single_reference.symbol_id());
+                assert_eq!(batch_reference.flags(), single_reference.flags());
+                assert_eq!(batch_reference.node_id(), single_reference.node_id());
+            }
+
+            // References are created in order, and resolved to the symbol in the same order
+            let all = batch.iter().chain(&single).map(IdentifierReference::reference_id);
+            let all = all.collect::<Vec<_>>();
+            assert!(all.windows(2).all(|ids| ids[0] < ids[1]));
+            assert_eq!(ctx.scoping().get_resolved_reference_ids(binding.symbol_id).as_slice(), all);
+
+            assert!(binding.create_references(0, flags, ctx).is_empty());
+        });
+    }
+
+    #[test]
+    fn create_spanned_references_in_batch() {
+        enter_program("", SourceType::ts(), |ctx| {
+            let binding =
+                ctx.generate_uid_in_current_scope("x", SymbolFlags::FunctionScopedVariable);
+            let spans_and_flags = [
+                (Span::new(0, 1), ReferenceFlags::Read),
+                (Span::new(2, 3), ReferenceFlags::Write),
+                (Span::new(4, 5), ReferenceFlags::Read | ReferenceFlags::Write),
+                (SPAN, ReferenceFlags::Type),
+            ];
+
+            let references = binding.create_spanned_references(&spans_and_flags, ctx);
+            assert_eq!(references.len(), spans_and_flags.len());
+            for (reference, &(span, flags)) in references.iter().zip(&spans_and_flags) {
+                assert_eq!(reference.name, binding.name);
+                assert_eq!(reference.span, span);
+                let reference = ctx.scoping().get_reference(reference.reference_id());
+                assert_eq!(reference.symbol_id(), Some(binding.symbol_id));
+                assert_eq!(reference.flags(), flags);
+            }
+            assert_eq!(
+                ctx.scoping().get_resolved_reference_ids(binding.symbol_id).as_slice(),
+                references.iter().map(IdentifierReference::reference_id).collect::<Vec<_>>()
+            );
+
+            assert!(binding.create_spanned_references(&[], ctx).is_empty());
+        });
+    }
+
+    /// Creates references to `_x` with each of the `create_spanned_*` methods, checking that the
+    /// `IdentifierReference` has the given span, as codegen maps the name in sourcemaps by it.
+    #[test]
+    fn create_spanned_references() {
+        enter_program("", SourceType::ts(), |ctx| {
+            let binding =
+                ctx.generate_uid_in_current_scope("x", SymbolFlags::FunctionScopedVariable);
+            let span = Span::new(10, 12);
+
+            let check = |ident: &IdentifierReference<'_>, flags, ctx: &TraverseCtx<'_, ()>| {
+                assert_eq!(ident.span, span);
+                assert_eq!(ident.name, binding.name);
+                let reference = ctx.scoping().get_reference(ident.reference_id());
+                assert_eq!(reference.symbol_id(), Some(binding.symbol_id));
+                assert_eq!(reference.flags(), flags);
+            };
+
+            let read = ReferenceFlags::Read;
+            let ident = binding.create_spanned_read_reference(span, ctx);
+            check(&ident, read, ctx);
+            let expr = binding.create_spanned_read_expression(span, ctx);
+            check(expression_reference(&expr), read, ctx);
+
+            let write = ReferenceFlags::Write;
+            let ident = binding.create_spanned_write_reference(span, ctx);
+            check(&ident, write, ctx);
+            let expr = binding.create_spanned_write_expression(span, ctx);
+            check(expression_reference(&expr), write, ctx);
+            let target = binding.create_spanned_write_target(span, ctx);
+            check(target_reference(&target), write, ctx);
+            let target = binding.create_spanned_write_simple_target(span, ctx);
+            check(simple_target_reference(&target), write, ctx);
+
+            let read_write = ReferenceFlags::Read | ReferenceFlags::Write;
+            let ident = binding.create_spanned_read_write_reference(span, ctx);
+            check(&ident, read_write, ctx);
+            let expr = binding.create_spanned_read_write_expression(span, ctx);
+            check(expression_reference(&expr), read_write, ctx);
+            let target = binding.create_spanned_read_write_target(span, ctx);
+            check(target_reference(&target), read_write, ctx);
+            let target = binding.create_spanned_read_write_simple_target(span, ctx);
+            check(simple_target_reference(&target), read_write, ctx);
+        });
+    }
 }
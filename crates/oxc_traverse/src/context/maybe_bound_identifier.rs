@@ -1,6 +1,12 @@
-use oxc_ast::ast::{AssignmentTarget, Expression, IdentifierReference, SimpleAssignmentTarget};
+use oxc_ast::{
+    NONE,
+    ast::{
+        AssignmentTarget, Expression, IdentifierReference, SimpleAssignmentTarget, TSType,
+        TSTypeName, TSTypeQueryExprName,
+    },
+};
 use oxc_span::{Atom, SPAN, Span};
-use oxc_syntax::{reference::ReferenceFlags, symbol::SymbolId};
+use oxc_syntax::{reference::ReferenceFlags, scope::ScopeId, symbol::SymbolId};
 
 use crate::TraverseCtx;
 
@@ -48,6 +54,18 @@ impl<'a> MaybeBoundIdentifier<'a> {
         Self { name: ident.name, symbol_id }
     }
 
+    /// Re-resolve this binding's name in scope `scope_id`.
+    ///
+    /// Returns a `MaybeBoundIdentifier` bound to the symbol which the name resolves to in `scope_id`
+    /// or its ancestors, or unbound if the name is not bound there.
+    ///
+    /// Use this when moving code into a different scope, before creating references for the new location.
+    #[must_use]
+    pub fn rebind_in_scope<State>(&self, scope_id: ScopeId, ctx: &TraverseCtx<'a, State>) -> Self {
+        let symbol_id = ctx.scoping().find_binding(scope_id, &self.name);
+        Self { name: self.name, symbol_id }
+    }
+
     /// Convert `MaybeBoundIdentifier` to `BoundIdentifier`.
     ///
     /// Returns `None` if symbol is not bound.
@@ -321,4 +339,45 @@ impl<'a> MaybeBoundIdentifier<'a> {
         let ident = self.create_spanned_reference(span, flags, ctx);
         SimpleAssignmentTarget::AssignmentTargetIdentifier(ctx.alloc(ident))
     }
+
+    // --- TypeScript types ---
+
+    /// Create `TSTypeName::IdentifierReference` referencing this binding, with dummy `Span`.
+    ///
+    /// The reference has `ReferenceFlags::Type`, as references in type positions do after semantic
+    /// analysis.
+    pub fn create_ts_type_name<State>(&self, ctx: &mut TraverseCtx<'a, State>) -> TSTypeName<'a> {
+        let ident = self.create_reference(ReferenceFlags::Type, ctx);
+        TSTypeName::IdentifierReference(ctx.alloc(ident))
+    }
+
+    /// Create `TSType::TSTypeReference` referencing this binding, with dummy `Span`.
+    ///
+    /// e.g. `Foo` in `let x: Foo`.
+    pub fn create_ts_type_reference<State>(&self, ctx: &mut TraverseCtx<'a, State>) -> TSType<'a> {
+        let type_name = self.create_ts_type_name(ctx);
+        ctx.ast.ts_type_type_reference(SPAN, type_name, NONE)
+    }
+
+    /// Create `TSType::TSTypeQuery` referencing this binding, with dummy `Span`.
+    ///
+    /// e.g. `typeof foo` in `let x: typeof foo`.
+    pub fn create_ts_type_query<State>(&self, ctx: &mut TraverseCtx<'a, State>) -> TSType<'a> {
+        let ident = self.create_reference(ReferenceFlags::Type, ctx);
+        let expr_name = TSTypeQueryExprName::IdentifierReference(ctx.alloc(ident));
+        ctx.ast.ts_type_type_query(SPAN, expr_name, NONE)
+    }
+
+    /// Create `TSTypeName::QualifiedName` for a member of this binding, with dummy `Span`.
+    ///
+    /// e.g. `Namespace.Member`, where this binding is `Namespace` and `property` is `Member`.
+    pub fn create_namespace_qualified_name<State>(
+        &self,
+        property: Atom<'a>,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) -> TSTypeName<'a> {
+        let left = self.create_ts_type_name(ctx);
+        let right = ctx.ast.identifier_name(SPAN, property);
+        ctx.ast.ts_type_name_qualified_name(SPAN, left, right)
+    }
 }
@@ -1,7 +1,7 @@
-use oxc_allocator::{Allocator, Box as ArenaBox, Vec as ArenaVec};
+use oxc_allocator::{Allocator, Box as ArenaBox, CloneIn, TakeIn, Vec as ArenaVec};
 use oxc_ast::{
     AstBuilder,
-    ast::{Expression, IdentifierReference, Statement},
+    ast::{Expression, IdentifierReference, Statement, VariableDeclarator},
 };
 use oxc_semantic::Scoping;
 use oxc_span::{Atom, Span};
@@ -12,7 +12,7 @@ use oxc_syntax::{
 };
 
 use crate::{
-    ancestor::{Ancestor, AncestorType},
+    ancestor::{Ancestor, AncestorType, ArrowFunctionExpressionWithoutBody, FunctionWithoutBody},
     ast_operations::{GatherNodeParts, get_var_name_from_node},
 };
 
@@ -21,6 +21,7 @@ mod bound_identifier;
 mod maybe_bound_identifier;
 mod reusable;
 mod scoping;
+mod statements;
 mod uid;
 use ancestry::PopToken;
 pub use ancestry::TraverseAncestry;
@@ -28,6 +29,44 @@ pub use bound_identifier::BoundIdentifier;
 pub use maybe_bound_identifier::MaybeBoundIdentifier;
 pub use reusable::ReusableTraverseCtx;
 pub use scoping::TraverseScoping;
+use statements::StatementInsertions;
+
+/// Function enclosing the current node.
+///
+/// Returned by [`TraverseCtx::enclosing_function`].
+///
+/// The function is still being traversed, so only the fields other than its body are accessible.
+#[derive(Clone, Copy, Debug)]
+pub enum EnclosingFunction<'a, 't> {
+    Function(FunctionWithoutBody<'a, 't>),
+    ArrowFunctionExpression(ArrowFunctionExpressionWithoutBody<'a, 't>),
+}
+
+impl EnclosingFunction<'_, '_> {
+    /// Get span of the function.
+    #[inline]
+    pub fn span(self) -> Span {
+        match self {
+            Self::Function(func) => *func.span(),
+            Self::ArrowFunctionExpression(arrow) => *arrow.span(),
+        }
+    }
+
+    /// Returns `true` if the function is `async`.
+    #[inline]
+    pub fn is_async(self) -> bool {
+        match self {
+            Self::Function(func) => *func.r#async(),
+            Self::ArrowFunctionExpression(arrow) => *arrow.r#async(),
+        }
+    }
+
+    /// Returns `true` if the function is an arrow function.
+    #[inline]
+    pub fn is_arrow(self) -> bool {
+        matches!(self, Self::ArrowFunctionExpression(_))
+    }
+}
 
 /// Traverse context.
 ///
@@ -117,6 +156,7 @@ pub struct TraverseCtx<'a, State> {
     pub ancestry: TraverseAncestry<'a>,
     pub scoping: TraverseScoping<'a>,
     pub ast: AstBuilder<'a>,
+    statements: StatementInsertions<'a>,
 }
 
 // Public methods
@@ -162,6 +202,48 @@ impl<'a, State> TraverseCtx<'a, State> {
         self.ancestry.ancestors()
     }
 
+    /// Get the nearest function whose body contains the current node.
+    ///
+    /// Returns `None` if current node is not inside a function body.
+    /// Nodes in a function's parameters are not considered to be inside that function.
+    pub fn enclosing_function<'t>(&'t self) -> Option<EnclosingFunction<'a, 't>> {
+        self.ancestors().find_map(|ancestor| match ancestor {
+            Ancestor::FunctionBody(func) => Some(EnclosingFunction::Function(func)),
+            Ancestor::ArrowFunctionExpressionBody(arrow) => {
+                Some(EnclosingFunction::ArrowFunctionExpression(arrow))
+            }
+            _ => None,
+        })
+    }
+
+    /// Get the nearest function whose body contains the current node, if that function is `async`.
+    ///
+    /// Returns `None` if current node is not inside a function body, or the nearest function is
+    /// not `async`. Outer `async` functions are not considered.
+    pub fn enclosing_async_function<'t>(&'t self) -> Option<EnclosingFunction<'a, 't>> {
+        self.enclosing_function().filter(|func| func.is_async())
+    }
+
+    /// Check if current node is a callback passed to one of `methods`, called as a method.
+    ///
+    /// e.g. with `methods` `["map", "from"]`, the arrow function in `items.map(() => {})`
+    /// or `Array.from(items, () => {})`.
+    ///
+    /// Returns the span of the method's name, or `None` if current node is not a direct argument
+    /// of such a call. Parentheses around current node are skipped. Computed member callees
+    /// (`items["map"](...)`) are not matched.
+    ///
+    /// The position of current node among the call's arguments is not checked, as the arguments
+    /// are being traversed and so are not accessible.
+    pub fn is_array_method_callback(&self, methods: &[&str]) -> Option<Span> {
+        let parent = self
+            .ancestors()
+            .find(|ancestor| !matches!(ancestor, Ancestor::ParenthesizedExpressionExpression(_)))?;
+        let Ancestor::CallExpressionArguments(call) = parent else { return None };
+        let Expression::StaticMemberExpression(member) = call.callee() else { return None };
+        methods.contains(&member.property.name.as_str()).then_some(member.property.span)
+    }
+
     /// Get depth in the AST.
     ///
     /// Count includes current node. i.e. in `Program`, depth is 1.
@@ -196,6 +278,28 @@ impl<'a, State> TraverseCtx<'a, State> {
         self.scoping.current_block_scope_id()
     }
 
+    /// Returns `true` if current scope is the root scope of the program.
+    ///
+    /// Code inside a block statement or function at top level is not in the root scope.
+    #[inline]
+    pub fn is_top_level(&self) -> bool {
+        self.current_scope_id() == self.scoping().root_scope_id()
+    }
+
+    /// Returns `true` if current scope is the root scope of a module.
+    ///
+    /// Always returns `false` when visiting `Program` itself, as source type is read from
+    /// the `Program` ancestor.
+    pub fn is_module_top_level(&self) -> bool {
+        self.is_top_level()
+            && self.ancestors().last().is_some_and(|ancestor| match ancestor {
+                Ancestor::ProgramHashbang(program) => program.source_type().is_module(),
+                Ancestor::ProgramDirectives(program) => program.source_type().is_module(),
+                Ancestor::ProgramBody(program) => program.source_type().is_module(),
+                _ => false,
+            })
+    }
+
     /// Get current scope flags.
     ///
     /// Shortcut for `ctx.scoping.current_scope_flags`.
@@ -446,9 +550,12 @@ impl<'a, State> TraverseCtx<'a, State> {
 
     /// Generate UID based on node.
     ///
-    /// Recursively gathers the identifying names of a node, and joins them with `$`.
+    /// Recursively gathers the identifying names of a node, and joins them with `$`,
+    /// e.g. `_foo$bar` for `foo.bar()`. A node with no identifying names gets `_ref`.
     ///
-    /// Based on Babel's `scope.generateUidBasedOnNode` logic.
+    /// Names deliberately match Babel's `scope.generateUidBasedOnNode`, rather than being joined in
+    /// camel case (`_fooBar`) with a `_temp` fallback, so that transforms output the same names as
+    /// Babel, which the conformance tests compare against.
     /// <https://github.com/babel/babel/blob/419644f27c5c59deb19e71aaabd417a3bc5483ca/packages/babel-traverse/src/scope/index.ts#L543>
     #[inline]
     pub fn generate_uid_based_on_node<N: GatherNodeParts<'a>>(
@@ -496,6 +603,28 @@ impl<'a, State> TraverseCtx<'a, State> {
         self.generate_uid_in_current_hoist_scope(&name)
     }
 
+    /// Reserve capacity for at least `additional` more references.
+    ///
+    /// Call this before creating many references in a loop, to avoid repeatedly growing
+    /// the references table.
+    ///
+    /// This is a shortcut for `ctx.scoping.reserve_references`.
+    #[inline]
+    pub fn reserve_references(&mut self, additional: usize) {
+        self.scoping.reserve_references(additional);
+    }
+
+    /// Reserve capacity for at least `additional` more references bound to `symbol_id`.
+    ///
+    /// As [`TraverseCtx::reserve_references`], and also reserves capacity in the list of
+    /// references resolved to `symbol_id`.
+    ///
+    /// This is a shortcut for `ctx.scoping.reserve_bound_references`.
+    #[inline]
+    pub fn reserve_bound_references(&mut self, symbol_id: SymbolId, additional: usize) {
+        self.scoping.reserve_bound_references(symbol_id, additional);
+    }
+
     /// Create a reference bound to a `SymbolId`.
     ///
     /// This is a shortcut for `ctx.scoping.create_bound_reference`.
@@ -641,6 +770,87 @@ impl<'a, State> TraverseCtx<'a, State> {
     pub fn delete_reference_for_identifier(&mut self, ident: &IdentifierReference) {
         self.scoping.delete_reference_for_identifier(ident);
     }
+
+    /// Get IDs of all resolved references to a symbol.
+    ///
+    /// Includes references created during traversal e.g. by [`BoundIdentifier::create_spanned_reference`].
+    ///
+    /// This is a shortcut for `ctx.scoping().get_resolved_reference_ids`.
+    pub fn symbol_references(&self, symbol_id: SymbolId) -> impl Iterator<Item = ReferenceId> + '_ {
+        self.scoping().get_resolved_reference_ids(symbol_id).iter().copied()
+    }
+
+    /// Move an expression out, or copy it if the original is still needed.
+    ///
+    /// If `needs_copy` is `false`, `expr` is replaced with a dummy and the original is returned.
+    ///
+    /// If `needs_copy` is `true`, `expr` is left in place and a copy is returned.
+    /// Only an `IdentifierReference` or a literal can be copied. A copy of an `IdentifierReference`
+    /// has a new reference to the same binding with the same `ReferenceFlags`.
+    ///
+    /// # Panics
+    /// Panics if `needs_copy` is `true` and `expr` is neither an `IdentifierReference`
+    /// nor a literal. Copying other expressions would need new references for every
+    /// `IdentifierReference` and new scopes for every function they contain.
+    pub fn take_or_clone_expression(
+        &mut self,
+        expr: &mut Expression<'a>,
+        needs_copy: bool,
+    ) -> Expression<'a> {
+        if !needs_copy {
+            return expr.take_in(self.ast);
+        }
+
+        match expr {
+            Expression::Identifier(ident) => {
+                let reference = self.scoping().get_reference(ident.reference_id());
+                let (symbol_id, flags) = (reference.symbol_id(), reference.flags());
+                self.create_ident_expr(ident.span, ident.name, symbol_id, flags)
+            }
+            _ if expr.is_literal() => expr.clone_in(self.ast.allocator),
+            _ => panic!("`take_or_clone_expression` can only copy an identifier or a literal"),
+        }
+    }
+
+    /// Insert a statement before the statement currently being visited.
+    ///
+    /// "Current statement" is the statement in the closest enclosing list of statements
+    /// (block, function body, program body etc) which contains the current node.
+    ///
+    /// The statement is inserted after all statements in that list have been visited,
+    /// and before `exit_statements` is called for the list. The inserted statement is not visited.
+    ///
+    /// In debug builds, panics if not inside a list of statements (e.g. in `enter_program`).
+    #[inline]
+    pub fn insert_statement_before_current(&mut self, stmt: Statement<'a>) {
+        self.statements.insert_before(stmt);
+    }
+
+    /// Insert a statement after the statement currently being visited.
+    ///
+    /// See [`TraverseCtx::insert_statement_before_current`] for details.
+    #[inline]
+    pub fn insert_statement_after_current(&mut self, stmt: Statement<'a>) {
+        self.statements.insert_after(stmt);
+    }
+
+    /// Declare `declarator` at the start of the closest enclosing list of statements
+    /// (block, function body, program body etc).
+    ///
+    /// The declaration's kind is `declarator.kind`. Declarators of the same kind queued for
+    /// the same list are combined into one declaration, e.g. `let _a, _b;`, placed before any
+    /// other inserted statements. As with [`TraverseCtx::insert_statement_before_current`],
+    /// the declaration is inserted after all statements in the list have been visited,
+    /// and is not visited.
+    ///
+    /// The caller is responsible for creating the binding in the scope of that list,
+    /// or for a `var`, in the enclosing function scope.
+    ///
+    /// In debug builds, panics if not inside a list of statements (e.g. in `enter_program`).
+    #[inline]
+    pub fn insert_declaration_in_nearest_block(&mut self, declarator: VariableDeclarator<'a>) {
+        self.statements.insert_declaration(declarator);
+    }
 }
 
 // Methods used internally within crate
@@ -653,7 +863,8 @@ impl<'a, State> TraverseCtx<'a, State> {
         let ancestry = TraverseAncestry::new();
         let scoping = TraverseScoping::new(scoping);
         let ast = AstBuilder::new(allocator);
-        Self { state, ancestry, scoping, ast }
+        let statements = StatementInsertions::default();
+        Self { state, ancestry, scoping, ast, statements }
     }
 
     /// Shortcut for `self.ancestry.push_stack`, to make `walk_*` methods less verbose.
@@ -686,6 +897,27 @@ impl<'a, State> TraverseCtx<'a, State> {
         unsafe { self.ancestry.retag_stack(ty) };
     }
 
+    /// Enter a list of statements. Used by `walk_statements`.
+    #[inline]
+    pub(crate) fn enter_statement_list(&mut self) {
+        self.statements.push_list();
+    }
+
+    /// Set index of the statement about to be visited. Used by `walk_statements`.
+    #[inline]
+    pub(crate) fn set_current_statement_index(&mut self, index: usize) {
+        self.statements.set_current_index(index);
+    }
+
+    /// Exit a list of statements, inserting any statements queued by
+    /// `insert_statement_before_current` / `insert_statement_after_current`
+    /// and declarations queued by `insert_declaration_in_nearest_block`.
+    /// Used by `walk_statements`.
+    #[inline]
+    pub(crate) fn exit_statement_list(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        self.statements.pop_list(stmts, self.ast);
+    }
+
     /// Shortcut for `ctx.scoping.set_current_scope_id`, to make `walk_*` methods less verbose.
     #[inline]
     pub(crate) fn set_current_scope_id(&mut self, scope_id: ScopeId) {
@@ -704,3 +936,348 @@ impl<'a, State> TraverseCtx<'a, State> {
         self.scoping.set_current_block_scope_id(scope_id);
     }
 }
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::{Allocator, Vec as ArenaVec};
+    use oxc_ast::ast::*;
+    use oxc_span::{SPAN, SourceType, Span};
+    use oxc_syntax::symbol::SymbolFlags;
+
+    use crate::{
+        Traverse, TraverseCtx,
+        test_utils::{enter_program, print, traverse},
+    };
+
+    /// Value of the numeric literal of an expression statement, if it has one.
+    fn number(stmt: &ExpressionStatement) -> Option<f64> {
+        match &stmt.expression {
+            Expression::NumericLiteral(lit) => Some(lit.value),
+            _ => None,
+        }
+    }
+
+    fn number_stmt<'a>(ctx: &TraverseCtx<'a, ()>, value: f64) -> Statement<'a> {
+        let expr = ctx.ast.expression_numeric_literal(SPAN, value, None, NumberBase::Decimal);
+        ctx.ast.statement_expression(SPAN, expr)
+    }
+
+    /// Records `is_top_level` and `is_module_top_level` for each expression statement.
+    #[derive(Default)]
+    struct CheckTopLevel(Vec<(bool, bool)>);
+
+    impl<'a> Traverse<'a, ()> for CheckTopLevel {
+        fn enter_expression_statement(
+            &mut self,
+            _stmt: &mut ExpressionStatement<'a>,
+            ctx: &mut TraverseCtx<'a, ()>,
+        ) {
+            self.0.push((ctx.is_top_level(), ctx.is_module_top_level()));
+        }
+    }
+
+    #[test]
+    fn is_top_level() {
+        // (source, source type, [ (is_top_level, is_module_top_level), ... ])
+        #[expect(clippy::type_complexity)]
+        let cases: &[(&str, SourceType, &[(bool, bool)])] = &[
+            ("0; { 1; }", SourceType::mjs(), &[(true, true), (false, false)]),
+            ("0; { 1; }", SourceType::cjs(), &[(true, false), (false, false)]),
+            ("if (x) 0; function f() { 1; }", SourceType::mjs(), &[(true, true), (false, false)]),
+        ];
+
+        for &(source, source_type, expected) in cases {
+            let allocator = Allocator::default();
+            let mut traverser = CheckTopLevel::default();
+            traverse(&allocator, source, source_type, &mut traverser);
+            assert_eq!(traverser.0, expected, "{source}");
+        }
+        enter_program("", SourceType::mjs(), |ctx| {
+            assert!(ctx.is_top_level());
+            assert!(!ctx.is_module_top_level());
+        });
+    }
+
+    /// Injects `var _helper;` before `0;` and after any other number.
+    #[derive(Default)]
+    struct InjectHelper {
+        /// Length of each list of statements in `exit_statements`
+        exit_lengths: Vec<usize>,
+    }
+
+    impl<'a> Traverse<'a, ()> for InjectHelper {
+        fn enter_expression_statement(
+            &mut self,
+            stmt: &mut ExpressionStatement<'a>,
+            ctx: &mut TraverseCtx<'a, ()>,
+        ) {
+            let Some(value) = number(stmt) else { return };
+            let binding =
+                ctx.generate_uid_in_root_scope("helper", SymbolFlags::FunctionScopedVariable);
+            let declarator = ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                binding.create_binding_pattern(ctx),
+                None,
+                false,
+            );
+            let var_stmt = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                ctx.ast.vec1(declarator),
+                false,
+            ));
+            if value == 0.0 {
+                ctx.insert_statement_before_current(var_stmt);
+            } else {
+                ctx.insert_statement_after_current(var_stmt);
+            }
+        }
+
+        fn exit_statements(
+            &mut self,
+            stmts: &mut ArenaVec<'a, Statement<'a>>,
+            _ctx: &mut TraverseCtx<'a, ()>,
+        ) {
+            self.exit_lengths.push(stmts.len());
+        }
+    }
+
+    #[test]
+    fn insert_statements() {
+        // (source, output, lengths of lists of statements in `exit_statements`)
+        // Insertions are made before `exit_statements`.
+        let cases: &[(&str, &str, &[usize])] = &[
+            ("0; { 1; }", "var _helper;0;{1;var _helper2}", &[2, 3]),
+            ("{ 0; } 1;", "{var _helper;0}1;var _helper2;", &[2, 3]),
+            ("0; 1; 2;", "var _helper;0;1;var _helper2;2;var _helper3;", &[6]),
+        ];
+
+        for &(source, output, exit_lengths) in cases {
+            let allocator = Allocator::default();
+            let mut traverser = InjectHelper::default();
+            let program = traverse(&allocator, source, SourceType::mjs(), &mut traverser);
+            assert_eq!(print(&program), output, "{source}");
+            assert_eq!(traverser.exit_lengths, exit_lengths, "{source}");
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Cannot insert a statement outside of a list of statements")]
+    fn insert_statement_outside_statements() {
+        enter_program("", SourceType::mjs(), |ctx| {
+            ctx.insert_statement_after_current(number_stmt(ctx, 2.0));
+        });
+    }
+
+    /// Declares `_d` with `let` at `0;`, and `_a` and `_b` with `var` and `_c` with `let` at any
+    /// other number, inserting `2;` before it.
+    struct DeclareInNearestBlock;
+
+    impl<'a> Traverse<'a, ()> for DeclareInNearestBlock {
+        fn enter_expression_statement(
+            &mut self,
+            stmt: &mut ExpressionStatement<'a>,
+            ctx: &mut TraverseCtx<'a, ()>,
+        ) {
+            let Some(value) = number(stmt) else { return };
+            let declarations: &[(&str, VariableDeclarationKind)] = if value == 0.0 {
+                &[("d", VariableDeclarationKind::Let)]
+            } else {
+                &[
+                    ("a", VariableDeclarationKind::Var),
+                    ("c", VariableDeclarationKind::Let),
+                    ("b", VariableDeclarationKind::Var),
+                ]
+            };
+            for &(name, kind) in declarations {
+                let flags = if kind.is_var() {
+                    SymbolFlags::FunctionScopedVariable
+                } else {
+                    SymbolFlags::BlockScopedVariable
+                };
+                let binding = ctx.generate_uid_in_current_scope(name, flags);
+                let declarator = ctx.ast.variable_declarator(
+                    SPAN,
+                    kind,
+                    binding.create_binding_pattern(ctx),
+                    None,
+                    false,
+                );
+                ctx.insert_declaration_in_nearest_block(declarator);
+            }
+            if value != 0.0 {
+                ctx.insert_statement_before_current(number_stmt(ctx, 2.0));
+            }
+        }
+    }
+
+    #[test]
+    fn insert_declaration_in_nearest_block() {
+        let cases = [
+            ("0; { 1; }", "let _d;0;{var _a,_b;let _c;2;1}"),
+            ("function f() { 1; }", "function f(){var _a,_b;let _c;2;1}"),
+            ("if (x) { 0; }", "if(x){let _d;0}"),
+        ];
+
+        for (source, output) in cases {
+            let allocator = Allocator::default();
+            let program =
+                traverse(&allocator, source, SourceType::mjs(), &mut DeclareInNearestBlock);
+            assert_eq!(print(&program), output, "{source}");
+        }
+    }
+
+    #[test]
+    fn symbol_references() {
+        enter_program("", SourceType::mjs(), |ctx| {
+            let binding =
+                ctx.generate_uid_in_current_scope("foo", SymbolFlags::FunctionScopedVariable);
+            assert_eq!(ctx.symbol_references(binding.symbol_id).count(), 0);
+
+            let first = binding.create_spanned_read_reference(Span::new(0, 1), ctx);
+            let second = binding.create_spanned_read_reference(Span::new(2, 3), ctx);
+            let reference_ids = ctx.symbol_references(binding.symbol_id).collect::<Vec<_>>();
+            assert_eq!(reference_ids, [first.reference_id(), second.reference_id()]);
+        });
+    }
+
+    /// Copies the expression of each expression statement, then moves it out and puts the copy
+    /// in its place.
+    struct TakeOrClone;
+
+    impl<'a> Traverse<'a, ()> for TakeOrClone {
+        fn enter_expression_statement(
+            &mut self,
+            stmt: &mut ExpressionStatement<'a>,
+            ctx: &mut TraverseCtx<'a, ()>,
+        ) {
+            let copy = ctx.take_or_clone_expression(&mut stmt.expression, true);
+            let taken = ctx.take_or_clone_expression(&mut stmt.expression, false);
+            if let (Expression::Identifier(copy), Expression::Identifier(taken)) = (&copy, &taken) {
+                // The copy has a new reference to the same binding, with the same flags
+                assert_eq!(copy.name, taken.name);
+                assert_ne!(copy.reference_id(), taken.reference_id());
+                let copied = ctx.scoping().get_reference(copy.reference_id());
+                let original = ctx.scoping().get_reference(taken.reference_id());
+                assert_eq!(copied.symbol_id(), original.symbol_id());
+                assert_eq!(copied.flags(), original.flags());
+            }
+            stmt.expression = copy;
+        }
+    }
+
+    #[test]
+    fn take_or_clone_expression() {
+        let cases = [
+            ("var x; x; y;", "var x;x;y;"),
+            ("true; 1; 'str'; null; /re/g; 1n;", "true;1;`str`;null;/re/g;1n;"),
+        ];
+
+        for (source, output) in cases {
+            let allocator = Allocator::default();
+            let program = traverse(&allocator, source, SourceType::mjs(), &mut TakeOrClone);
+            assert_eq!(print(&program), output, "{source}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "can only copy an identifier or a literal")]
+    fn take_or_clone_expression_panics_on_copying_member_expression() {
+        let allocator = Allocator::default();
+        traverse(&allocator, "x.y;", SourceType::mjs(), &mut TakeOrClone);
+    }
+
+    /// Records the method [`TraverseCtx::is_array_method_callback`] finds for each function.
+    #[derive(Default)]
+    struct CheckArrayMethodCallbacks(Vec<Option<Span>>);
+
+    impl CheckArrayMethodCallbacks {
+        const METHODS: &[&str] = &["map", "flatMap", "from", "forEach"];
+    }
+
+    impl<'a> Traverse<'a, ()> for CheckArrayMethodCallbacks {
+        fn enter_arrow_function_expression(
+            &mut self,
+            _arrow: &mut ArrowFunctionExpression<'a>,
+            ctx: &mut TraverseCtx<'a, ()>,
+        ) {
+            self.0.push(ctx.is_array_method_callback(Self::METHODS));
+        }
+
+        fn enter_function(&mut self, _func: &mut Function<'a>, ctx: &mut TraverseCtx<'a, ()>) {
+            self.0.push(ctx.is_array_method_callback(Self::METHODS));
+        }
+    }
+
+    #[test]
+    fn is_array_method_callback() {
+        // (source, method the callback is passed to)
+        let cases = [
+            ("items.map(() => {})", Some("map")),
+            ("items.flatMap((function() {}))", Some("flatMap")),
+            ("Array.from(items, () => {})", Some("from")),
+            ("items.forEach(function() {})", Some("forEach")),
+            ("items.filter(() => {})", None),
+            ("items.map(wrap(() => {}))", None),
+        ];
+
+        for (source, method) in cases {
+            let allocator = Allocator::default();
+            let mut traverser = CheckArrayMethodCallbacks::default();
+            traverse(&allocator, source, SourceType::mjs(), &mut traverser);
+            let found = traverser.0.iter().map(|span| span.map(|span| span.source_text(source)));
+            assert_eq!(found.collect::<Vec<_>>(), [method], "{source}");
+        }
+    }
+
+    /// Generates a UID based on the expression of each expression statement.
+    #[derive(Default)]
+    struct GenerateUidsBasedOnNodes(Vec<String>);
+
+    impl<'a> Traverse<'a, ()> for GenerateUidsBasedOnNodes {
+        fn enter_expression_statement(
+            &mut self,
+            stmt: &mut ExpressionStatement<'a>,
+            ctx: &mut TraverseCtx<'a, ()>,
+        ) {
+            let binding = ctx.generate_uid_based_on_node(
+                &stmt.expression,
+                ctx.current_scope_id(),
+                SymbolFlags::FunctionScopedVariable,
+            );
+            self.0.push(binding.name.to_string());
+        }
+    }
+
+    #[test]
+    fn generate_uid_based_on_node() {
+        // (expression, UID), generated in order in a program which declares `_taken`
+        let cases = [
+            ("ünïcode", "_ünïcode"),
+            // Leading underscores are trimmed
+            ("__proto.x", "_proto$x"),
+            // Collisions get a numeric postfix
+            ("taken", "_taken2"),
+            ("taken", "_taken3"),
+            ("foo.bar()", "_foo$bar"),
+            ("foo.bar()", "_foo$bar2"),
+            // Member chains are joined with `$` as in Babel, and truncated to 20 characters
+            ("a.b.c.d", "_a$b$c$d"),
+            ("first.second.third.fourth", "_first$second$third$f"),
+            ("éé.éééééééééééééééééééé", "_éé$ééééééééééééééééé"),
+            // String literals are converted to identifiers
+            ("'my-module/sub path'", "_myModuleSubPath"),
+            // Other expressions fall back to `ref`, as in Babel
+            ("[]", "_ref"),
+        ];
+
+        let source =
+            cases.iter().fold(String::from("var _taken;"), |source, (expr, _)| source + expr + ";");
+        let allocator = Allocator::default();
+        let mut traverser = GenerateUidsBasedOnNodes::default();
+        traverse(&allocator, &source, SourceType::mjs(), &mut traverser);
+        assert_eq!(traverser.0, cases.map(|(_, uid)| uid));
+    }
+}
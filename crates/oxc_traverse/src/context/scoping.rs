@@ -293,6 +293,17 @@ impl<'a> TraverseScoping<'a> {
         uid_generator.create(name)
     }
 
+    /// Reserve capacity for at least `additional` more references.
+    pub fn reserve_references(&mut self, additional: usize) {
+        self.scoping.reserve(0, additional, 0);
+    }
+
+    /// Reserve capacity for at least `additional` more references bound to `symbol_id`.
+    pub fn reserve_bound_references(&mut self, symbol_id: SymbolId, additional: usize) {
+        self.reserve_references(additional);
+        self.scoping.reserve_resolved_references(symbol_id, additional);
+    }
+
     /// Create a reference bound to a `SymbolId`
     pub fn create_bound_reference(
         &mut self,
@@ -0,0 +1,115 @@
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{
+    AstBuilder,
+    ast::{Statement, VariableDeclarationKind, VariableDeclarator},
+};
+use oxc_span::SPAN;
+
+/// Statements queued for insertion around the statement currently being visited.
+///
+/// Contains a stack with an entry for each `Vec<Statement>` which traversal is currently inside.
+///
+/// `walk_statements` pushes an entry before visiting a list of statements, updates the entry's
+/// index before visiting each statement, and pops the entry once all statements have been visited,
+/// inserting any queued statements into the list.
+#[derive(Default)]
+pub struct StatementInsertions<'a> {
+    stack: Vec<StatementList<'a>>,
+}
+
+/// Insertions for a single `Vec<Statement>`.
+///
+/// `before` and `after` are ordered by index, as statements are visited in order.
+#[derive(Default)]
+struct StatementList<'a> {
+    /// Index of statement currently being visited
+    current_index: usize,
+    before: Vec<(usize, Statement<'a>)>,
+    after: Vec<(usize, Statement<'a>)>,
+    /// Declarators to declare at the start of the list, grouped by kind,
+    /// in order of the first declarator of each kind.
+    declarations: Vec<(VariableDeclarationKind, Vec<VariableDeclarator<'a>>)>,
+}
+
+impl<'a> StatementInsertions<'a> {
+    /// Queue a statement to be inserted before the statement currently being visited.
+    pub fn insert_before(&mut self, stmt: Statement<'a>) {
+        if let Some(list) = self.current_list() {
+            list.before.push((list.current_index, stmt));
+        }
+    }
+
+    /// Queue a statement to be inserted after the statement currently being visited.
+    pub fn insert_after(&mut self, stmt: Statement<'a>) {
+        if let Some(list) = self.current_list() {
+            list.after.push((list.current_index, stmt));
+        }
+    }
+
+    /// Queue a declarator to be declared at the start of the current list of statements.
+    ///
+    /// Declarators of the same kind are combined into a single declaration, e.g. `var _a, _b;`.
+    pub fn insert_declaration(&mut self, declarator: VariableDeclarator<'a>) {
+        let Some(list) = self.current_list() else { return };
+        let kind = declarator.kind;
+        match list.declarations.iter_mut().find(|(k, _)| *k == kind) {
+            Some((_, declarators)) => declarators.push(declarator),
+            None => list.declarations.push((kind, vec![declarator])),
+        }
+    }
+
+    /// Enter a list of statements.
+    #[inline]
+    pub fn push_list(&mut self) {
+        self.stack.push(StatementList::default());
+    }
+
+    /// Set index of the statement currently being visited, in the current list of statements.
+    #[inline]
+    pub fn set_current_index(&mut self, index: usize) {
+        // `push_list` is always called before this, so `stack` cannot be empty
+        if let Some(list) = self.stack.last_mut() {
+            list.current_index = index;
+        }
+    }
+
+    /// Exit a list of statements, and insert any queued statements into it.
+    pub fn pop_list(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>, ast: AstBuilder<'a>) {
+        let Some(list) = self.stack.pop() else { return };
+        if list.before.is_empty() && list.after.is_empty() && list.declarations.is_empty() {
+            return;
+        }
+
+        let mut new_stmts = ast.vec_with_capacity(
+            stmts.len() + list.before.len() + list.after.len() + list.declarations.len(),
+        );
+        for (kind, declarators) in list.declarations {
+            let declarators = ast.vec_from_iter(declarators);
+            new_stmts.push(Statement::VariableDeclaration(ast.alloc_variable_declaration(
+                SPAN,
+                kind,
+                declarators,
+                false,
+            )));
+        }
+        let mut before = list.before.into_iter().peekable();
+        let mut after = list.after.into_iter().peekable();
+        for (index, stmt) in stmts.drain(..).enumerate() {
+            while let Some((_, stmt)) = before.next_if(|&(i, _)| i == index) {
+                new_stmts.push(stmt);
+            }
+            new_stmts.push(stmt);
+            while let Some((_, stmt)) = after.next_if(|&(i, _)| i == index) {
+                new_stmts.push(stmt);
+            }
+        }
+
+        *stmts = new_stmts;
+    }
+
+    fn current_list(&mut self) -> Option<&mut StatementList<'a>> {
+        let list = self.stack.last_mut();
+        debug_assert!(list.is_some(), "Cannot insert a statement outside of a list of statements");
+        list
+    }
+}
@@ -5607,8 +5607,11 @@ unsafe fn walk_statements<'a, State, Tr: Traverse<'a, State>>(
     ctx: &mut TraverseCtx<'a, State>,
 ) {
     traverser.enter_statements(&mut *stmts, ctx);
-    for stmt in &mut *stmts {
+    ctx.enter_statement_list();
+    for (index, stmt) in (*stmts).iter_mut().enumerate() {
+        ctx.set_current_statement_index(index);
         walk_statement(traverser, stmt, ctx);
     }
+    ctx.exit_statement_list(&mut *stmts);
     traverser.exit_statements(&mut *stmts, ctx);
 }
@@ -69,8 +69,8 @@ use oxc_semantic::Scoping;
 pub mod ast_operations;
 mod context;
 pub use context::{
-    BoundIdentifier, MaybeBoundIdentifier, ReusableTraverseCtx, TraverseAncestry, TraverseCtx,
-    TraverseScoping,
+    BoundIdentifier, EnclosingFunction, MaybeBoundIdentifier, ReusableTraverseCtx,
+    TraverseAncestry, TraverseCtx, TraverseScoping,
 };
 
 mod generated {
@@ -83,6 +83,8 @@ pub use generated::{ancestor, ancestor::Ancestor, traverse::Traverse};
 use generated::{scopes_collector, walk::walk_ast};
 
 mod compile_fail_tests;
+#[cfg(test)]
+mod test_utils;
 
 /// Traverse AST with a [`Traverse`] impl.
 ///
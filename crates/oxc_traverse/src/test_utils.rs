@@ -0,0 +1,52 @@
+//! Helpers for tests of [`TraverseCtx`] methods.
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::Program;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+
+use crate::{Traverse, TraverseCtx, traverse_mut};
+
+/// Parse `source_text`, and traverse it with `traverser`.
+///
+/// # Panics
+/// Panics if `source_text` does not parse.
+pub fn traverse<'a>(
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    source_type: SourceType,
+    traverser: &mut impl Traverse<'a, ()>,
+) -> Program<'a> {
+    let ret = Parser::new(allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty(), "failed to parse {source_text:?}: {:?}", ret.errors);
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    traverse_mut(traverser, allocator, &mut program, scoping, ());
+    program
+}
+
+/// Parse `source_text`, and call `f` on entering the program.
+pub fn enter_program(
+    source_text: &str,
+    source_type: SourceType,
+    f: impl for<'a> FnOnce(&mut TraverseCtx<'a, ()>),
+) {
+    struct EnterProgram<F>(Option<F>);
+
+    impl<'a, F: FnOnce(&mut TraverseCtx<'a, ()>)> Traverse<'a, ()> for EnterProgram<F> {
+        fn enter_program(&mut self, _program: &mut Program<'a>, ctx: &mut TraverseCtx<'a, ()>) {
+            (self.0.take().unwrap())(ctx);
+        }
+    }
+
+    let allocator = Allocator::default();
+    let source_text = allocator.alloc_str(source_text);
+    traverse(&allocator, source_text, source_type, &mut EnterProgram(Some(f)));
+}
+
+/// Print `program` minified, so expected output fits on one line.
+pub fn print(program: &Program) -> String {
+    Codegen::new().with_options(CodegenOptions::minify()).build(program).code
+}
@@ -48,6 +48,16 @@ export interface ArrowFunctionsOptions {
   spec?: boolean
 }
 
+export interface BlockScopingOptions {
+  /**
+   * Report an error instead of moving a loop body into a function, when the body
+   * captures a `let` or `const` binding declared in the loop in a closure.
+   *
+   * @default false
+   */
+  throwIfClosureRequired?: boolean
+}
+
 export interface CompilerAssumptions {
   ignoreFunctionLength?: boolean
   noDocumentAll?: boolean
@@ -123,6 +133,22 @@ export interface DecoratorOptions {
 export interface Es2015Options {
   /** Transform arrow functions into function expressions. */
   arrowFunction?: ArrowFunctionsOptions
+  /** Transform `let` and `const` declarations into `var` declarations. */
+  blockScoping?: BlockScopingOptions
+  /** Transform `for...of` loops into `for` loops. */
+  forOf?: ForOfOptions
+  /** Transform template literals into string concatenation. */
+  templateLiterals?: TemplateLiteralsOptions
+}
+
+export interface ForOfOptions {
+  /**
+   * Iterate over every iterable by index, as if it was an array.
+   * Produces faster code, but only works for arrays and array-like objects.
+   *
+   * @default false
+   */
+  loose?: boolean
 }
 
 export declare const enum HelperMode {
@@ -422,6 +448,16 @@ export interface StyledComponentsOptions {
   topLevelImportPaths?: Array<string>
 }
 
+export interface TemplateLiteralsOptions {
+  /**
+   * Also transform tagged templates, to a call with an array of the strings as the first argument.
+   * The array has no `raw` property.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 /**
  * Transpile a JavaScript or TypeScript into a target ECMAScript version.
  *
@@ -482,6 +518,12 @@ export interface TransformOptions {
    * @see [esbuild#target](https://esbuild.github.io/api/#target)
    */
   target?: string | Array<string>
+  /**
+   * Configure how async functions are lowered, when `target` does not support them.
+   *
+   * The `ES2017Options` typings are generated from the options struct in `oxc_transformer`.
+   */
+  es2017?: ES2017Options
   /** Behaviour for runtime helpers. */
   helpers?: Helpers
   /** Define Plugin */
@@ -617,3 +659,53 @@ export interface TypeScriptOptions {
    */
   rewriteImportExtensions?: 'rewrite' | 'remove' | boolean
 }
+
+export interface ES2017Options {
+  /**
+   * Record how each async function is lowered, and return the records in
+   * `TransformerReturn::decision_log`.
+   *
+   * Useful for checking that upgrading Oxc does not change the output for a codebase.
+   *
+   * @default false
+   * @since 0.77.2
+   */
+  decisionLog?: boolean;
+  /**
+   * How to name the variable which holds the generator function wrapped in
+   * `asyncToGenerator`.
+   *
+   * `"babel"` matches Babel, using the function's own name (`_foo`), otherwise `_ref`.
+   * `"ref"` always uses `_ref`. `"name"` also uses the name inferred from the variable or
+   * property the function is assigned to (`_foo` for `const foo = async () => {}`).
+   *
+   * @default "babel"
+   * @since 0.77.2
+   */
+  generatorNaming?: "babel" | "ref" | "name";
+  /**
+   * Call this helper instead of `asyncToGenerator` from the helper runtime, given as
+   * `[object, property]`.
+   *
+   * For example, `["myRuntime", "asyncToGenerator"]` calls `myRuntime.asyncToGenerator`.
+   * The helper must take a generator function and return an async function, as
+   * `asyncToGenerator` does.
+   *
+   * @default null
+   * @since 0.77.2
+   */
+  helperName?: [string, string] | null;
+  /**
+   * Calling convention of the helper.
+   *
+   * `"babel"` matches Babel's `asyncToGenerator`, which takes a generator function and
+   * returns an async function: `asyncToGenerator(function* () {}).apply(this, arguments)`.
+   * `"esbuild"` matches esbuild's `__async`, which takes `this`, the arguments and a
+   * generator function, and returns a promise: `__async(this, null, function* () {})`.
+   * Unless `helperName` is set, `"esbuild"` calls a global `__async` function.
+   *
+   * @default "babel"
+   * @since 0.77.2
+   */
+  helper?: "babel" | "esbuild";
+}
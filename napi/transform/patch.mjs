@@ -16,3 +16,11 @@ if (!nativeBinding && globalThis.process?.versions?.["webcontainer"]) {
 ` + s,
 );
 fs.writeFileSync(filename, data);
+
+// Typings of plugin options are generated by `oxc_transformer`, from `declare_plugin_options!`.
+const dtsFilename = './index.d.ts';
+const es2017Options = fs.readFileSync(
+  '../../crates/oxc_transformer/src/es2017/generated/options.d.ts',
+  'utf-8',
+);
+fs.appendFileSync(dtsFilename, '\n' + es2017Options);
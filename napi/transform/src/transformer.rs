@@ -19,8 +19,8 @@ use oxc::{
     semantic::{SemanticBuilder, SemanticBuilderReturn},
     span::SourceType,
     transformer::{
-        EnvOptions, HelperLoaderMode, HelperLoaderOptions, JsxRuntime, ProposalOptions,
-        RewriteExtensionsMode,
+        ES2017Options, EnvOptions, HelperLoaderMode, HelperLoaderOptions, JsxRuntime,
+        ProposalOptions, RewriteExtensionsMode,
     },
     transformer_plugins::{
         InjectGlobalVariablesConfig, InjectImport, ModuleRunnerTransform,
@@ -131,6 +131,12 @@ pub struct TransformOptions {
     /// @see [esbuild#target](https://esbuild.github.io/api/#target)
     pub target: Option<Either<String, Vec<String>>>,
 
+    /// Configure how async functions are lowered, when `target` does not support them.
+    ///
+    /// The `ES2017Options` typings are generated from the options struct in `oxc_transformer`.
+    #[napi(ts_type = "ES2017Options")]
+    pub es2017: Option<serde_json::Value>,
+
     /// Behaviour for runtime helpers.
     pub helpers: Option<Helpers>,
 
@@ -153,11 +159,20 @@ impl TryFrom<TransformOptions> for oxc::transformer::TransformOptions {
     type Error = String;
 
     fn try_from(options: TransformOptions) -> Result<Self, Self::Error> {
-        let env = match options.target {
+        let mut env = match options.target {
             Some(Either::A(s)) => EnvOptions::from_target(&s)?,
             Some(Either::B(list)) => EnvOptions::from_target_list(&list)?,
             _ => EnvOptions::default(),
         };
+        if let Some(es2017) = options.es2017 {
+            let es2017 = serde_json::from_value::<ES2017Options>(es2017)
+                .map_err(|err| format!("Invalid es2017 option: {err}"))?;
+            env.es2017 = ES2017Options {
+                async_to_generator: env.es2017.async_to_generator,
+                span_filter: env.es2017.span_filter,
+                ..es2017
+            };
+        }
         Ok(Self {
             cwd: options.cwd.map(PathBuf::from).unwrap_or_default(),
             assumptions: options.assumptions.map(Into::into).unwrap_or_default(),
@@ -655,15 +670,73 @@ impl From<ArrowFunctionsOptions> for oxc::transformer::ArrowFunctionsOptions {
     }
 }
 
+#[napi(object)]
+pub struct BlockScopingOptions {
+    /// Report an error instead of moving a loop body into a function, when the body
+    /// captures a `let` or `const` binding declared in the loop in a closure.
+    ///
+    /// @default false
+    pub throw_if_closure_required: Option<bool>,
+}
+
+impl From<BlockScopingOptions> for oxc::transformer::BlockScopingOptions {
+    fn from(options: BlockScopingOptions) -> Self {
+        oxc::transformer::BlockScopingOptions {
+            throw_if_closure_required: options.throw_if_closure_required.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct ForOfOptions {
+    /// Iterate over every iterable by index, as if it was an array.
+    /// Produces faster code, but only works for arrays and array-like objects.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<ForOfOptions> for oxc::transformer::ForOfOptions {
+    fn from(options: ForOfOptions) -> Self {
+        oxc::transformer::ForOfOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct TemplateLiteralsOptions {
+    /// Also transform tagged templates, to a call with an array of the strings as the first argument.
+    /// The array has no `raw` property.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<TemplateLiteralsOptions> for oxc::transformer::TemplateLiteralsOptions {
+    fn from(options: TemplateLiteralsOptions) -> Self {
+        oxc::transformer::TemplateLiteralsOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
 #[napi(object)]
 pub struct Es2015Options {
     /// Transform arrow functions into function expressions.
     pub arrow_function: Option<ArrowFunctionsOptions>,
+    /// Transform `let` and `const` declarations into `var` declarations.
+    pub block_scoping: Option<BlockScopingOptions>,
+    /// Transform `for...of` loops into `for` loops.
+    pub for_of: Option<ForOfOptions>,
+    /// Transform template literals into string concatenation.
+    pub template_literals: Option<TemplateLiteralsOptions>,
 }
 
 impl From<Es2015Options> for oxc::transformer::ES2015Options {
     fn from(options: Es2015Options) -> Self {
-        oxc::transformer::ES2015Options { arrow_function: options.arrow_function.map(Into::into) }
+        oxc::transformer::ES2015Options {
+            arrow_function: options.arrow_function.map(Into::into),
+            block_scoping: options.block_scoping.map(Into::into),
+            for_of: options.for_of.map(Into::into),
+            template_literals: options.template_literals.map(Into::into),
+        }
     }
 }
 
@@ -134,6 +134,25 @@ describe('helpers', () => {
   });
 });
 
+describe('es2017', () => {
+  it('should call the esbuild helper', () => {
+    const code = 'async function foo() { await bar(); }';
+    const ret = transform('test.js', code, {
+      target: 'es2016',
+      es2017: { helper: 'esbuild' },
+    });
+    expect(ret.errors.length).toBe(0);
+    expect(ret.code).toMatchInlineSnapshot(`
+      "function foo() {
+      	return __async(void 0, null, function* () {
+      		yield bar();
+      	});
+      }
+      "
+    `);
+  });
+});
+
 describe('modules', () => {
   it('should transform export = and import ', () => {
     const code = `
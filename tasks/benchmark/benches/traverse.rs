@@ -0,0 +1,67 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::Program;
+use oxc_benchmark::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_syntax::{reference::ReferenceFlags, symbol::SymbolFlags};
+use oxc_traverse::{Traverse, TraverseCtx, traverse_mut};
+
+const REFERENCES_COUNT: usize = 10_000;
+
+/// Creates `REFERENCES_COUNT` references to a new binding.
+struct CreateReferences {
+    batch: bool,
+}
+
+impl<'a> Traverse<'a, ()> for CreateReferences {
+    fn enter_program(&mut self, _program: &mut Program<'a>, ctx: &mut TraverseCtx<'a, ()>) {
+        let binding = ctx.generate_uid_in_current_scope("x", SymbolFlags::FunctionScopedVariable);
+        if self.batch {
+            black_box(binding.create_references(REFERENCES_COUNT, ReferenceFlags::Read, ctx));
+        } else {
+            let references =
+                std::iter::repeat_with(|| binding.create_reference(ReferenceFlags::Read, ctx))
+                    .take(REFERENCES_COUNT)
+                    .collect::<Vec<_>>();
+            black_box(references);
+        }
+    }
+}
+
+fn bench_create_references(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("traverse_create_references");
+
+    // Create `Allocator` outside of `bench_function`, so same allocator is used for
+    // both the warmup and measurement phases
+    let mut allocator = Allocator::default();
+
+    for (name, batch) in [("loop", false), ("batch", true)] {
+        let id = BenchmarkId::from_parameter(name);
+        group.bench_function(id, |b| {
+            b.iter_with_setup_wrapper(|runner| {
+                // Reset allocator at start of each iteration
+                allocator.reset();
+
+                let mut program = Parser::new(&allocator, "", SourceType::mjs()).parse().program;
+                let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+
+                runner.run(|| {
+                    // Return `Scoping`, so it's dropped outside of the measured section
+                    traverse_mut(
+                        &mut CreateReferences { batch },
+                        &allocator,
+                        &mut program,
+                        scoping,
+                        (),
+                    )
+                });
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(traverse, bench_create_references);
+criterion_main!(traverse);
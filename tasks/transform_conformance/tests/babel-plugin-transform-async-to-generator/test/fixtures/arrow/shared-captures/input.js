@@ -0,0 +1,8 @@
+function outer() {
+  const a = async () => { await this.a; return arguments; };
+  const b = async () => { await this.b; return arguments; };
+
+  function inner() {
+    const c = async () => { await this.c; };
+  }
+}
@@ -0,0 +1,32 @@
+function outer() {
+  var _arguments = arguments, _this = this;
+  const a = /*#__PURE__*/function () {
+    var _ref = babelHelpers.asyncToGenerator(function* () {
+      yield _this.a;
+      return _arguments;
+    });
+    return function a() {
+      return _ref.apply(this, arguments);
+    };
+  }();
+  const b = /*#__PURE__*/function () {
+    var _ref2 = babelHelpers.asyncToGenerator(function* () {
+      yield _this.b;
+      return _arguments;
+    });
+    return function b() {
+      return _ref2.apply(this, arguments);
+    };
+  }();
+  function inner() {
+    var _this2 = this;
+    const c = /*#__PURE__*/function () {
+      var _ref3 = babelHelpers.asyncToGenerator(function* () {
+        yield _this2.c;
+      });
+      return function c() {
+        return _ref3.apply(this, arguments);
+      };
+    }();
+  }
+}
@@ -0,0 +1,24 @@
+const sentinel = { reason: "sentinel" };
+
+async function run() {
+  try {
+    await Promise.reject(sentinel);
+  } catch (e) {
+    return e;
+  }
+  throw new Error("unreachable");
+}
+
+async function runDeferred() {
+  try {
+    await new Promise((_, reject) => setTimeout(() => reject(sentinel)));
+  } catch (e) {
+    return e;
+  }
+  throw new Error("unreachable");
+}
+
+return Promise.all([run(), runDeferred()]).then(([caught, caughtDeferred]) => {
+  expect(caught).toBe(sentinel);
+  expect(caughtDeferred).toBe(sentinel);
+});
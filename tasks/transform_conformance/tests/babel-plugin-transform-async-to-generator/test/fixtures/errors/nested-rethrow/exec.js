@@ -0,0 +1,40 @@
+const sentinel = { reason: "sentinel" };
+const log = [];
+
+async function inner() {
+  try {
+    await Promise.reject(sentinel);
+  } catch (e) {
+    log.push("inner catch");
+    throw e;
+  } finally {
+    log.push("inner finally");
+  }
+}
+
+async function outer() {
+  try {
+    await inner();
+  } catch (e) {
+    log.push("outer catch");
+    return e;
+  }
+}
+
+async function sameFunction() {
+  try {
+    try {
+      await Promise.reject(sentinel);
+    } catch (e) {
+      throw e;
+    }
+  } catch (e) {
+    return e;
+  }
+}
+
+return Promise.all([outer(), sameFunction()]).then(([caught, caughtSameFunction]) => {
+  expect(caught).toBe(sentinel);
+  expect(caughtSameFunction).toBe(sentinel);
+  expect(log).toEqual(["inner catch", "inner finally", "outer catch"]);
+});
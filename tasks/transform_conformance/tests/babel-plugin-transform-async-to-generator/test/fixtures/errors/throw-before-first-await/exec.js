@@ -0,0 +1,35 @@
+const sentinel = new Error("sentinel");
+const stack = sentinel.stack;
+
+async function run() {
+  throw sentinel;
+}
+
+async function runWithNewError() {
+  throw new Error("created in body");
+}
+
+const promise = run();
+expect(promise).toBeInstanceOf(Promise);
+
+return Promise.all([
+  promise.then(
+    () => {
+      throw new Error("expected rejection");
+    },
+    (error) => {
+      expect(error).toBe(sentinel);
+      expect(error.stack).toBe(stack);
+    },
+  ),
+  runWithNewError().then(
+    () => {
+      throw new Error("expected rejection");
+    },
+    (error) => {
+      // The top frame is the function body, not the helper.
+      const topFrame = error.stack.split("\n")[1];
+      expect(topFrame).not.toMatch(/^\s*at (asyncGeneratorStep|_next|_throw)\b/);
+    },
+  ),
+]);
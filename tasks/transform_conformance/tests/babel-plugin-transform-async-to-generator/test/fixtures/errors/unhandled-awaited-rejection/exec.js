@@ -0,0 +1,28 @@
+const sentinel = { reason: "sentinel" };
+
+async function run() {
+  await Promise.reject(sentinel);
+  return "unreachable";
+}
+
+const arrow = async () => {
+  await Promise.reject(sentinel);
+};
+
+class Foo {
+  async method() {
+    await Promise.reject(sentinel);
+  }
+}
+
+const expectRejection = (promise) =>
+  promise.then(
+    () => {
+      throw new Error("expected rejection");
+    },
+    (reason) => {
+      expect(reason).toBe(sentinel);
+    },
+  );
+
+return Promise.all([run(), arrow(), new Foo().method()].map(expectRejection));
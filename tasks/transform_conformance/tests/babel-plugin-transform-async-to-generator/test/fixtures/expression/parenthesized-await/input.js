@@ -0,0 +1,9 @@
+async function foo() {
+  return (await x());
+}
+
+async function bar() {
+  return ((await x()));
+}
+
+const baz = async () => ((await x()) + (await y()));
@@ -0,0 +1,26 @@
+function foo() {
+  return _foo.apply(this, arguments);
+}
+function _foo() {
+  _foo = babelHelpers.asyncToGenerator(function* () {
+    return yield x();
+  });
+  return _foo.apply(this, arguments);
+}
+function bar() {
+  return _bar.apply(this, arguments);
+}
+function _bar() {
+  _bar = babelHelpers.asyncToGenerator(function* () {
+    return yield x();
+  });
+  return _bar.apply(this, arguments);
+}
+const baz = /*#__PURE__*/function () {
+  var _ref = babelHelpers.asyncToGenerator(function* () {
+    return (yield x()) + (yield y());
+  });
+  return function baz() {
+    return _ref.apply(this, arguments);
+  };
+}();
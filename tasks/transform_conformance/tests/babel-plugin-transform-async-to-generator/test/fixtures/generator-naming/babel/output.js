@@ -0,0 +1,34 @@
+function foo() {
+  return _foo.apply(this, arguments);
+}
+function _foo() {
+  _foo = babelHelpers.asyncToGenerator(function* () {
+    yield a;
+  });
+  return _foo.apply(this, arguments);
+}
+const bar = /*#__PURE__*/function () {
+  var _ref = babelHelpers.asyncToGenerator(function* (z) {
+    yield z;
+  });
+  return function bar(_x) {
+    return _ref.apply(this, arguments);
+  };
+}();
+const baz = /*#__PURE__*/function () {
+  var _ref2 = babelHelpers.asyncToGenerator(function* (x) {
+    yield x;
+  });
+  return function baz(_x2) {
+    return _ref2.apply(this, arguments);
+  };
+}();
+const named = /*#__PURE__*/function () {
+  var _qux = babelHelpers.asyncToGenerator(function* (y) {
+    yield y;
+  });
+  function qux(_x3) {
+    return _qux.apply(this, arguments);
+  }
+  return qux;
+}();
@@ -0,0 +1,12 @@
+async function foo() {
+  await a;
+}
+const bar = async function (z) {
+  await z;
+};
+const baz = async (x) => {
+  await x;
+};
+const named = async function qux(y) {
+  await y;
+};
@@ -0,0 +1,34 @@
+function foo() {
+  return _ref.apply(this, arguments);
+}
+function _ref() {
+  _ref = babelHelpers.asyncToGenerator(function* () {
+    yield a;
+  });
+  return _ref.apply(this, arguments);
+}
+const bar = /*#__PURE__*/function () {
+  var _ref2 = babelHelpers.asyncToGenerator(function* (z) {
+    yield z;
+  });
+  return function bar(_x) {
+    return _ref2.apply(this, arguments);
+  };
+}();
+const baz = /*#__PURE__*/function () {
+  var _ref3 = babelHelpers.asyncToGenerator(function* (x) {
+    yield x;
+  });
+  return function baz(_x2) {
+    return _ref3.apply(this, arguments);
+  };
+}();
+const named = /*#__PURE__*/function () {
+  var _ref4 = babelHelpers.asyncToGenerator(function* (y) {
+    yield y;
+  });
+  function qux(_x3) {
+    return _ref4.apply(this, arguments);
+  }
+  return qux;
+}();
@@ -0,0 +1,9 @@
+const f = async () => async () => await g();
+
+async function outer() {
+  await a;
+  const inner = async () => {
+    await b;
+  };
+  await c;
+}
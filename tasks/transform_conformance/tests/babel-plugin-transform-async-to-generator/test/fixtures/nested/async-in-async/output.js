@@ -0,0 +1,28 @@
+const f = /*#__PURE__*/function () {
+  var _ref = babelHelpers.asyncToGenerator(function* () {
+    return /*#__PURE__*/babelHelpers.asyncToGenerator(function* () {
+      return yield g();
+    });
+  });
+  return function f() {
+    return _ref.apply(this, arguments);
+  };
+}();
+function outer() {
+  return _outer.apply(this, arguments);
+}
+function _outer() {
+  _outer = babelHelpers.asyncToGenerator(function* () {
+    yield a;
+    const inner = /*#__PURE__*/function () {
+      var _ref2 = babelHelpers.asyncToGenerator(function* () {
+        yield b;
+      });
+      return function inner() {
+        return _ref2.apply(this, arguments);
+      };
+    }();
+    yield c;
+  });
+  return _outer.apply(this, arguments);
+}
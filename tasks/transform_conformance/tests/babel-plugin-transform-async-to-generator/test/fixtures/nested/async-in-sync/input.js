@@ -0,0 +1,10 @@
+function outer() {
+  const inner = async () => {
+    await a;
+  };
+  return inner;
+}
+
+const arrow = () => async function () {
+  await b;
+};
@@ -0,0 +1,14 @@
+function outer() {
+  const inner = /*#__PURE__*/function () {
+    var _ref = babelHelpers.asyncToGenerator(function* () {
+      yield a;
+    });
+    return function inner() {
+      return _ref.apply(this, arguments);
+    };
+  }();
+  return inner;
+}
+const arrow = () => /*#__PURE__*/babelHelpers.asyncToGenerator(function* () {
+  yield b;
+});
@@ -0,0 +1,8 @@
+async function outer() {
+  await a;
+  function inner() {
+    return async;
+  }
+  const arrow = () => async;
+  await b;
+}
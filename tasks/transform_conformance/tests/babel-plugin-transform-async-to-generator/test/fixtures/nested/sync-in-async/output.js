@@ -0,0 +1,14 @@
+function outer() {
+  return _outer.apply(this, arguments);
+}
+function _outer() {
+  _outer = babelHelpers.asyncToGenerator(function* () {
+    yield a;
+    function inner() {
+      return async;
+    }
+    const arrow = () => async;
+    yield b;
+  });
+  return _outer.apply(this, arguments);
+}
@@ -0,0 +1,17 @@
+async function foo() {
+  switch (await x()) {
+    case await y():
+      await z();
+      break;
+    case 1:
+    default:
+      return await w();
+  }
+}
+
+const bar = async () => {
+  switch (await x()) {
+    case (await y()) + 1:
+      break;
+  }
+};
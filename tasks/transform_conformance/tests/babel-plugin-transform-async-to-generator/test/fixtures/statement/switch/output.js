@@ -0,0 +1,27 @@
+function foo() {
+  return _foo.apply(this, arguments);
+}
+function _foo() {
+  _foo = babelHelpers.asyncToGenerator(function* () {
+    switch (yield x()) {
+      case yield y():
+        yield z();
+        break;
+      case 1:
+      default:
+        return yield w();
+    }
+  });
+  return _foo.apply(this, arguments);
+}
+const bar = /*#__PURE__*/function () {
+  var _ref = babelHelpers.asyncToGenerator(function* () {
+    switch (yield x()) {
+      case (yield y()) + 1:
+        break;
+    }
+  });
+  return function bar() {
+    return _ref.apply(this, arguments);
+  };
+}();
@@ -0,0 +1,50 @@
+let a;
+
+// The value of the expression is the left side when it short-circuits.
+a = 0;
+expect(a &&= 1).toBe(0);
+expect(a).toBe(0);
+a = 2;
+expect(a &&= 1).toBe(1);
+expect(a).toBe(1);
+
+a = "left";
+expect(a ||= "right").toBe("left");
+a = "";
+expect(a ||= "right").toBe("right");
+expect(a).toBe("right");
+
+a = 0;
+expect(a ??= 1).toBe(0);
+a = undefined;
+expect(a ??= 1).toBe(1);
+a = null;
+expect(a ??= 2).toBe(2);
+
+// The object and the computed key are evaluated once.
+const log = [];
+const obj = { x: null, y: 1, z: 0 };
+const getObj = () => (log.push("obj"), obj);
+const key = (k) => (log.push(k), k);
+
+expect(getObj()[key("x")] ??= 3).toBe(3);
+expect(getObj()[key("y")] &&= 4).toBe(4);
+expect(getObj()[key("z")] ||= 5).toBe(5);
+expect(obj).toEqual({ x: 3, y: 4, z: 5 });
+expect(log).toEqual(["obj", "x", "obj", "y", "obj", "z"]);
+
+// The setter is not called when the expression short-circuits.
+let sets = 0;
+const accessor = {
+  get value() {
+    return 1;
+  },
+  set value(v) {
+    sets++;
+  },
+};
+accessor.value ||= 2;
+accessor.value ??= 2;
+expect(sets).toBe(0);
+accessor.value &&= 2;
+expect(sets).toBe(1);